@@ -4,6 +4,9 @@ use crate::{
 };
 use std::collections::HashSet;
 
+/// The maximum number of valid values a [Constraint] built by [Constraint::new_range_constraint] can hold.
+pub const MAX_RANGE_CONSTRAINT_LENGTH: u64 = 1 << 20;
+
 #[allow(dead_code)]
 impl Constraint {
     /// Creates a new empty [Constraint] with the given ID.
@@ -67,10 +70,79 @@ impl Constraint {
         let valid_values: ValueTypeSet = values.into_iter().collect();
         Constraint { id, valid_values }
     }
+
+    /// Creates a new [Constraint] whose valid values are every value from `min` to `max` inclusive.
+    ///
+    /// if `min` is greater than `max` the [Constraint] has no valid values, so is not theoretically possible.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The [ConstraintIdType] for the [Constraint].
+    /// * `min` - The lowest valid [ValueType].
+    /// * `max` - The highest valid [ValueType].
+    ///
+    /// # Panics
+    ///
+    /// Panics if the range holds more than [MAX_RANGE_CONSTRAINT_LENGTH] values,
+    /// as every value is stored in the valid value set.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use crate::rusted_dice::constraint_management::Constraint;
+    /// let constraint = Constraint::new_range_constraint(1, 3, 6);
+    /// assert_eq!(constraint, Constraint::new_many_item_constraint(1, vec![3, 4, 5, 6]));
+    /// ```
+    pub fn new_range_constraint(
+        id: ConstraintIdType,
+        min: ValueType,
+        max: ValueType,
+    ) -> Constraint {
+        if min > max {
+            return Constraint::new_empty_constraint(id);
+        }
+        if (i64::from(max) - i64::from(min)) as u64 + 1 > MAX_RANGE_CONSTRAINT_LENGTH {
+            panic!("Range constraint is too large.");
+        }
+        Constraint::new_many_item_constraint(id, min..=max)
+    }
+
+    /// Creates a new [Constraint] whose valid values are every value of `universe` not in `excluded`.
+    ///
+    /// values of `excluded` missing from `universe` are ignored.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The [ConstraintIdType] for the [Constraint].
+    /// * `universe` - Every [ValueType] the [Constraint] could allow.
+    /// * `excluded` - The [ValueType]s removed from `universe`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use crate::rusted_dice::constraint_management::Constraint;
+    /// let constraint = Constraint::new_excluding_constraint(1, &[1, 2, 3, 4, 5, 6], &[2, 4]);
+    /// assert_eq!(constraint, Constraint::new_many_item_constraint(1, vec![1, 3, 5, 6]));
+    /// ```
+    pub fn new_excluding_constraint(
+        id: ConstraintIdType,
+        universe: &[ValueType],
+        excluded: &[ValueType],
+    ) -> Constraint {
+        let excluded: ValueTypeSet = excluded.iter().copied().collect();
+        Constraint::new_many_item_constraint(
+            id,
+            universe
+                .iter()
+                .copied()
+                .filter(|value| !excluded.contains(value)),
+        )
+    }
 }
 
 #[cfg(test)]
 mod tests {
+    use crate::constraint_management::IsTheoreticallyPossible;
     use crate::UnsignedSmallValueType;
 
     use super::*;
@@ -115,4 +187,81 @@ mod tests {
             assert_eq!(constraint, Constraint::new_many_item_constraint(test_value, vec![2, 4, 6]));
         }
     }
+
+    #[test]
+    fn test_new_range_constraint() {
+        let constraint = Constraint::new_range_constraint(1, 3, 6);
+        assert_eq!(constraint.id, 1);
+        assert_eq!(constraint.valid_values, HashSet::from([3, 4, 5, 6]));
+    }
+
+    #[test]
+    fn test_new_range_constraint_single_value() {
+        assert_eq!(
+            Constraint::new_range_constraint(2, -4, -4),
+            Constraint::new_single_valid_value_constraint(2, -4)
+        );
+    }
+
+    #[test]
+    fn test_new_range_constraint_min_greater_than_max() {
+        let constraint = Constraint::new_range_constraint(3, 6, 3);
+        assert_eq!(constraint, Constraint::new_empty_constraint(3));
+        assert!(!constraint.is_theoretically_possible());
+    }
+
+    #[test]
+    fn test_new_range_constraint_at_limit() {
+        let constraint =
+            Constraint::new_range_constraint(1, 0, MAX_RANGE_CONSTRAINT_LENGTH as ValueType - 1);
+        assert_eq!(
+            constraint.valid_values.len() as u64,
+            MAX_RANGE_CONSTRAINT_LENGTH
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Range constraint is too large.")]
+    fn test_new_range_constraint_too_large() {
+        Constraint::new_range_constraint(1, ValueType::MIN, ValueType::MAX);
+    }
+
+    #[test]
+    fn test_new_excluding_constraint() {
+        let universe: Vec<ValueType> = (1..=6).collect();
+        let constraint = Constraint::new_excluding_constraint(1, &universe, &[2, 4]);
+        assert_eq!(constraint.id, 1);
+        assert_eq!(constraint.valid_values, HashSet::from([1, 3, 5, 6]));
+    }
+
+    #[test]
+    fn test_new_excluding_constraint_nothing_excluded() {
+        assert_eq!(
+            Constraint::new_excluding_constraint(2, &[1, 2, 2, 3], &[]),
+            Constraint::new_many_item_constraint(2, vec![1, 2, 3])
+        );
+    }
+
+    #[test]
+    fn test_new_excluding_constraint_excluded_outside_universe() {
+        assert_eq!(
+            Constraint::new_excluding_constraint(2, &[1, 2, 3], &[0, 3, 9]),
+            Constraint::new_many_item_constraint(2, vec![1, 2])
+        );
+    }
+
+    #[test]
+    fn test_new_excluding_constraint_everything_excluded() {
+        let constraint = Constraint::new_excluding_constraint(4, &[1, 2, 3], &[3, 2, 1]);
+        assert_eq!(constraint, Constraint::new_empty_constraint(4));
+        assert!(!constraint.is_theoretically_possible());
+    }
+
+    #[test]
+    fn test_new_excluding_constraint_empty_universe() {
+        assert_eq!(
+            Constraint::new_excluding_constraint(4, &[], &[1]),
+            Constraint::new_empty_constraint(4)
+        );
+    }
 }
@@ -0,0 +1,74 @@
+use std::hash::{Hash, Hasher};
+
+use crate::constraint_management::Constraint;
+use crate::ValueType;
+
+impl Hash for Constraint {
+    /// Hash a [Constraint] based on its ID and then its sorted valid values.
+    /// equal [Constraint]s hash identically, whatever order their valid values were added in.
+    ///
+    /// # Arguments
+    ///
+    /// * `self` - The [Constraint] to hash.
+    /// * `state` - The [Hasher] to feed.
+    ///
+    /// # Examples
+    /// ```
+    /// # use crate::rusted_dice::constraint_management::Constraint;
+    /// # use std::collections::HashSet;
+    /// let mut constraints = HashSet::new();
+    /// constraints.insert(Constraint::new_many_item_constraint(1, vec![1, 2, 3]));
+    /// constraints.insert(Constraint::new_many_item_constraint(1, vec![3, 2, 1]));
+    /// assert_eq!(constraints.len(), 1);
+    /// ```
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        let mut valid_values: Vec<&ValueType> = self.valid_values.iter().collect();
+        valid_values.sort();
+
+        self.id.hash(state);
+        valid_values.hash(state);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::hash_map::DefaultHasher;
+    use std::hash::{Hash, Hasher};
+
+    use crate::constraint_management::Constraint;
+
+    fn hash_of(constraint: &Constraint) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        constraint.hash(&mut hasher);
+        hasher.finish()
+    }
+
+    #[test]
+    fn test_hash_equal_constraints() {
+        let constraint_one = Constraint::new_many_item_constraint(3, vec![1, 2, 3, 4, 5, 6, 7, 8]);
+        let constraint_two = Constraint::new_many_item_constraint(3, vec![8, 7, 6, 5, 4, 3, 2, 1]);
+        assert_eq!(constraint_one, constraint_two);
+        assert_eq!(hash_of(&constraint_one), hash_of(&constraint_two));
+    }
+
+    #[test]
+    fn test_hash_equal_empty_constraints() {
+        let constraint_one = Constraint::new_empty_constraint(2);
+        let constraint_two = Constraint::new_many_item_constraint(2, vec![]);
+        assert_eq!(hash_of(&constraint_one), hash_of(&constraint_two));
+    }
+
+    #[test]
+    fn test_hash_different_id() {
+        let constraint_one = Constraint::new_many_item_constraint(1, vec![1, 2]);
+        let constraint_two = Constraint::new_many_item_constraint(2, vec![1, 2]);
+        assert_ne!(hash_of(&constraint_one), hash_of(&constraint_two));
+    }
+
+    #[test]
+    fn test_hash_different_valid_values() {
+        let constraint_one = Constraint::new_many_item_constraint(1, vec![1, 2]);
+        let constraint_two = Constraint::new_many_item_constraint(1, vec![1, 2, 3]);
+        assert_ne!(hash_of(&constraint_one), hash_of(&constraint_two));
+    }
+}
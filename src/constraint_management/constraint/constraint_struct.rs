@@ -35,6 +35,7 @@ use crate::constraint_management::{ConstraintIdType, ValueTypeSet};
 /// let constraint = Constraint { id: 1, valid_values: values };
 /// ```
 #[derive(Debug, Clone, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Constraint {
     pub id: ConstraintIdType,
     pub valid_values: ValueTypeSet,
@@ -92,6 +93,17 @@ mod tests {
         assert!(constraint_one != constraint_two);
     }
 
+    #[test]
+    #[cfg(feature = "serde")]
+    fn test_serde_round_trip() {
+        let constraint = Constraint::new_many_item_constraint(12, vec![-1, 3, 5]);
+        let json = serde_json::to_string(&constraint).unwrap();
+        assert_eq!(
+            serde_json::from_str::<Constraint>(&json).unwrap(),
+            constraint
+        );
+    }
+
     proptest! {
         #[test]
         fn test_fmt(test_id: ConstraintIdType, test_valid_value: ValueType) {
@@ -0,0 +1,101 @@
+use crate::constraint_management::Constraint;
+
+impl Constraint {
+    /// Combines two [Constraint]s with the same id, keeping the union of valid values.
+    /// unlike the addition operator, which keeps the intersection, a value is valid if either [Constraint] allows it.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the `id` of `self` does not match the `id` of `other`.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The [Constraint] to union with.
+    ///
+    /// # Returns
+    ///
+    /// The resulting [Constraint].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use crate::rusted_dice::constraint_management::Constraint;
+    /// let constraint_one = Constraint::new_many_item_constraint(1, vec![1, 2]);
+    /// let constraint_two = Constraint::new_many_item_constraint(1, vec![2, 3]);
+    ///
+    /// assert_eq!(
+    ///     constraint_one.union(&constraint_two),
+    ///     Constraint::new_many_item_constraint(1, vec![1, 2, 3])
+    /// );
+    /// ```
+    pub fn union(&self, other: &Constraint) -> Constraint {
+        if self.id != other.id {
+            panic!("Can not union Constraints with different ids.");
+        }
+        Constraint {
+            id: self.id,
+            valid_values: self
+                .valid_values
+                .union(&other.valid_values)
+                .copied()
+                .collect(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::constraint_management::{Constraint, ConstraintIdType, ValueTypeSet};
+
+    use proptest::prelude::*;
+
+    #[test]
+    #[should_panic(expected = "Can not union Constraints with different ids.")]
+    fn panic_on_different_id_union() {
+        let constraint_one = Constraint::new_empty_constraint(0);
+        let constraint_two = Constraint::new_empty_constraint(1);
+        let _ = constraint_one.union(&constraint_two);
+    }
+
+    #[test]
+    fn union_overlap() {
+        let constraint_one = Constraint::new_many_item_constraint(1, vec![1, 2]);
+        let constraint_two = Constraint::new_many_item_constraint(1, vec![2, 3]);
+
+        assert_eq!(
+            constraint_one.union(&constraint_two).valid_values,
+            vec![1, 2, 3].into_iter().collect()
+        );
+    }
+
+    #[test]
+    fn union_with_empty() {
+        let constraint_one = Constraint::new_many_item_constraint(7, vec![4, 5]);
+        let constraint_two = Constraint::new_empty_constraint(7);
+
+        assert_eq!(constraint_one.union(&constraint_two), constraint_one);
+        assert_eq!(constraint_two.union(&constraint_one), constraint_one);
+    }
+
+    proptest! {
+        #[test]
+        fn union_contains_both(
+            id: ConstraintIdType,
+            valid_values_one: ValueTypeSet,
+            valid_values_two: ValueTypeSet
+        ) {
+            let constraint_one = Constraint::new_many_item_constraint(id, valid_values_one.clone());
+            let constraint_two = Constraint::new_many_item_constraint(id, valid_values_two.clone());
+
+            let result = constraint_one.union(&constraint_two);
+            assert_eq!(result.id, id);
+            assert!(result.valid_values.is_superset(&valid_values_one));
+            assert!(result.valid_values.is_superset(&valid_values_two));
+            assert_eq!(result, constraint_two.union(&constraint_one));
+            assert!(result
+                .valid_values
+                .iter()
+                .all(|value| valid_values_one.contains(value) || valid_values_two.contains(value)));
+        }
+    }
+}
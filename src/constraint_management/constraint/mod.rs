@@ -1,10 +1,13 @@
 pub mod constraint_add;
 pub mod constraint_add_assign;
 pub mod constraint_factory;
+pub mod constraint_hash;
 pub mod constraint_ord;
 pub mod constraint_possibility;
 pub mod constraint_struct;
+pub mod constraint_union;
 pub mod valid_value_set_helpers;
 
+pub use self::constraint_factory::MAX_RANGE_CONSTRAINT_LENGTH;
 pub use self::constraint_struct::Constraint;
 pub use self::valid_value_set_helpers::combine_valid_value_sets;
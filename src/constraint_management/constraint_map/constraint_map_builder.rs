@@ -0,0 +1,175 @@
+use std::collections::HashMap;
+
+use crate::constraint_management::{Constraint, ConstraintIdToConstraintHashMap, ConstraintMap};
+
+use super::add_constraint_to_map;
+
+/// Builds a [ConstraintMap] one [Constraint] at a time.
+///
+/// [Constraint]s with the same id are combined the same way as [ConstraintMap::new_constraint_map].
+///
+/// # Example
+///
+/// ```
+/// # use crate::rusted_dice::constraint_management::Constraint;
+/// # use crate::rusted_dice::constraint_management::ConstraintMapBuilder;
+/// let mut builder = ConstraintMapBuilder::new()
+///     .with_constraint(Constraint::new_many_item_constraint(1, vec![1, 2, 3]));
+/// for id in 2..4 {
+///     builder = builder.with_constraint(Constraint::new_single_valid_value_constraint(id, 4));
+/// }
+/// let constraint_map = builder
+///     .with_constraint(Constraint::new_many_item_constraint(1, vec![2, 3, 4]))
+///     .build();
+///
+/// assert_eq!(constraint_map.map.len(), 3);
+/// assert_eq!(
+///     constraint_map.map.get(&1).unwrap().valid_values,
+///     vec![2, 3].into_iter().collect()
+/// );
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct ConstraintMapBuilder {
+    map: ConstraintIdToConstraintHashMap,
+}
+
+impl ConstraintMapBuilder {
+    /// Creates a new [ConstraintMapBuilder] without any [Constraint]s.
+    ///
+    /// # Returns
+    ///
+    /// The new [ConstraintMapBuilder].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use crate::rusted_dice::constraint_management::ConstraintMapBuilder;
+    /// let constraint_map = ConstraintMapBuilder::new().build();
+    /// assert!(constraint_map.map.is_empty());
+    /// ```
+    pub fn new() -> ConstraintMapBuilder {
+        ConstraintMapBuilder {
+            map: HashMap::new(),
+        }
+    }
+
+    /// Adds a [Constraint] to the [ConstraintMapBuilder].
+    /// if a [Constraint] with the same id was already added the two are combined.
+    ///
+    /// # Arguments
+    ///
+    /// * `constraint` - the [Constraint] to add.
+    ///
+    /// # Returns
+    ///
+    /// The [ConstraintMapBuilder] with the [Constraint] added.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use crate::rusted_dice::constraint_management::Constraint;
+    /// # use crate::rusted_dice::constraint_management::ConstraintMapBuilder;
+    /// let constraint_map = ConstraintMapBuilder::new()
+    ///     .with_constraint(Constraint::new_single_valid_value_constraint(1, 3))
+    ///     .build();
+    /// assert!(constraint_map.map.contains_key(&1));
+    /// ```
+    pub fn with_constraint(mut self, constraint: Constraint) -> ConstraintMapBuilder {
+        add_constraint_to_map(&mut self.map, constraint);
+        self
+    }
+
+    /// Builds the [ConstraintMap].
+    ///
+    /// # Returns
+    ///
+    /// The new [ConstraintMap] containing every added [Constraint].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use crate::rusted_dice::constraint_management::Constraint;
+    /// # use crate::rusted_dice::constraint_management::ConstraintMap;
+    /// # use crate::rusted_dice::constraint_management::ConstraintMapBuilder;
+    /// let constraint = Constraint::new_many_item_constraint(2, vec![1, 2]);
+    /// let constraint_map = ConstraintMapBuilder::new()
+    ///     .with_constraint(constraint.clone())
+    ///     .build();
+    /// assert_eq!(constraint_map, ConstraintMap::new_single_constraint_constraint_map(constraint));
+    /// ```
+    pub fn build(self) -> ConstraintMap {
+        ConstraintMap { map: self.map }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+
+    use crate::constraint_management::{Constraint, ConstraintMap, ConstraintMapBuilder};
+
+    #[test]
+    fn test_build_empty() {
+        assert_eq!(
+            ConstraintMapBuilder::new().build(),
+            ConstraintMap::new_empty_constraint_map()
+        );
+        assert_eq!(
+            ConstraintMapBuilder::default().build(),
+            ConstraintMap::new_empty_constraint_map()
+        );
+    }
+
+    #[test]
+    fn test_same_id_constraints_intersect_like_new_constraint_map() {
+        let constraints = vec![
+            Constraint::new_many_item_constraint(1, vec![1, 2, 3, 4]),
+            Constraint::new_many_item_constraint(2, vec![5, 6]),
+            Constraint::new_many_item_constraint(1, vec![2, 3, 4, 5]),
+            Constraint::new_many_item_constraint(1, vec![3, 4, 5, 6]),
+        ];
+
+        let built = constraints
+            .iter()
+            .cloned()
+            .fold(ConstraintMapBuilder::new(), |builder, constraint| {
+                builder.with_constraint(constraint)
+            })
+            .build();
+
+        assert_eq!(built, ConstraintMap::new_constraint_map(constraints));
+        assert_eq!(
+            built.map.get(&1).unwrap().valid_values,
+            vec![3, 4].into_iter().collect()
+        );
+    }
+
+    #[test]
+    fn test_disjoint_same_id_constraints_become_impossible() {
+        let constraint_map = ConstraintMapBuilder::new()
+            .with_constraint(Constraint::new_single_valid_value_constraint(1, 1))
+            .with_constraint(Constraint::new_single_valid_value_constraint(1, 2))
+            .build();
+        assert!(constraint_map.map.get(&1).unwrap().valid_values.is_empty());
+    }
+
+    proptest! {
+        #[test]
+        fn test_builder_matches_new_constraint_map(
+            constraints in prop::collection::vec(
+                (0u16..4, prop::collection::vec(-5i32..5, 0..6)),
+                0..8,
+            )
+        ) {
+            let constraints: Vec<Constraint> = constraints
+                .into_iter()
+                .map(|(id, values)| Constraint::new_many_item_constraint(id, values))
+                .collect();
+            let mut builder = ConstraintMapBuilder::new();
+            for constraint in constraints.iter().cloned() {
+                builder = builder.with_constraint(constraint);
+            }
+            prop_assert_eq!(builder.build(), ConstraintMap::new_constraint_map(constraints));
+        }
+    }
+}
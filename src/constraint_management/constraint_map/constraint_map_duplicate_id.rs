@@ -0,0 +1,55 @@
+use std::error::Error;
+use std::fmt;
+
+use crate::constraint_management::ConstraintIdType;
+
+/// The error returned when a [ConstraintMap](crate::constraint_management::ConstraintMap) is built
+/// from [Constraint](crate::constraint_management::Constraint)s that are expected to have distinct ids,
+/// but two of them share an id.
+///
+/// holds the [ConstraintIdType] that was found more than once.
+///
+/// # Example
+///
+/// ```
+/// # use crate::rusted_dice::constraint_management::DuplicateId;
+/// let duplicate_id = DuplicateId(3);
+/// assert_eq!(duplicate_id.to_string(), "Constraint id 3 is used more than once.");
+/// ```
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct DuplicateId(pub ConstraintIdType);
+
+impl fmt::Display for DuplicateId {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            formatter,
+            "Constraint id {} is used more than once.",
+            self.0
+        )
+    }
+}
+
+impl Error for DuplicateId {}
+
+#[cfg(test)]
+mod tests {
+    use super::DuplicateId;
+
+    #[test]
+    fn test_duplicate_id_display() {
+        assert_eq!(
+            DuplicateId(0).to_string(),
+            "Constraint id 0 is used more than once."
+        );
+        assert_eq!(
+            DuplicateId(u16::MAX).to_string(),
+            "Constraint id 65535 is used more than once."
+        );
+    }
+
+    #[test]
+    fn test_duplicate_id_is_error() {
+        let error: Box<dyn std::error::Error> = Box::new(DuplicateId(7));
+        assert_eq!(error.to_string(), "Constraint id 7 is used more than once.");
+    }
+}
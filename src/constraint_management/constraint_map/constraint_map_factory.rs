@@ -1,6 +1,8 @@
 use std::collections::HashMap;
 
-use crate::constraint_management::{Constraint, ConstraintIdToConstraintHashMap, ConstraintMap};
+use crate::constraint_management::{
+    Constraint, ConstraintIdToConstraintHashMap, ConstraintMap, DuplicateId,
+};
 
 use super::add_constraint_to_map;
 
@@ -23,6 +25,30 @@ impl ConstraintMap {
         ConstraintMap { map }
     }
 
+    /// Creates a new empty [ConstraintMap] with space pre-allocated for at least `capacity` [Constraint]s.
+    /// useful to avoid rehashing when many [Constraint]s are about to be added.
+    ///
+    /// # Arguments
+    ///
+    /// * `capacity`: the number of [Constraint]s to pre-allocate space for.
+    ///
+    /// # Returns
+    ///
+    /// The new empty [ConstraintMap].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use crate::rusted_dice::constraint_management::ConstraintMap;
+    /// let constraint_map = ConstraintMap::with_capacity(10);
+    /// assert!(constraint_map.map.is_empty());
+    /// assert!(constraint_map.map.capacity() >= 10);
+    /// ```
+    pub fn with_capacity(capacity: usize) -> ConstraintMap {
+        let map: ConstraintIdToConstraintHashMap = HashMap::with_capacity(capacity);
+        ConstraintMap { map }
+    }
+
     /// Creates a new [ConstraintMap] with the given [Constraint].
     ///
     /// # Arguments
@@ -86,6 +112,49 @@ impl ConstraintMap {
         }
         ConstraintMap { map }
     }
+
+    /// Creates a new [ConstraintMap] from an iterator of [Constraint]s with distinct IDs.
+    ///
+    /// unlike [ConstraintMap::new_constraint_map], [Constraint]s sharing an ID are not merged,
+    /// instead the first repeated ID is returned as an error.
+    ///
+    /// # Arguments
+    ///
+    /// * 'constraints': An iterator of [Constraint] items.
+    ///
+    /// # Returns
+    ///
+    /// The new [ConstraintMap] containing the [Constraint]s,
+    /// or a [DuplicateId] error if two [Constraint]s share an ID.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use crate::rusted_dice::constraint_management::{Constraint, ConstraintMap, DuplicateId};
+    /// let constraint_map = ConstraintMap::try_new_constraint_map(vec![
+    ///     Constraint::new_single_valid_value_constraint(1, 1),
+    ///     Constraint::new_single_valid_value_constraint(2, 2),
+    /// ]);
+    /// assert!(constraint_map.is_ok());
+    ///
+    /// let constraint_map = ConstraintMap::try_new_constraint_map(vec![
+    ///     Constraint::new_single_valid_value_constraint(1, 1),
+    ///     Constraint::new_single_valid_value_constraint(1, 2),
+    /// ]);
+    /// assert_eq!(constraint_map, Err(DuplicateId(1)));
+    /// ```
+    pub fn try_new_constraint_map(
+        constraints: impl IntoIterator<Item = Constraint>,
+    ) -> Result<ConstraintMap, DuplicateId> {
+        let mut map: ConstraintIdToConstraintHashMap = HashMap::new();
+        for constraint in constraints {
+            let id = constraint.id;
+            if map.insert(id, constraint).is_some() {
+                return Err(DuplicateId(id));
+            }
+        }
+        Ok(ConstraintMap { map })
+    }
 }
 
 #[cfg(test)]
@@ -100,6 +169,30 @@ mod tests {
         assert_eq!(constraint_map, ConstraintMap::new_empty_constraint_map());
     }
 
+    #[test]
+    fn test_with_capacity() {
+        let constraint_map = ConstraintMap::with_capacity(64);
+        assert!(constraint_map.map.capacity() >= 64);
+        assert_eq!(constraint_map, ConstraintMap::new_empty_constraint_map());
+    }
+
+    #[test]
+    fn test_with_capacity_filled_equals_new_constraint_map() {
+        let constraints: Vec<Constraint> = (0..100)
+            .map(|id| Constraint::new_many_item_constraint(id % 50, vec![1, 2, 3, id.into()]))
+            .collect();
+
+        let mut constraint_map = ConstraintMap::with_capacity(constraints.len());
+        for constraint in constraints.clone() {
+            add_constraint_to_map(&mut constraint_map.map, constraint);
+        }
+
+        assert_eq!(
+            constraint_map,
+            ConstraintMap::new_constraint_map(constraints)
+        );
+    }
+
     #[test]
     fn test_new_constraint_map() {
         let constraint3_123 = Constraint::new_many_item_constraint(3, vec![1, 2, 3]);
@@ -186,4 +279,39 @@ mod tests {
             ConstraintMap::new_constraint_map(constraint_iter)
         );
     }
+
+    #[test]
+    fn test_try_new_constraint_map_no_constraint() {
+        assert_eq!(
+            ConstraintMap::try_new_constraint_map(vec![]),
+            Ok(ConstraintMap::new_empty_constraint_map())
+        );
+    }
+
+    #[test]
+    fn test_try_new_constraint_map_distinct_ids() {
+        let constraints = vec![
+            Constraint::new_many_item_constraint(1, vec![1, 2, 3]),
+            Constraint::new_many_item_constraint(2, vec![4]),
+            Constraint::new_many_item_constraint(3, vec![5, 6]),
+        ];
+        assert_eq!(
+            ConstraintMap::try_new_constraint_map(constraints.clone()),
+            Ok(ConstraintMap::new_constraint_map(constraints))
+        );
+    }
+
+    #[test]
+    fn test_try_new_constraint_map_duplicate_id() {
+        let constraints = vec![
+            Constraint::new_many_item_constraint(1, vec![1, 2, 3]),
+            Constraint::new_many_item_constraint(2, vec![4]),
+            Constraint::new_many_item_constraint(2, vec![4]),
+            Constraint::new_many_item_constraint(1, vec![5, 6]),
+        ];
+        assert_eq!(
+            ConstraintMap::try_new_constraint_map(constraints),
+            Err(DuplicateId(2))
+        );
+    }
 }
@@ -0,0 +1,91 @@
+use std::hash::{Hash, Hasher};
+
+use crate::constraint_management::{Constraint, ConstraintMap};
+
+impl Hash for ConstraintMap {
+    /// Hash a [ConstraintMap] based on its [Constraint]s in sorted order.
+    /// equal [ConstraintMap]s hash identically, whatever order their [Constraint]s were added in.
+    ///
+    /// # Arguments
+    ///
+    /// * `self` - The [ConstraintMap] to hash.
+    /// * `state` - The [Hasher] to feed.
+    ///
+    /// # Examples
+    /// ```
+    /// # use crate::rusted_dice::constraint_management::Constraint;
+    /// # use crate::rusted_dice::constraint_management::ConstraintMap;
+    /// # use std::collections::HashSet;
+    /// let mut constraint_maps = HashSet::new();
+    /// constraint_maps.insert(ConstraintMap::new_constraint_map(vec![
+    ///     Constraint::new_many_item_constraint(1, vec![1, 2, 3]),
+    ///     Constraint::new_many_item_constraint(2, vec![4, 5]),
+    /// ]));
+    /// constraint_maps.insert(ConstraintMap::new_constraint_map(vec![
+    ///     Constraint::new_many_item_constraint(2, vec![5, 4]),
+    ///     Constraint::new_many_item_constraint(1, vec![3, 2, 1]),
+    /// ]));
+    /// assert_eq!(constraint_maps.len(), 1);
+    /// ```
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        let mut constraints: Vec<&Constraint> = self.map.values().collect();
+        constraints.sort();
+
+        constraints.hash(state);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::hash_map::DefaultHasher;
+    use std::hash::{Hash, Hasher};
+
+    use crate::constraint_management::{Constraint, ConstraintMap};
+
+    fn hash_of(constraint_map: &ConstraintMap) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        constraint_map.hash(&mut hasher);
+        hasher.finish()
+    }
+
+    #[test]
+    fn test_hash_empty() {
+        assert_eq!(
+            hash_of(&ConstraintMap::new_empty_constraint_map()),
+            hash_of(&ConstraintMap::with_capacity(64))
+        );
+    }
+
+    #[test]
+    fn test_hash_equal_constraint_maps() {
+        let constraints: Vec<Constraint> = (0..16)
+            .map(|id| Constraint::new_many_item_constraint(id, vec![id.into(), 100]))
+            .collect();
+        let constraint_map_one = ConstraintMap::new_constraint_map(constraints.clone());
+        let constraint_map_two = ConstraintMap::new_constraint_map(constraints.into_iter().rev());
+
+        assert_eq!(constraint_map_one, constraint_map_two);
+        assert_eq!(hash_of(&constraint_map_one), hash_of(&constraint_map_two));
+    }
+
+    #[test]
+    fn test_hash_different_constraint_maps() {
+        let constraint_map_one =
+            ConstraintMap::new_constraint_map(vec![Constraint::new_many_item_constraint(
+                1,
+                vec![1, 2],
+            )]);
+        let constraint_map_two = ConstraintMap::new_constraint_map(vec![
+            Constraint::new_many_item_constraint(1, vec![1, 2]),
+            Constraint::new_many_item_constraint(2, vec![1, 2]),
+        ]);
+        let constraint_map_three =
+            ConstraintMap::new_constraint_map(vec![Constraint::new_many_item_constraint(
+                1,
+                vec![1],
+            )]);
+
+        assert_ne!(hash_of(&constraint_map_one), hash_of(&constraint_map_two));
+        assert_ne!(hash_of(&constraint_map_one), hash_of(&constraint_map_three));
+    }
+}
@@ -36,10 +36,10 @@ impl IsTheoreticallyPossible for ConstraintMap {
     /// assert!(!constraint_map_two.is_theoretically_possible());
     /// ```
     fn is_theoretically_possible(&self) -> bool {
-        return !self
+        !self
             .map
             .iter()
-            .any(|(_, constraint)| !constraint.is_theoretically_possible());
+            .any(|(_, constraint)| !constraint.is_theoretically_possible())
     }
 }
 
@@ -77,12 +77,45 @@ impl AreConstraintsCompiledWith for ConstraintMap {
     /// assert!(!constraint_map_one.is_compliant_with(id_value_map));
     /// ```
     fn is_compliant_with(&self, id_value_map: IdToValueMap) -> bool {
-        for (id, value) in &id_value_map {
-            if self.map.contains_key(id) && !self.map[id].valid_values.contains(value) {
-                return false;
-            }
-        }
-        true
+        self.are_constraints_compiled_with(&id_value_map)
+    }
+}
+
+impl ConstraintMap {
+    /// Checks if the [ConstraintMap] is compliant with a specific [IdToValueMap], without taking ownership of it.
+    ///
+    /// an id absent from the [ConstraintMap] is unconstrained, so any value for it is compliant.
+    /// an id absent from the [IdToValueMap] is not checked.
+    ///
+    /// # Arguments
+    ///
+    /// * `values` - The [IdToValueMap] map to check compliance with.
+    ///
+    /// # Returns
+    ///
+    /// `true` if every id in both the [ConstraintMap] and `values` has its value in the valid values, `false` otherwise.
+    ///
+    /// # Examples
+    /// ```
+    /// # use std::collections::HashMap;
+    /// # use crate::rusted_dice::constraint_management::Constraint;
+    /// # use crate::rusted_dice::constraint_management::ConstraintMap;
+    /// # use crate::rusted_dice::constraint_management::IdToValueMap;
+    /// let constraint_map = ConstraintMap::new_constraint_map(
+    ///     vec![Constraint::new_many_item_constraint(1, vec![1, 2, 3])]
+    /// );
+    /// let passing: IdToValueMap = HashMap::from([(1, 2), (9, 100)]);
+    /// let failing: IdToValueMap = HashMap::from([(1, 4)]);
+    ///
+    /// assert!(constraint_map.are_constraints_compiled_with(&passing));
+    /// assert!(!constraint_map.are_constraints_compiled_with(&failing));
+    /// ```
+    pub fn are_constraints_compiled_with(&self, values: &IdToValueMap) -> bool {
+        values.iter().all(|(id, value)| {
+            self.map
+                .get(id)
+                .is_none_or(|constraint| constraint.valid_values.contains(value))
+        })
     }
 }
 
@@ -220,4 +253,53 @@ mod tests {
         id_value_map.insert(3, 4);
         assert!(!constraint_map.is_compliant_with(id_value_map));
     }
+
+    #[test]
+    fn are_constraints_compiled_with_empty() {
+        let constraint_map = ConstraintMap::new_empty_constraint_map();
+        assert!(constraint_map.are_constraints_compiled_with(&HashMap::new()));
+        assert!(constraint_map.are_constraints_compiled_with(&HashMap::from([(1, 1)])));
+
+        let constraint_map =
+            ConstraintMap::new_constraint_map(vec![Constraint::new_many_item_constraint(
+                1,
+                vec![1, 2, 3],
+            )]);
+        assert!(constraint_map.are_constraints_compiled_with(&HashMap::new()));
+    }
+
+    #[test]
+    fn are_constraints_compiled_with_absent_id_unconstrained() {
+        let constraint_map =
+            ConstraintMap::new_constraint_map(vec![Constraint::new_many_item_constraint(
+                1,
+                vec![1, 2, 3],
+            )]);
+        let id_value_map: IdToValueMap = HashMap::from([(2, 100), (3, -100)]);
+        assert!(constraint_map.are_constraints_compiled_with(&id_value_map));
+    }
+
+    #[test]
+    fn are_constraints_compiled_with_passing_and_failing() {
+        let constraint_map = ConstraintMap::new_constraint_map(vec![
+            Constraint::new_many_item_constraint(1, vec![1, 2, 3]),
+            Constraint::new_many_item_constraint(2, vec![4, 5]),
+        ]);
+
+        let passing: IdToValueMap = HashMap::from([(1, 3), (2, 4), (7, 7)]);
+        assert!(constraint_map.are_constraints_compiled_with(&passing));
+        assert!(constraint_map.is_compliant_with(passing));
+
+        let failing: IdToValueMap = HashMap::from([(1, 3), (2, 6)]);
+        assert!(!constraint_map.are_constraints_compiled_with(&failing));
+        assert!(!constraint_map.is_compliant_with(failing));
+    }
+
+    #[test]
+    fn are_constraints_compiled_with_impossible_constraint() {
+        let constraint_map =
+            ConstraintMap::new_constraint_map(vec![Constraint::new_empty_constraint(1)]);
+        assert!(!constraint_map.are_constraints_compiled_with(&HashMap::from([(1, 1)])));
+        assert!(constraint_map.are_constraints_compiled_with(&HashMap::from([(2, 1)])));
+    }
 }
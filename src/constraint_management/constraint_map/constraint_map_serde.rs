@@ -0,0 +1,92 @@
+use serde::de::Error;
+use serde::{Deserialize, Deserializer};
+
+use crate::constraint_management::{ConstraintIdToConstraintHashMap, ConstraintMap};
+
+/// the serialized form of a [ConstraintMap], before its keys are checked.
+#[derive(Deserialize)]
+struct UncheckedConstraintMap {
+    map: ConstraintIdToConstraintHashMap,
+}
+
+impl<'de> Deserialize<'de> for ConstraintMap {
+    /// Deserializes a [ConstraintMap] from its `{map}` form.
+    /// every key must be the id of its [Constraint](crate::constraint_management::Constraint),
+    /// otherwise the input is rejected.
+    ///
+    /// # Arguments
+    ///
+    /// * `deserializer` - The [Deserializer] to read from.
+    ///
+    /// # Returns
+    ///
+    /// The new [ConstraintMap] or the error of the [Deserializer].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use crate::rusted_dice::constraint_management::ConstraintMap;
+    /// let constraint_map: ConstraintMap =
+    ///     serde_json::from_str("{\"map\":{\"1\":{\"id\":1,\"valid_values\":[2]}}}").unwrap();
+    /// assert!(constraint_map.map.get(&1).unwrap().valid_values.contains(&2));
+    ///
+    /// assert!(serde_json::from_str::<ConstraintMap>(
+    ///     "{\"map\":{\"1\":{\"id\":2,\"valid_values\":[2]}}}"
+    /// )
+    /// .is_err());
+    /// ```
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let unchecked = UncheckedConstraintMap::deserialize(deserializer)?;
+        for (id, constraint) in unchecked.map.iter() {
+            if *id != constraint.id {
+                return Err(D::Error::custom(format!(
+                    "Constraint key {} does not match its id {}.",
+                    id, constraint.id
+                )));
+            }
+        }
+        Ok(ConstraintMap { map: unchecked.map })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::constraint_management::{Constraint, ConstraintMap};
+
+    #[test]
+    fn test_deserialize_empty() {
+        let constraint_map: ConstraintMap = serde_json::from_str("{\"map\":{}}").unwrap();
+        assert_eq!(constraint_map, ConstraintMap::new_empty_constraint_map());
+    }
+
+    #[test]
+    fn test_serde_round_trip() {
+        let constraint_map = ConstraintMap::new_constraint_map(vec![
+            Constraint::new_many_item_constraint(1, vec![1, 2, 3]),
+            Constraint::new_single_valid_value_constraint(7, -4),
+            Constraint::new_empty_constraint(9),
+        ]);
+        let json = serde_json::to_string(&constraint_map).unwrap();
+        assert_eq!(
+            serde_json::from_str::<ConstraintMap>(&json).unwrap(),
+            constraint_map
+        );
+    }
+
+    #[test]
+    fn test_deserialize_mismatched_key() {
+        let error = serde_json::from_str::<ConstraintMap>(
+            "{\"map\":{\"1\":{\"id\":1,\"valid_values\":[1]},\"3\":{\"id\":2,\"valid_values\":[]}}}",
+        )
+        .unwrap_err();
+        assert!(error
+            .to_string()
+            .starts_with("Constraint key 3 does not match its id 2."));
+    }
+
+    #[test]
+    fn test_deserialize_invalid() {
+        assert!(serde_json::from_str::<ConstraintMap>("{}").is_err());
+        assert!(serde_json::from_str::<ConstraintMap>("{\"map\":[]}").is_err());
+    }
+}
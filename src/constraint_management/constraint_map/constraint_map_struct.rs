@@ -54,6 +54,7 @@ use crate::constraint_management::ConstraintIdToConstraintHashMap;
 /// ```
 #[allow(dead_code)]
 #[derive(Debug, Clone, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct ConstraintMap {
     pub map: ConstraintIdToConstraintHashMap,
 }
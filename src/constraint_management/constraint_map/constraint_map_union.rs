@@ -0,0 +1,124 @@
+use crate::constraint_management::ConstraintMap;
+
+impl ConstraintMap {
+    /// Combines two [ConstraintMap]s, keeping the union of valid values for each id.
+    /// the [Constraint](crate::constraint_management::Constraint)s of matching ids are combined with
+    /// [Constraint::union](crate::constraint_management::Constraint::union).
+    ///
+    /// an id missing from either [ConstraintMap] is unconstrained there,
+    /// so the union for that id is unconstrained and the id is not kept.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The [ConstraintMap] to union with.
+    ///
+    /// # Returns
+    ///
+    /// The resulting [ConstraintMap].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use crate::rusted_dice::constraint_management::Constraint;
+    /// # use crate::rusted_dice::constraint_management::ConstraintMap;
+    /// let constraint_map_one = ConstraintMap::new_constraint_map(
+    ///     vec![
+    ///        Constraint::new_many_item_constraint(1, vec![1, 2]),
+    ///        Constraint::new_many_item_constraint(2, vec![1, 2, 3])
+    ///     ]
+    /// );
+    /// let constraint_map_two = ConstraintMap::new_constraint_map(
+    ///     vec![
+    ///        Constraint::new_many_item_constraint(1, vec![2, 3])
+    ///     ]
+    /// );
+    /// let constraint_map_three = ConstraintMap::new_constraint_map(
+    ///     vec![
+    ///        Constraint::new_many_item_constraint(1, vec![1, 2, 3])
+    ///     ]
+    /// );
+    ///
+    /// assert_eq!(constraint_map_one.union(&constraint_map_two), constraint_map_three);
+    /// ```
+    pub fn union(&self, other: &ConstraintMap) -> ConstraintMap {
+        ConstraintMap {
+            map: self
+                .map
+                .iter()
+                .filter_map(|(id, constraint)| {
+                    other
+                        .map
+                        .get(id)
+                        .map(|other_constraint| (*id, constraint.union(other_constraint)))
+                })
+                .collect(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::constraint_management::{Constraint, ConstraintMap};
+
+    #[test]
+    fn union_empty() {
+        let constraint_map_one = ConstraintMap::new_empty_constraint_map();
+        let constraint_map_two =
+            ConstraintMap::new_constraint_map(vec![Constraint::new_many_item_constraint(
+                1,
+                vec![1, 2],
+            )]);
+
+        assert_eq!(
+            constraint_map_one.union(&constraint_map_two),
+            ConstraintMap::new_empty_constraint_map()
+        );
+        assert_eq!(
+            constraint_map_two.union(&constraint_map_one),
+            ConstraintMap::new_empty_constraint_map()
+        );
+    }
+
+    #[test]
+    fn union_matching_id() {
+        let constraint_map_one =
+            ConstraintMap::new_constraint_map(vec![Constraint::new_many_item_constraint(
+                1,
+                vec![1, 2],
+            )]);
+        let constraint_map_two =
+            ConstraintMap::new_constraint_map(vec![Constraint::new_many_item_constraint(
+                1,
+                vec![2, 3],
+            )]);
+
+        assert_eq!(
+            constraint_map_one.union(&constraint_map_two),
+            ConstraintMap::new_constraint_map(vec![Constraint::new_many_item_constraint(
+                1,
+                vec![1, 2, 3]
+            )])
+        );
+    }
+
+    #[test]
+    fn union_many_ids() {
+        let constraint_map_one = ConstraintMap::new_constraint_map(vec![
+            Constraint::new_many_item_constraint(1, vec![1]),
+            Constraint::new_many_item_constraint(2, vec![4, 5]),
+            Constraint::new_many_item_constraint(3, vec![6]),
+        ]);
+        let constraint_map_two = ConstraintMap::new_constraint_map(vec![
+            Constraint::new_many_item_constraint(2, vec![7]),
+            Constraint::new_empty_constraint(3),
+            Constraint::new_many_item_constraint(4, vec![1]),
+        ]);
+
+        let expected = ConstraintMap::new_constraint_map(vec![
+            Constraint::new_many_item_constraint(2, vec![4, 5, 7]),
+            Constraint::new_many_item_constraint(3, vec![6]),
+        ]);
+        assert_eq!(constraint_map_one.union(&constraint_map_two), expected);
+        assert_eq!(constraint_map_two.union(&constraint_map_one), expected);
+    }
+}
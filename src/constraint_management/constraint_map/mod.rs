@@ -2,11 +2,19 @@ pub mod constraint_map_add;
 pub mod constraint_map_add_assign;
 pub mod constraint_map_add_assign_constraint;
 pub mod constraint_map_add_constraint;
+pub mod constraint_map_builder;
+pub mod constraint_map_duplicate_id;
 pub mod constraint_map_factory;
+pub mod constraint_map_hash;
 pub mod constraint_map_ord;
 pub mod constraint_map_possibility;
+#[cfg(feature = "serde")]
+pub mod constraint_map_serde;
 pub mod constraint_map_struct;
+pub mod constraint_map_union;
 pub mod id_to_constraint_hashmap_helpers;
 
+pub use self::constraint_map_builder::ConstraintMapBuilder;
+pub use self::constraint_map_duplicate_id::DuplicateId;
 pub use self::constraint_map_struct::ConstraintMap;
 pub use self::id_to_constraint_hashmap_helpers::add_constraint_to_map;
@@ -5,9 +5,12 @@ pub mod types;
 
 pub use self::constraint::combine_valid_value_sets;
 pub use self::constraint::Constraint;
+pub use self::constraint::MAX_RANGE_CONSTRAINT_LENGTH;
 
 pub use self::constraint_map::add_constraint_to_map;
 pub use self::constraint_map::ConstraintMap;
+pub use self::constraint_map::ConstraintMapBuilder;
+pub use self::constraint_map::DuplicateId;
 
 pub use self::traits::AreConstraintsCompiledWith;
 pub use self::traits::IsConstraintCompiledWith;
@@ -3,6 +3,8 @@ extern crate prettytable;
 pub mod constraint_management;
 mod integration_tests;
 pub mod probability;
+#[cfg(feature = "proptest")]
+pub mod proptest_strategy;
 
 pub mod types;
 
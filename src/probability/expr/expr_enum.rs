@@ -0,0 +1,57 @@
+use crate::ValueType;
+
+/// A dice expression tree, evaluated into a [ProbabilityDistribution](crate::probability::ProbabilityDistribution)
+/// with [Expr::evaluate].
+///
+/// # Examples
+/// ```
+/// # use crate::rusted_dice::probability::Expr;
+/// // 4d6 keep highest 3 plus 2
+/// let expr = Expr::Add(
+///     Box::new(Expr::KeepHighest(Box::new(Expr::Dice(4, 6)), 3)),
+///     Box::new(Expr::Const(2)),
+/// );
+/// ```
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum Expr {
+    /// rolling M, N sided dice and summing them, see
+    /// [ProbabilityDistribution::new_multiple_dice](crate::probability::ProbabilityDistribution::new_multiple_dice).
+    Dice(u16, ValueType),
+    /// a constant value.
+    Const(ValueType),
+    /// the sum of two expressions.
+    Add(Box<Expr>, Box<Expr>),
+    /// the first expression minus the second.
+    Sub(Box<Expr>, Box<Expr>),
+    /// the product of two expressions.
+    Mul(Box<Expr>, Box<Expr>),
+    /// the sum of the highest N dice of a [Expr::Dice] pool.
+    /// any other expression is a single roll, so keeping one or more keeps it and keeping none gives 0.
+    KeepHighest(Box<Expr>, u16),
+    /// the negation of an expression.
+    Neg(Box<Expr>),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Expr;
+
+    #[test]
+    fn test_clone() {
+        let expr = Expr::Neg(Box::new(Expr::Sub(
+            Box::new(Expr::Dice(2, 6)),
+            Box::new(Expr::Const(1)),
+        )));
+        assert_eq!(expr.clone(), expr);
+    }
+
+    #[test]
+    fn test_eq() {
+        assert_eq!(Expr::Dice(2, 6), Expr::Dice(2, 6));
+        assert_ne!(Expr::Dice(2, 6), Expr::Dice(6, 2));
+        assert_ne!(
+            Expr::Add(Box::new(Expr::Const(1)), Box::new(Expr::Const(2))),
+            Expr::Add(Box::new(Expr::Const(2)), Box::new(Expr::Const(1)))
+        );
+    }
+}
@@ -0,0 +1,137 @@
+use crate::probability::pool_aggregator::KeepHighest;
+use crate::probability::{Expr, ProbabilityDistribution};
+
+impl Expr {
+    /// Evaluates the [Expr] into a [ProbabilityDistribution].
+    /// each node is built from its children using the [ProbabilityDistribution] operators,
+    /// [Expr::KeepHighest] of [Expr::Dice] uses [ProbabilityDistribution::new_pool].
+    ///
+    /// # Arguments
+    ///
+    /// * `self` - The [Expr] to evaluate.
+    ///
+    /// # Returns
+    ///
+    /// The new [ProbabilityDistribution].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use crate::rusted_dice::probability::{Expr, ProbabilityDistribution, ToTable};
+    /// let expr = Expr::Sub(
+    ///     Box::new(Expr::KeepHighest(Box::new(Expr::Dice(2, 4)), 1)),
+    ///     Box::new(Expr::Const(1)),
+    /// );
+    ///
+    /// assert_eq!(
+    ///     expr.evaluate()
+    ///         .to_table()
+    ///         .to_string()
+    ///         .replace("\r\n", "\n"),
+    ///     "\
+    ///     +-------+-------+\n\
+    ///     | value | count |\n\
+    ///     +=======+=======+\n\
+    ///     | 0     | 1     |\n\
+    ///     +-------+-------+\n\
+    ///     | 1     | 3     |\n\
+    ///     +-------+-------+\n\
+    ///     | 2     | 5     |\n\
+    ///     +-------+-------+\n\
+    ///     | 3     | 7     |\n\
+    ///     +-------+-------+\n\
+    ///     ");
+    /// ```
+    pub fn evaluate(&self) -> ProbabilityDistribution {
+        match self {
+            Expr::Dice(number_of_dice, number_of_sides) => {
+                ProbabilityDistribution::new_multiple_dice(*number_of_dice, *number_of_sides)
+            }
+            Expr::Const(value) => ProbabilityDistribution::from(*value),
+            Expr::Add(lhs, rhs) => lhs.evaluate() + rhs.evaluate(),
+            Expr::Sub(lhs, rhs) => lhs.evaluate() - rhs.evaluate(),
+            Expr::Mul(lhs, rhs) => lhs.evaluate() * rhs.evaluate(),
+            Expr::KeepHighest(expr, keep) => match expr.as_ref() {
+                Expr::Dice(number_of_dice, number_of_sides) => ProbabilityDistribution::new_pool(
+                    *number_of_dice,
+                    *number_of_sides,
+                    &KeepHighest(*keep),
+                ),
+                _ if *keep == 0 => ProbabilityDistribution::from(0),
+                _ => expr.evaluate(),
+            },
+            Expr::Neg(expr) => -expr.evaluate(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::probability::pool_aggregator::KeepHighest;
+    use crate::probability::{Expr, ProbabilityDistribution, ToTable};
+
+    #[test]
+    fn test_evaluate_dice() {
+        assert_eq!(
+            Expr::Dice(3, 6).evaluate().to_table(),
+            ProbabilityDistribution::new_multiple_dice(3, 6).to_table()
+        );
+    }
+
+    #[test]
+    fn test_evaluate_const() {
+        assert_eq!(
+            Expr::Const(-4).evaluate().to_table(),
+            ProbabilityDistribution::from(-4).to_table()
+        );
+    }
+
+    #[test]
+    fn test_evaluate_arithmetic() {
+        let expr = Expr::Neg(Box::new(Expr::Mul(
+            Box::new(Expr::Sub(
+                Box::new(Expr::Dice(1, 6)),
+                Box::new(Expr::Dice(1, 4)),
+            )),
+            Box::new(Expr::Const(3)),
+        )));
+        let expected =
+            -((ProbabilityDistribution::new_dice(6) - ProbabilityDistribution::new_dice(4)) * 3);
+        assert_eq!(expr.evaluate().to_table(), expected.to_table());
+    }
+
+    #[test]
+    fn test_evaluate_keep_highest_plus_const() {
+        let expr = Expr::Add(
+            Box::new(Expr::KeepHighest(Box::new(Expr::Dice(4, 6)), 3)),
+            Box::new(Expr::Const(2)),
+        );
+        let evaluated = expr.evaluate();
+
+        assert_eq!(
+            evaluated.to_table(),
+            (ProbabilityDistribution::new_pool(4, 6, &KeepHighest(3)) + 2).to_table()
+        );
+        assert_eq!(evaluated.total_outcome_count(), 1296);
+        assert_eq!(evaluated.coefficients().0, 5);
+        assert_eq!(evaluated.coefficients().1.len(), 16);
+    }
+
+    #[test]
+    fn test_evaluate_keep_highest_of_single_roll() {
+        let single_roll = Expr::Add(Box::new(Expr::Dice(1, 6)), Box::new(Expr::Const(1)));
+
+        assert_eq!(
+            Expr::KeepHighest(Box::new(single_roll.clone()), 2)
+                .evaluate()
+                .to_table(),
+            single_roll.evaluate().to_table()
+        );
+        assert_eq!(
+            Expr::KeepHighest(Box::new(single_roll), 0)
+                .evaluate()
+                .to_table(),
+            ProbabilityDistribution::from(0).to_table()
+        );
+    }
+}
@@ -0,0 +1,4 @@
+pub mod expr_enum;
+pub mod expr_evaluate;
+
+pub use self::expr_enum::Expr;
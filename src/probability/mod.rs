@@ -1,9 +1,23 @@
+pub mod expr;
+pub mod pool_aggregator;
 pub mod probability_distribution;
 pub mod probability_outcome;
 pub mod traits;
 pub mod types;
 
+pub use self::expr::Expr;
+
 pub use self::probability_distribution::add_outcome_to_map;
+pub use self::probability_distribution::checked_add_outcome_to_map;
+pub use self::probability_distribution::collapse_outcome_to_count_map;
+pub use self::probability_distribution::diff;
+pub use self::probability_distribution::CombineOverflow;
+pub use self::probability_distribution::ConstraintIdConflict;
+pub use self::probability_distribution::CountOverflow;
+pub use self::probability_distribution::DecodeError;
+pub use self::probability_distribution::FromCountMap;
+pub use self::probability_distribution::NotationError;
+pub use self::probability_distribution::OutcomeDiff;
 pub use self::probability_distribution::ToHashMap;
 pub use self::probability_distribution::ToTable;
 
@@ -12,6 +26,8 @@ pub use self::probability_distribution::ProbabilityDistribution;
 pub use self::probability_outcome::ProbabilityOutcome;
 
 pub use self::traits::Combine;
+pub use self::traits::PoolAggregator;
 
 pub use self::types::BinaryOperation;
 pub use self::types::OutcomeToCountMap;
+pub use self::types::ValueToCountMap;
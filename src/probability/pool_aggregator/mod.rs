@@ -0,0 +1,9 @@
+pub mod pool_aggregator_count_successes;
+pub mod pool_aggregator_keep_highest;
+pub mod pool_aggregator_keep_lowest;
+pub mod pool_aggregator_sum_all;
+
+pub use self::pool_aggregator_count_successes::CountSuccesses;
+pub use self::pool_aggregator_keep_highest::KeepHighest;
+pub use self::pool_aggregator_keep_lowest::KeepLowest;
+pub use self::pool_aggregator_sum_all::SumAll;
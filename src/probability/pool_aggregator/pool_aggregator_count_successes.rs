@@ -0,0 +1,48 @@
+use crate::{probability::PoolAggregator, ValueType};
+
+/// A [PoolAggregator] that counts the dice in the pool showing at least the target value.
+///
+/// # Examples
+/// ```
+/// # use crate::rusted_dice::probability::PoolAggregator;
+/// # use crate::rusted_dice::probability::pool_aggregator::CountSuccesses;
+/// assert_eq!(CountSuccesses(5).aggregate(&[1, 5, 6]), 2);
+/// ```
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct CountSuccesses(pub ValueType);
+
+impl PoolAggregator for CountSuccesses {
+    /// Counts the faces greater than or equal to the target.
+    ///
+    /// # Arguments
+    ///
+    /// * `sorted_faces` - The [ValueType] faces shown by each dice, sorted ascending.
+    ///
+    /// # Returns
+    ///
+    /// The number of successful faces.
+    fn aggregate(&self, sorted_faces: &[ValueType]) -> ValueType {
+        sorted_faces.iter().filter(|face| **face >= self.0).count() as ValueType
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::probability::pool_aggregator::CountSuccesses;
+    use crate::probability::PoolAggregator;
+
+    #[test]
+    fn test_count_successes_empty() {
+        assert_eq!(CountSuccesses(4).aggregate(&[]), 0);
+    }
+
+    #[test]
+    fn test_count_successes_none() {
+        assert_eq!(CountSuccesses(7).aggregate(&[1, 2, 6]), 0);
+    }
+
+    #[test]
+    fn test_count_successes_inclusive() {
+        assert_eq!(CountSuccesses(4).aggregate(&[1, 4, 4, 6]), 3);
+    }
+}
@@ -0,0 +1,51 @@
+use crate::{probability::PoolAggregator, ValueType};
+
+/// A [PoolAggregator] that sums the highest N dice in the pool.
+/// if N is larger than the pool, all dice are kept.
+///
+/// # Examples
+/// ```
+/// # use crate::rusted_dice::probability::PoolAggregator;
+/// # use crate::rusted_dice::probability::pool_aggregator::KeepHighest;
+/// assert_eq!(KeepHighest(2).aggregate(&[1, 3, 6]), 9);
+/// assert_eq!(KeepHighest(5).aggregate(&[1, 3, 6]), 10);
+/// ```
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct KeepHighest(pub u16);
+
+impl PoolAggregator for KeepHighest {
+    /// Sums the highest faces.
+    ///
+    /// # Arguments
+    ///
+    /// * `sorted_faces` - The [ValueType] faces shown by each dice, sorted ascending.
+    ///
+    /// # Returns
+    ///
+    /// The sum of the highest N faces.
+    fn aggregate(&self, sorted_faces: &[ValueType]) -> ValueType {
+        let dropped = sorted_faces.len().saturating_sub(self.0.into());
+        sorted_faces[dropped..].iter().sum()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::probability::pool_aggregator::KeepHighest;
+    use crate::probability::PoolAggregator;
+
+    #[test]
+    fn test_keep_highest_none() {
+        assert_eq!(KeepHighest(0).aggregate(&[1, 2, 3]), 0);
+    }
+
+    #[test]
+    fn test_keep_highest() {
+        assert_eq!(KeepHighest(3).aggregate(&[1, 2, 3, 6]), 11);
+    }
+
+    #[test]
+    fn test_keep_highest_more_than_pool() {
+        assert_eq!(KeepHighest(10).aggregate(&[1, 2, 3, 6]), 12);
+    }
+}
@@ -0,0 +1,51 @@
+use crate::{probability::PoolAggregator, ValueType};
+
+/// A [PoolAggregator] that sums the lowest N dice in the pool.
+/// if N is larger than the pool, all dice are kept.
+///
+/// # Examples
+/// ```
+/// # use crate::rusted_dice::probability::PoolAggregator;
+/// # use crate::rusted_dice::probability::pool_aggregator::KeepLowest;
+/// assert_eq!(KeepLowest(2).aggregate(&[1, 3, 6]), 4);
+/// assert_eq!(KeepLowest(5).aggregate(&[1, 3, 6]), 10);
+/// ```
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct KeepLowest(pub u16);
+
+impl PoolAggregator for KeepLowest {
+    /// Sums the lowest faces.
+    ///
+    /// # Arguments
+    ///
+    /// * `sorted_faces` - The [ValueType] faces shown by each dice, sorted ascending.
+    ///
+    /// # Returns
+    ///
+    /// The sum of the lowest N faces.
+    fn aggregate(&self, sorted_faces: &[ValueType]) -> ValueType {
+        let kept = sorted_faces.len().min(self.0.into());
+        sorted_faces[..kept].iter().sum()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::probability::pool_aggregator::KeepLowest;
+    use crate::probability::PoolAggregator;
+
+    #[test]
+    fn test_keep_lowest_none() {
+        assert_eq!(KeepLowest(0).aggregate(&[1, 2, 3]), 0);
+    }
+
+    #[test]
+    fn test_keep_lowest() {
+        assert_eq!(KeepLowest(3).aggregate(&[1, 2, 3, 6]), 6);
+    }
+
+    #[test]
+    fn test_keep_lowest_more_than_pool() {
+        assert_eq!(KeepLowest(10).aggregate(&[1, 2, 3, 6]), 12);
+    }
+}
@@ -0,0 +1,43 @@
+use crate::{probability::PoolAggregator, ValueType};
+
+/// A [PoolAggregator] that sums every dice in the pool.
+///
+/// # Examples
+/// ```
+/// # use crate::rusted_dice::probability::PoolAggregator;
+/// # use crate::rusted_dice::probability::pool_aggregator::SumAll;
+/// assert_eq!(SumAll.aggregate(&[1, 3, 6]), 10);
+/// ```
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct SumAll;
+
+impl PoolAggregator for SumAll {
+    /// Sums all of the faces.
+    ///
+    /// # Arguments
+    ///
+    /// * `sorted_faces` - The [ValueType] faces shown by each dice, sorted ascending.
+    ///
+    /// # Returns
+    ///
+    /// The sum of all faces.
+    fn aggregate(&self, sorted_faces: &[ValueType]) -> ValueType {
+        sorted_faces.iter().sum()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::probability::pool_aggregator::SumAll;
+    use crate::probability::PoolAggregator;
+
+    #[test]
+    fn test_sum_all_empty() {
+        assert_eq!(SumAll.aggregate(&[]), 0);
+    }
+
+    #[test]
+    fn test_sum_all() {
+        assert_eq!(SumAll.aggregate(&[-2, 1, 1, 4]), 4);
+    }
+}
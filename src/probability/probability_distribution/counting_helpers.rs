@@ -0,0 +1,167 @@
+use crate::CountType;
+
+/// a helper function to calculate the binomial coefficient, the number of ways to choose `k` items from `n`.
+///
+/// # Arguments
+///
+/// * `n` - The number of items to choose from.
+/// * `k` - The number of items to choose.
+///
+/// # Returns
+///
+/// The number of ways to choose `k` items from `n` as a [CountType], 0 if `k` is larger than `n`.
+///
+/// # Panics
+///
+/// Panics if the number of ways does not fit in a [CountType], e.g. `binomial_coefficient(68, 34)`.
+///
+/// # Example
+/// ```
+/// # use crate::rusted_dice::probability::probability_distribution::binomial_coefficient;
+/// assert_eq!(binomial_coefficient(5, 2), 10);
+/// assert_eq!(binomial_coefficient(5, 0), 1);
+/// assert_eq!(binomial_coefficient(2, 5), 0);
+/// ```
+pub fn binomial_coefficient(n: u16, k: u16) -> CountType {
+    if k > n {
+        return 0;
+    }
+    let k = k.min(n - k);
+    let mut result: u128 = 1;
+    for i in 0..k {
+        result = result
+            .checked_mul(u128::from(n - i))
+            .expect("Binomial coefficient overflowed CountType.")
+            / u128::from(i + 1);
+    }
+    CountType::try_from(result).expect("Binomial coefficient overflowed CountType.")
+}
+
+/// a helper function to calculate the greatest common divisor of two counts.
+///
+/// # Arguments
+///
+/// * `a` - The first [CountType].
+/// * `b` - The second [CountType].
+///
+/// # Returns
+///
+/// The largest [CountType] dividing both `a` and `b`, the other count if one of them is 0.
+///
+/// # Example
+/// ```
+/// # use crate::rusted_dice::probability::probability_distribution::greatest_common_divisor;
+/// assert_eq!(greatest_common_divisor(12, 18), 6);
+/// assert_eq!(greatest_common_divisor(0, 5), 5);
+/// assert_eq!(greatest_common_divisor(0, 0), 0);
+/// ```
+pub fn greatest_common_divisor(a: CountType, b: CountType) -> CountType {
+    let (mut a, mut b) = (a, b);
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+    a
+}
+
+/// a helper function to calculate the least common multiple of two counts.
+///
+/// # Arguments
+///
+/// * `a` - The first [CountType].
+/// * `b` - The second [CountType].
+///
+/// # Returns
+///
+/// The smallest [CountType] both `a` and `b` divide, 0 if either of them is 0.
+///
+/// # Example
+/// ```
+/// # use crate::rusted_dice::probability::probability_distribution::least_common_multiple;
+/// assert_eq!(least_common_multiple(4, 6), 12);
+/// assert_eq!(least_common_multiple(0, 6), 0);
+/// ```
+pub fn least_common_multiple(a: CountType, b: CountType) -> CountType {
+    if a == 0 || b == 0 {
+        return 0;
+    }
+    a / greatest_common_divisor(a, b) * b
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{binomial_coefficient, greatest_common_divisor, least_common_multiple};
+
+    #[test]
+    fn test_binomial_coefficient_zero() {
+        assert_eq!(binomial_coefficient(0, 0), 1);
+        assert_eq!(binomial_coefficient(6, 0), 1);
+    }
+
+    #[test]
+    fn test_binomial_coefficient_all() {
+        assert_eq!(binomial_coefficient(1, 1), 1);
+        assert_eq!(binomial_coefficient(6, 6), 1);
+    }
+
+    #[test]
+    fn test_binomial_coefficient_small() {
+        assert_eq!(binomial_coefficient(4, 2), 6);
+        assert_eq!(binomial_coefficient(6, 3), 20);
+        assert_eq!(binomial_coefficient(10, 7), 120);
+    }
+
+    #[test]
+    fn test_binomial_coefficient_large() {
+        assert_eq!(binomial_coefficient(52, 5), 2598960);
+        assert_eq!(binomial_coefficient(60, 30), 118264581564861424);
+    }
+
+    #[test]
+    fn test_binomial_coefficient_largest_fitting() {
+        assert_eq!(binomial_coefficient(67, 33), 14226520737620288370);
+        assert_eq!(binomial_coefficient(67, 34), 14226520737620288370);
+        assert_eq!(binomial_coefficient(u16::MAX, 1), 65535);
+        assert_eq!(binomial_coefficient(u16::MAX, u16::MAX - 1), 65535);
+    }
+
+    #[test]
+    #[should_panic(expected = "Binomial coefficient overflowed CountType.")]
+    fn test_binomial_coefficient_overflow() {
+        binomial_coefficient(68, 34);
+    }
+
+    #[test]
+    #[should_panic(expected = "Binomial coefficient overflowed CountType.")]
+    fn test_binomial_coefficient_one_hundred_choose_fifty() {
+        binomial_coefficient(100, 50);
+    }
+
+    #[test]
+    #[should_panic(expected = "Binomial coefficient overflowed CountType.")]
+    fn test_binomial_coefficient_intermediate_overflow() {
+        binomial_coefficient(u16::MAX, u16::MAX / 2);
+    }
+
+    #[test]
+    fn test_binomial_coefficient_k_larger_than_n() {
+        assert_eq!(binomial_coefficient(3, 4), 0);
+    }
+
+    #[test]
+    fn test_greatest_common_divisor() {
+        assert_eq!(greatest_common_divisor(0, 0), 0);
+        assert_eq!(greatest_common_divisor(7, 0), 7);
+        assert_eq!(greatest_common_divisor(1, 9), 1);
+        assert_eq!(greatest_common_divisor(36, 24), 12);
+        assert_eq!(greatest_common_divisor(13, 17), 1);
+    }
+
+    #[test]
+    fn test_least_common_multiple() {
+        assert_eq!(least_common_multiple(0, 0), 0);
+        assert_eq!(least_common_multiple(1, 9), 9);
+        assert_eq!(least_common_multiple(4, 6), 12);
+        assert_eq!(least_common_multiple(6, 4), 12);
+        assert_eq!(least_common_multiple(36, 24), 72);
+    }
+}
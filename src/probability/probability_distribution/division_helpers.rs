@@ -0,0 +1,86 @@
+use crate::ValueType;
+
+/// a helper function to divide two values, rounding to the nearest integer.
+/// a tie, where the remainder is exactly half of `rhs`, rounds away from zero
+/// when `round_tie_away` returns true for the quotient truncated toward zero.
+///
+/// # Arguments
+///
+/// * `lhs` - The [ValueType] to divide.
+/// * `rhs` - The [ValueType] to divide by.
+/// * `round_tie_away` - Decides if a tie rounds away from zero, given the truncated quotient.
+///
+/// # Returns
+///
+/// The rounded quotient as a [ValueType].
+///
+/// # Panics
+///
+/// Panics if `rhs` is 0, or if `lhs` is [ValueType::MIN] and `rhs` is -1, as with the divide operator.
+///
+/// # Example
+/// ```
+/// # use crate::rusted_dice::probability::probability_distribution::div_round_with;
+/// assert_eq!(div_round_with(7, 2, |_| true), 4);
+/// assert_eq!(div_round_with(7, 2, |_| false), 3);
+/// assert_eq!(div_round_with(-7, 2, |_| true), -4);
+/// assert_eq!(div_round_with(8, 3, |_| false), 3);
+/// ```
+pub fn div_round_with(
+    lhs: ValueType,
+    rhs: ValueType,
+    round_tie_away: fn(ValueType) -> bool,
+) -> ValueType {
+    let quotient = lhs / rhs;
+    let remainder = lhs % rhs;
+    let twice_remainder = 2 * i64::from(remainder).abs();
+    let divisor = i64::from(rhs).abs();
+
+    if twice_remainder > divisor || (twice_remainder == divisor && round_tie_away(quotient)) {
+        if (lhs < 0) == (rhs < 0) {
+            quotient + 1
+        } else {
+            quotient - 1
+        }
+    } else {
+        quotient
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::div_round_with;
+
+    #[test]
+    fn test_div_round_with_exact() {
+        assert_eq!(div_round_with(6, 3, |_| true), 2);
+        assert_eq!(div_round_with(-6, 3, |_| false), -2);
+        assert_eq!(div_round_with(0, -5, |_| true), 0);
+    }
+
+    #[test]
+    fn test_div_round_with_not_a_tie() {
+        assert_eq!(div_round_with(8, 3, |_| true), 3);
+        assert_eq!(div_round_with(7, 3, |_| true), 2);
+        assert_eq!(div_round_with(-8, 3, |_| false), -3);
+        assert_eq!(div_round_with(8, -3, |_| false), -3);
+        assert_eq!(div_round_with(-7, -3, |_| false), 2);
+    }
+
+    #[test]
+    fn test_div_round_with_tie() {
+        assert_eq!(div_round_with(5, 2, |_| true), 3);
+        assert_eq!(div_round_with(5, 2, |_| false), 2);
+        assert_eq!(div_round_with(-5, 2, |_| true), -3);
+        assert_eq!(div_round_with(5, -2, |_| false), -2);
+        assert_eq!(div_round_with(-5, -2, |_| true), 3);
+        assert_eq!(div_round_with(5, 2, |quotient| quotient == 2), 3);
+    }
+
+    #[test]
+    fn test_div_round_with_extremes() {
+        assert_eq!(div_round_with(i32::MAX, 2, |_| true), 1073741824);
+        assert_eq!(div_round_with(i32::MIN, 2, |_| true), -1073741824);
+        assert_eq!(div_round_with(i32::MIN, i32::MIN, |_| false), 1);
+    }
+}
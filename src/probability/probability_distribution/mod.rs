@@ -1,24 +1,123 @@
+pub mod counting_helpers;
+pub mod division_helpers;
 pub mod outcome_to_counts_helpers;
+pub mod probability_distribution_abs;
+pub mod probability_distribution_abs_difference;
 pub mod probability_distribution_add;
+pub mod probability_distribution_add_assign;
 pub mod probability_distribution_add_constraint;
 pub mod probability_distribution_add_self_value_constraint;
+pub mod probability_distribution_apply_soak;
+pub mod probability_distribution_are_constraints_independent;
 pub mod probability_distribution_bitand;
 pub mod probability_distribution_bitor;
 pub mod probability_distribution_bitxor;
+pub mod probability_distribution_bucket;
+pub mod probability_distribution_bytes;
+pub mod probability_distribution_capped_factory;
+pub mod probability_distribution_clamp;
+pub mod probability_distribution_coefficients;
 pub mod probability_distribution_combine;
+pub mod probability_distribution_combine_checked_counts;
+pub mod probability_distribution_combine_disjoint;
+pub mod probability_distribution_combine_map;
+pub mod probability_distribution_combine_saturating;
+pub mod probability_distribution_comparison;
+pub mod probability_distribution_conditional_on_constraint;
+pub mod probability_distribution_constraint_ids;
+pub mod probability_distribution_convolution_steps;
+pub mod probability_distribution_count_of;
+pub mod probability_distribution_cumulative;
+pub mod probability_distribution_deduplicate_constraint_columns;
+pub mod probability_distribution_describe;
+pub mod probability_distribution_described_factory;
+pub mod probability_distribution_diff;
 pub mod probability_distribution_div;
+pub mod probability_distribution_div_floor;
+pub mod probability_distribution_div_round;
+pub mod probability_distribution_div_round_half_even;
+pub mod probability_distribution_drop_constraints;
+pub mod probability_distribution_expected_advantage;
+pub mod probability_distribution_expected_constraint_value;
+pub mod probability_distribution_expected_successes;
+pub mod probability_distribution_exploding_factory;
+pub mod probability_distribution_extremes;
 pub mod probability_distribution_factory;
+pub mod probability_distribution_filter;
+pub mod probability_distribution_first_order_dominates;
+pub mod probability_distribution_from;
+pub mod probability_distribution_from_count_map;
+pub mod probability_distribution_from_iterator;
+pub mod probability_distribution_from_notation;
+pub mod probability_distribution_fudge_factory;
+pub mod probability_distribution_in_place;
+pub mod probability_distribution_interquartile_range;
+pub mod probability_distribution_iter_values;
+pub mod probability_distribution_keep_central_mass;
+pub mod probability_distribution_keep_factory;
+pub mod probability_distribution_len;
+pub mod probability_distribution_map_values;
+pub mod probability_distribution_mean_given_at_least;
+pub mod probability_distribution_min_max_combine;
+pub mod probability_distribution_mixture;
 pub mod probability_distribution_mul;
+pub mod probability_distribution_mul_assign;
 pub mod probability_distribution_neg;
 pub mod probability_distribution_not;
+pub mod probability_distribution_partition_at;
+pub mod probability_distribution_peak_probability;
+pub mod probability_distribution_percentile;
+pub mod probability_distribution_pool_factory;
+pub mod probability_distribution_probability_exactly_successes;
+pub mod probability_distribution_probability_meets;
+pub mod probability_distribution_probability_n_matching;
+pub mod probability_distribution_probability_natural_max;
+pub mod probability_distribution_probability_pool_sum_at_least;
+pub mod probability_distribution_probability_straight;
+pub mod probability_distribution_probability_tie;
+pub mod probability_distribution_reduce;
+pub mod probability_distribution_relabel;
 pub mod probability_distribution_rem;
+pub mod probability_distribution_reroll;
+pub mod probability_distribution_retain_possible;
+#[cfg(feature = "rand")]
+pub mod probability_distribution_sample;
+pub mod probability_distribution_scale_counts;
+#[cfg(feature = "serde")]
+pub mod probability_distribution_serde;
+pub mod probability_distribution_split_at_cap;
+pub mod probability_distribution_stats;
 pub mod probability_distribution_struct;
 pub mod probability_distribution_sub;
+pub mod probability_distribution_sub_assign;
+pub mod probability_distribution_to_csv;
 pub mod probability_distribution_to_hash_map;
+pub mod probability_distribution_to_json;
+pub mod probability_distribution_to_probabilities;
 pub mod probability_distribution_to_table;
+pub mod probability_distribution_to_table_styled;
 pub mod probability_distribution_total_outcome_count;
+pub mod probability_distribution_try_combine;
+pub mod probability_distribution_weighted_factory;
 
+pub use self::counting_helpers::binomial_coefficient;
+pub use self::counting_helpers::greatest_common_divisor;
+pub use self::counting_helpers::least_common_multiple;
+pub use self::division_helpers::div_round_with;
 pub use self::outcome_to_counts_helpers::add_outcome_to_map;
+pub use self::outcome_to_counts_helpers::checked_add_outcome_to_map;
+pub use self::outcome_to_counts_helpers::collapse_outcome_to_count_map;
+pub use self::probability_distribution_bytes::DecodeError;
+pub use self::probability_distribution_combine_checked_counts::CountOverflow;
+pub use self::probability_distribution_combine_disjoint::ConstraintIdConflict;
+pub use self::probability_distribution_combine_map::CombineOverflow;
+pub use self::probability_distribution_diff::diff;
+pub use self::probability_distribution_diff::OutcomeDiff;
+pub use self::probability_distribution_from_count_map::FromCountMap;
+pub use self::probability_distribution_from_notation::NotationError;
+pub use self::probability_distribution_from_notation::DEFAULT_EXPLOSION_CAP;
+pub use self::probability_distribution_from_notation::MAX_POOL_COMBINATIONS;
 pub use self::probability_distribution_struct::ProbabilityDistribution;
+pub use self::probability_distribution_to_hash_map::ordered_column_names;
 pub use self::probability_distribution_to_hash_map::ToHashMap;
 pub use self::probability_distribution_to_table::ToTable;
@@ -1,9 +1,13 @@
 use crate::{
-    probability::{types::OutcomeToCountMap, ProbabilityOutcome},
+    probability::{
+        types::{OutcomeToCountMap, ValueToCountMap},
+        ProbabilityOutcome,
+    },
     CountType,
 };
 
 use std::collections::btree_map::Entry::{Occupied, Vacant};
+use std::collections::BTreeMap;
 
 /// a helper function to add a [ProbabilityOutcome] to a [OutcomeToCountMap].
 /// if the [ProbabilityOutcome] already exists in the [OutcomeToCountMap] the count will be added to the existing count.
@@ -49,11 +53,102 @@ pub fn add_outcome_to_map(
     };
 }
 
+/// a helper function to add a [ProbabilityOutcome] to a [OutcomeToCountMap], checking for overflow.
+/// the same as [add_outcome_to_map], but returns `None` instead of overflowing [CountType],
+/// leaving the [OutcomeToCountMap] unchanged.
+///
+/// # Arguments
+///
+/// * `outcome_to_count_map` - The [OutcomeToCountMap] to add the [ProbabilityOutcome] to.
+/// * `probability_outcome` - The [ProbabilityOutcome] to add to the [OutcomeToCountMap].
+/// * `count` - The count to add to the [ProbabilityOutcome] in the [OutcomeToCountMap].
+///
+/// # Returns
+///
+/// `Some(())` if the count was added, `None` if the new count does not fit in [CountType].
+///
+/// # Example
+/// ```
+/// # use crate::rusted_dice::probability::ProbabilityOutcome;
+/// # use crate::rusted_dice::probability::checked_add_outcome_to_map;
+/// # use std::collections::BTreeMap;
+/// let mut outcome_to_count_map = BTreeMap::new();
+/// let probability_outcome = ProbabilityOutcome::new_with_empty_constraint_map(123);
+///
+/// assert_eq!(
+///     checked_add_outcome_to_map(&mut outcome_to_count_map, probability_outcome.clone(), u64::MAX),
+///     Some(())
+/// );
+/// assert_eq!(
+///     checked_add_outcome_to_map(&mut outcome_to_count_map, probability_outcome.clone(), 1),
+///     None
+/// );
+/// assert_eq!(outcome_to_count_map.get(&probability_outcome), Some(&u64::MAX));
+/// ```
+pub fn checked_add_outcome_to_map(
+    outcome_to_count_map: &mut OutcomeToCountMap,
+    probability_outcome: ProbabilityOutcome,
+    count: CountType,
+) -> Option<()> {
+    match outcome_to_count_map.entry(probability_outcome) {
+        Occupied(mut entry) => {
+            let new_count = entry.get().checked_add(count)?;
+            *entry.get_mut() = new_count;
+        }
+        Vacant(entry) => {
+            entry.insert(count);
+        }
+    };
+    Some(())
+}
+
+/// a helper function to collapse a [OutcomeToCountMap] into a [ValueToCountMap].
+/// the counts of [ProbabilityOutcome]s sharing a value but differing in constraints are summed.
+///
+/// # Arguments
+///
+/// * `outcome_to_count_map` - The [OutcomeToCountMap] to collapse.
+///
+/// # Returns
+///
+/// The [ValueToCountMap] of each value to its total count.
+///
+/// # Example
+/// ```
+/// # use crate::rusted_dice::constraint_management::Constraint;
+/// # use crate::rusted_dice::probability::ProbabilityOutcome;
+/// # use crate::rusted_dice::probability::add_outcome_to_map;
+/// # use crate::rusted_dice::probability::collapse_outcome_to_count_map;
+/// # use std::collections::BTreeMap;
+/// let mut outcome_to_count_map = BTreeMap::new();
+/// add_outcome_to_map(&mut outcome_to_count_map, ProbabilityOutcome::new_with_empty_constraint_map(5), 1);
+/// add_outcome_to_map(
+///     &mut outcome_to_count_map,
+///     ProbabilityOutcome::new_with_constraints(5, vec![Constraint::new_single_valid_value_constraint(1, 2)]),
+///     3,
+/// );
+///
+/// let value_to_count_map = collapse_outcome_to_count_map(&outcome_to_count_map);
+/// assert_eq!(value_to_count_map.get(&5), Some(&4));
+/// assert_eq!(value_to_count_map.len(), 1);
+/// ```
+pub fn collapse_outcome_to_count_map(outcome_to_count_map: &OutcomeToCountMap) -> ValueToCountMap {
+    let mut value_to_count_map: ValueToCountMap = BTreeMap::new();
+    for (outcome, count) in outcome_to_count_map.iter() {
+        *value_to_count_map.entry(outcome.value).or_insert(0) += count;
+    }
+    value_to_count_map
+}
+
 #[cfg(test)]
 mod tests {
     use std::collections::BTreeMap;
 
-    use crate::probability::{add_outcome_to_map, ProbabilityOutcome};
+    use crate::constraint_management::Constraint;
+    use crate::probability::{
+        add_outcome_to_map, checked_add_outcome_to_map, collapse_outcome_to_count_map,
+        ProbabilityOutcome,
+    };
 
     #[test]
     fn add_outcome_to_map_empty() {
@@ -114,4 +209,92 @@ mod tests {
 
         assert!(outcome_to_count_map.get(&probability_outcome_one) == Some(&31));
     }
+
+    #[test]
+    fn checked_add_outcome_to_map_adds() {
+        let mut outcome_to_count_map = BTreeMap::new();
+        let probability_outcome_one = ProbabilityOutcome::new_with_empty_constraint_map(1);
+        let probability_outcome_two = ProbabilityOutcome::new_with_empty_constraint_map(2);
+
+        assert_eq!(
+            checked_add_outcome_to_map(
+                &mut outcome_to_count_map,
+                probability_outcome_one.clone(),
+                3
+            ),
+            Some(())
+        );
+        assert_eq!(
+            checked_add_outcome_to_map(
+                &mut outcome_to_count_map,
+                probability_outcome_one.clone(),
+                4
+            ),
+            Some(())
+        );
+        assert_eq!(
+            checked_add_outcome_to_map(
+                &mut outcome_to_count_map,
+                probability_outcome_two.clone(),
+                5
+            ),
+            Some(())
+        );
+
+        assert_eq!(outcome_to_count_map.get(&probability_outcome_one), Some(&7));
+        assert_eq!(outcome_to_count_map.get(&probability_outcome_two), Some(&5));
+    }
+
+    #[test]
+    fn checked_add_outcome_to_map_overflow() {
+        let mut outcome_to_count_map = BTreeMap::new();
+        let probability_outcome = ProbabilityOutcome::new_with_empty_constraint_map(1);
+
+        assert_eq!(
+            checked_add_outcome_to_map(
+                &mut outcome_to_count_map,
+                probability_outcome.clone(),
+                u64::MAX - 1
+            ),
+            Some(())
+        );
+        assert_eq!(
+            checked_add_outcome_to_map(&mut outcome_to_count_map, probability_outcome.clone(), 1),
+            Some(())
+        );
+        assert_eq!(
+            checked_add_outcome_to_map(&mut outcome_to_count_map, probability_outcome.clone(), 1),
+            None
+        );
+        assert_eq!(
+            outcome_to_count_map.get(&probability_outcome),
+            Some(&u64::MAX)
+        );
+    }
+
+    #[test]
+    fn collapse_outcome_to_count_map_empty() {
+        let outcome_to_count_map = BTreeMap::new();
+        assert!(collapse_outcome_to_count_map(&outcome_to_count_map).is_empty());
+    }
+
+    #[test]
+    fn collapse_outcome_to_count_map_merges_constrained_values() {
+        let mut outcome_to_count_map = BTreeMap::new();
+        outcome_to_count_map.insert(ProbabilityOutcome::new_with_empty_constraint_map(1), 2);
+        outcome_to_count_map.insert(
+            ProbabilityOutcome::new_with_constraints(
+                1,
+                vec![Constraint::new_single_valid_value_constraint(1, 1)],
+            ),
+            3,
+        );
+        outcome_to_count_map.insert(ProbabilityOutcome::new_with_empty_constraint_map(2), 7);
+
+        let value_to_count_map = collapse_outcome_to_count_map(&outcome_to_count_map);
+
+        assert_eq!(value_to_count_map.get(&1), Some(&5));
+        assert_eq!(value_to_count_map.get(&2), Some(&7));
+        assert_eq!(value_to_count_map.len(), 2);
+    }
 }
@@ -0,0 +1,114 @@
+use crate::probability::ProbabilityDistribution;
+use crate::ValueType;
+
+impl ProbabilityDistribution {
+    /// Returns the [ProbabilityDistribution] of the absolute values, for example of `|d6 - d6|`.
+    /// constraint maps are kept, outcomes whose absolute values coincide are merged and their counts added.
+    ///
+    /// # Panics
+    ///
+    /// Panics in debug builds if any value is [ValueType::MIN], whose absolute value overflows.
+    ///
+    /// # Returns
+    ///
+    /// The new [ProbabilityDistribution].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use crate::rusted_dice::probability::ProbabilityDistribution;
+    /// # use crate::rusted_dice::probability::ToTable;
+    /// let difference = ProbabilityDistribution::new_dice(3) - ProbabilityDistribution::new_dice(3);
+    ///
+    /// assert_eq!(
+    ///     difference
+    ///         .abs()
+    ///         .to_table()
+    ///         .to_string()
+    ///         .replace("\r\n", "\n"),
+    ///     "\
+    ///     +-------+-------+\n\
+    ///     | value | count |\n\
+    ///     +=======+=======+\n\
+    ///     | 0     | 3     |\n\
+    ///     +-------+-------+\n\
+    ///     | 1     | 4     |\n\
+    ///     +-------+-------+\n\
+    ///     | 2     | 2     |\n\
+    ///     +-------+-------+\n\
+    ///     ");
+    /// ```
+    pub fn abs(&self) -> Self {
+        self.map_values(ValueType::abs)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::constraint_management::Constraint;
+    use crate::probability::{ProbabilityDistribution, ProbabilityOutcome};
+    use crate::ValueType;
+
+    #[test]
+    fn test_abs_empty() {
+        let probability_distribution = ProbabilityDistribution::new_empty_distribution().abs();
+        assert_eq!(probability_distribution.outcome_counts.len(), 0);
+    }
+
+    #[test]
+    fn test_abs_positive_unchanged() {
+        let probability_distribution = ProbabilityDistribution::new_multiple_dice(2, 6);
+        assert_eq!(
+            probability_distribution.abs().outcome_counts,
+            probability_distribution.outcome_counts
+        );
+    }
+
+    #[test]
+    fn test_abs_negative_dice() {
+        assert_eq!(
+            ProbabilityDistribution::new_dice(-6).abs().outcome_counts,
+            ProbabilityDistribution::new_dice(6).outcome_counts
+        );
+    }
+
+    #[test]
+    fn test_abs_collapses_difference() {
+        let probability_distribution =
+            (ProbabilityDistribution::new_dice(3) - ProbabilityDistribution::new_dice(3)).abs();
+
+        assert_eq!(probability_distribution.outcome_counts.len(), 3);
+        for (value, count) in [(0, 3), (1, 4), (2, 2)] {
+            assert_eq!(
+                probability_distribution
+                    .outcome_counts
+                    .get(&ProbabilityOutcome::new_with_empty_constraint_map(value)),
+                Some(&count)
+            );
+        }
+    }
+
+    #[test]
+    fn test_abs_keeps_constraints() {
+        let probability_distribution =
+            (ProbabilityDistribution::new_dice(2).add_self_value_constraint(1) - 2).abs();
+
+        for (value, face) in [(1, 1), (0, 2)] {
+            assert_eq!(
+                probability_distribution.outcome_counts.get(
+                    &ProbabilityOutcome::new_with_constraints(
+                        value,
+                        vec![Constraint::new_single_valid_value_constraint(1, face)],
+                    )
+                ),
+                Some(&1)
+            );
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "attempt to negate with overflow")]
+    fn test_abs_min_overflow() {
+        ProbabilityDistribution::new_constant(ValueType::MIN).abs();
+    }
+}
@@ -0,0 +1,143 @@
+use crate::probability::{Combine, ProbabilityDistribution};
+use crate::ValueType;
+
+fn _abs_difference(lhs: ValueType, rhs: ValueType) -> ValueType {
+    (lhs - rhs).abs()
+}
+
+impl ProbabilityDistribution {
+    /// Combines two [ProbabilityDistribution]s into the distribution of the absolute difference of their values.
+    /// the same as `(self - other)` with every value made positive, computed in a single combine.
+    /// constraint maps are combined using the ConstraintMap::add function.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The [ProbabilityDistribution] to take the difference with.
+    ///
+    /// # Returns
+    ///
+    /// The new [ProbabilityDistribution] of `|self - other|`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the difference of two values overflows a [ValueType].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use crate::rusted_dice::probability::ProbabilityDistribution;
+    /// # use crate::rusted_dice::probability::ToTable;
+    /// let dice_one = ProbabilityDistribution::new_dice(3);
+    /// let dice_two = ProbabilityDistribution::new_dice(3);
+    ///
+    /// assert_eq!(
+    ///     dice_one
+    ///         .abs_difference(&dice_two)
+    ///         .to_table()
+    ///         .to_string()
+    ///         .replace("\r\n", "\n"),
+    ///     "\
+    ///     +-------+-------+\n\
+    ///     | value | count |\n\
+    ///     +=======+=======+\n\
+    ///     | 0     | 3     |\n\
+    ///     +-------+-------+\n\
+    ///     | 1     | 4     |\n\
+    ///     +-------+-------+\n\
+    ///     | 2     | 2     |\n\
+    ///     +-------+-------+\n\
+    ///     ");
+    /// ```
+    pub fn abs_difference(&self, other: &Self) -> Self {
+        self.combine(other.clone(), _abs_difference)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::probability::{ProbabilityDistribution, ProbabilityOutcome};
+    use crate::ValueType;
+
+    #[test]
+    fn test_abs_difference_empty() {
+        let dice = ProbabilityDistribution::new_dice(6);
+        let empty = ProbabilityDistribution::new_empty_distribution();
+        assert_eq!(dice.abs_difference(&empty).outcome_counts.len(), 0);
+        assert_eq!(empty.abs_difference(&dice).outcome_counts.len(), 0);
+    }
+
+    #[test]
+    fn test_abs_difference_two_d6() {
+        let dice = ProbabilityDistribution::new_dice(6);
+        let difference = dice.abs_difference(&dice);
+
+        assert_eq!(difference.total_outcome_count(), 36);
+        let counts: Vec<(ValueType, u64)> = difference
+            .outcome_counts
+            .iter()
+            .map(|(outcome, count)| (outcome.value, *count))
+            .collect();
+        assert_eq!(
+            counts,
+            vec![(0, 6), (1, 10), (2, 8), (3, 6), (4, 4), (5, 2)]
+        );
+    }
+
+    #[test]
+    fn test_abs_difference_matches_sub() {
+        let dice_one = ProbabilityDistribution::new_multiple_dice(2, 4);
+        let dice_two = ProbabilityDistribution::new_dice(6) - 3;
+        let difference = dice_one.abs_difference(&dice_two);
+        let subtracted = dice_one - dice_two;
+
+        for (outcome, count) in subtracted.outcome_counts.iter() {
+            let absolute = ProbabilityOutcome::new_with_empty_constraint_map(outcome.value.abs());
+            assert!(difference.outcome_counts.get(&absolute).unwrap() >= count);
+        }
+        assert_eq!(
+            difference.total_outcome_count(),
+            subtracted.total_outcome_count()
+        );
+        assert!(difference
+            .outcome_counts
+            .keys()
+            .all(|outcome| outcome.value >= 0));
+    }
+
+    #[test]
+    fn test_abs_difference_keeps_constraints() {
+        let dice_one = ProbabilityDistribution::new_dice(3).add_self_value_constraint(1);
+        let dice_two = ProbabilityDistribution::new_dice(3).add_self_value_constraint(2);
+        let difference = dice_one.abs_difference(&dice_two);
+
+        assert_eq!(difference.outcome_counts.len(), 9);
+        assert!(difference
+            .outcome_counts
+            .keys()
+            .all(|outcome| outcome.constraint_map.map.len() == 2));
+    }
+
+    #[test]
+    fn test_abs_difference_impossible_constraints_dropped() {
+        let dice = ProbabilityDistribution::new_dice(3).add_self_value_constraint(1);
+        let difference = dice.abs_difference(&dice);
+
+        assert_eq!(difference.total_outcome_count(), 3);
+        assert_eq!(
+            difference
+                .outcome_counts
+                .keys()
+                .map(|outcome| outcome.value)
+                .collect::<Vec<_>>(),
+            vec![0, 0, 0]
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "attempt to subtract with overflow")]
+    fn test_abs_difference_overflow() {
+        let low = ProbabilityDistribution::from(ValueType::MIN);
+        let high = ProbabilityDistribution::from(1);
+        let _ = low.abs_difference(&high);
+    }
+}
@@ -0,0 +1,152 @@
+use std::ops::AddAssign;
+
+use crate::{
+    probability::{Combine, ProbabilityDistribution},
+    ValueType,
+};
+
+fn _add(lhs: ValueType, rhs: ValueType) -> ValueType {
+    lhs + rhs
+}
+
+impl AddAssign for ProbabilityDistribution {
+    /// Implements the addition assignment operator for [ProbabilityDistribution].
+    /// values are combined using the addition function.
+    /// constraint maps are combined using the ConstraintMap::add function.
+    ///
+    /// # Arguments
+    ///
+    /// * `self` - The [ProbabilityDistribution] to update.
+    /// * `other` - The second [ProbabilityDistribution] operand.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use crate::rusted_dice::probability::ProbabilityDistribution;
+    /// # use crate::rusted_dice::probability::ToTable;
+    /// let mut probability_distribution = ProbabilityDistribution::new_dice(3);
+    /// probability_distribution += ProbabilityDistribution::new_dice(3);
+    ///
+    /// assert_eq!(
+    ///     probability_distribution
+    ///         .to_table()
+    ///         .to_string()
+    ///         .replace("\r\n", "\n"),
+    ///     "\
+    ///     +-------+-------+\n\
+    ///     | value | count |\n\
+    ///     +=======+=======+\n\
+    ///     | 2     | 1     |\n\
+    ///     +-------+-------+\n\
+    ///     | 3     | 2     |\n\
+    ///     +-------+-------+\n\
+    ///     | 4     | 3     |\n\
+    ///     +-------+-------+\n\
+    ///     | 5     | 2     |\n\
+    ///     +-------+-------+\n\
+    ///     | 6     | 1     |\n\
+    ///     +-------+-------+\n\
+    ///     ");
+    /// ```
+    fn add_assign(&mut self, other: Self) {
+        *self = self.combine(other, _add);
+    }
+}
+
+impl AddAssign<ValueType> for ProbabilityDistribution {
+    /// Implements the addition assignment operator for [ProbabilityDistribution] += [ValueType].
+    /// values are combined using the addition function.
+    /// constraint map is taken from the [ProbabilityDistribution].
+    ///
+    /// # Arguments
+    ///
+    /// * `self` - The [ProbabilityDistribution] to update.
+    /// * `other` - The [ValueType] operand.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use crate::rusted_dice::probability::ProbabilityDistribution;
+    /// # use crate::rusted_dice::probability::ToTable;
+    /// let mut probability_distribution = ProbabilityDistribution::new_dice(3);
+    /// probability_distribution += 2;
+    ///
+    /// assert_eq!(
+    ///     probability_distribution
+    ///         .to_table()
+    ///         .to_string()
+    ///         .replace("\r\n", "\n"),
+    ///     "\
+    ///     +-------+-------+\n\
+    ///     | value | count |\n\
+    ///     +=======+=======+\n\
+    ///     | 3     | 1     |\n\
+    ///     +-------+-------+\n\
+    ///     | 4     | 1     |\n\
+    ///     +-------+-------+\n\
+    ///     | 5     | 1     |\n\
+    ///     +-------+-------+\n\
+    ///     ");
+    /// ```
+    fn add_assign(&mut self, other: ValueType) {
+        *self = self.combine_value_type(other, _add);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::probability::ProbabilityDistribution;
+
+    #[test]
+    fn test_add_assign_empty() {
+        let mut probability_distribution = ProbabilityDistribution::new_empty_distribution();
+        probability_distribution += ProbabilityDistribution::new_dice(6);
+        assert_eq!(probability_distribution.outcome_counts.len(), 0);
+
+        let mut probability_distribution = ProbabilityDistribution::new_dice(6);
+        probability_distribution += ProbabilityDistribution::new_empty_distribution();
+        assert_eq!(probability_distribution.outcome_counts.len(), 0);
+    }
+
+    #[test]
+    fn test_add_assign_matches_add() {
+        for (number_of_dice, number_of_sides, other_number_of_dice, other_number_of_sides) in
+            [(2, 6, 3, 4), (1, 20, 2, 8), (3, 4, 1, -6)]
+        {
+            let one = ProbabilityDistribution::new_multiple_dice(number_of_dice, number_of_sides);
+            let two = ProbabilityDistribution::new_multiple_dice(
+                other_number_of_dice,
+                other_number_of_sides,
+            );
+
+            let mut assigned = one.clone();
+            assigned += two.clone();
+            assert_eq!(assigned.outcome_counts, (one + two).outcome_counts);
+        }
+    }
+
+    #[test]
+    fn test_add_assign_matches_add_with_constraints() {
+        let one = ProbabilityDistribution::new_dice(4).add_self_value_constraint(1);
+        let two = ProbabilityDistribution::new_dice(4).add_self_value_constraint(1)
+            + ProbabilityDistribution::new_dice(3).add_self_value_constraint(2);
+
+        let mut assigned = one.clone();
+        assigned += two.clone();
+        assert_eq!(assigned.outcome_counts, (one + two).outcome_counts);
+    }
+
+    #[test]
+    fn test_add_assign_value_type() {
+        for value in [-3, 0, 1, 7] {
+            let probability_distribution = ProbabilityDistribution::new_multiple_dice(2, 6);
+
+            let mut assigned = probability_distribution.clone();
+            assigned += value;
+            assert_eq!(
+                assigned.outcome_counts,
+                (probability_distribution + value).outcome_counts
+            );
+        }
+    }
+}
@@ -0,0 +1,119 @@
+use crate::probability::{Combine, ProbabilityDistribution};
+use crate::ValueType;
+
+fn _soak(damage: ValueType, soak: ValueType) -> ValueType {
+    (damage - soak).max(0)
+}
+
+impl ProbabilityDistribution {
+    /// Reduces every value of the [ProbabilityDistribution] by a soak [ProbabilityDistribution], never going below 0.
+    /// useful to model damage after armour or damage reduction, the damage left is `max(0, damage - soak)`.
+    ///
+    /// unlike subtraction the result is never negative, outcomes soaked down to 0 are merged.
+    /// constraint maps are combined using the ConstraintMap::add function.
+    ///
+    /// # Arguments
+    ///
+    /// * `soak` - The [ProbabilityDistribution] of the damage soaked.
+    ///
+    /// # Returns
+    ///
+    /// The new [ProbabilityDistribution] of the damage left.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `damage - soak` overflows a [ValueType].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use crate::rusted_dice::probability::ProbabilityDistribution;
+    /// # use crate::rusted_dice::probability::ToTable;
+    /// let damage = ProbabilityDistribution::new_dice(4);
+    /// let soak = ProbabilityDistribution::from(2);
+    ///
+    /// assert_eq!(
+    ///     damage
+    ///         .apply_soak(&soak)
+    ///         .to_table()
+    ///         .to_string()
+    ///         .replace("\r\n", "\n"),
+    ///     "\
+    ///     +-------+-------+\n\
+    ///     | value | count |\n\
+    ///     +=======+=======+\n\
+    ///     | 0     | 2     |\n\
+    ///     +-------+-------+\n\
+    ///     | 1     | 1     |\n\
+    ///     +-------+-------+\n\
+    ///     | 2     | 1     |\n\
+    ///     +-------+-------+\n\
+    ///     ");
+    /// ```
+    pub fn apply_soak(&self, soak: &ProbabilityDistribution) -> Self {
+        self.combine(soak.clone(), _soak)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::probability::ProbabilityDistribution;
+    use crate::ValueType;
+
+    #[test]
+    fn test_apply_soak_empty() {
+        let dice = ProbabilityDistribution::new_dice(6);
+        let empty = ProbabilityDistribution::new_empty_distribution();
+        assert_eq!(dice.apply_soak(&empty).outcome_counts.len(), 0);
+        assert_eq!(empty.apply_soak(&dice).outcome_counts.len(), 0);
+    }
+
+    #[test]
+    fn test_apply_soak_constant() {
+        let soaked =
+            ProbabilityDistribution::new_dice(6).apply_soak(&ProbabilityDistribution::from(3));
+        assert_eq!(soaked.coefficients(), (0, vec![3, 1, 1, 1]));
+    }
+
+    #[test]
+    fn test_apply_soak_no_soak() {
+        let dice = ProbabilityDistribution::new_dice(6);
+        assert_eq!(
+            dice.apply_soak(&ProbabilityDistribution::from(0))
+                .coefficients(),
+            dice.coefficients()
+        );
+    }
+
+    #[test]
+    fn test_apply_soak_everything_soaked() {
+        let soaked =
+            ProbabilityDistribution::new_dice(6).apply_soak(&ProbabilityDistribution::from(10));
+        assert_eq!(soaked.coefficients(), (0, vec![6]));
+    }
+
+    #[test]
+    fn test_apply_soak_random_soak() {
+        let soaked =
+            ProbabilityDistribution::new_dice(4).apply_soak(&ProbabilityDistribution::new_dice(2));
+        // damage 1..4 minus soak 1..2, floored at 0
+        assert_eq!(soaked.coefficients(), (0, vec![3, 2, 2, 1]));
+        assert_eq!(soaked.total_outcome_count(), 8);
+    }
+
+    #[test]
+    fn test_apply_soak_keeps_constraints() {
+        let damage = ProbabilityDistribution::new_dice(3).add_self_value_constraint(1);
+        let soak = ProbabilityDistribution::new_dice(3).add_self_value_constraint(2);
+        let soaked = damage.apply_soak(&soak);
+
+        assert_eq!(soaked.outcome_counts.len(), 9);
+        assert_eq!(soaked.coefficients(), (0, vec![6, 2, 1]));
+    }
+
+    #[test]
+    #[should_panic(expected = "attempt to subtract with overflow")]
+    fn test_apply_soak_overflow() {
+        ProbabilityDistribution::from(ValueType::MIN).apply_soak(&ProbabilityDistribution::from(1));
+    }
+}
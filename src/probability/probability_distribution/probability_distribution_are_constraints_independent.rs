@@ -0,0 +1,159 @@
+use std::collections::BTreeMap;
+
+use crate::constraint_management::ConstraintIdType;
+use crate::probability::ProbabilityDistribution;
+use crate::{CountType, ValueType};
+
+impl ProbabilityDistribution {
+    /// Checks whether the tracked values of two constraints are statistically independent.
+    ///
+    /// only outcomes which have a single valid value for both `id_a` and `id_b` are considered,
+    /// the joint counts of those values must equal the product of their marginal counts
+    /// divided by the total count, for every pair of values, including pairs which never occur.
+    ///
+    /// # Arguments
+    ///
+    /// * `id_a` - The [ConstraintIdType] of the first tracked value.
+    /// * `id_b` - The [ConstraintIdType] of the second tracked value.
+    ///
+    /// # Returns
+    ///
+    /// `true` if the joint distribution of the two tracked values factorizes, `false` otherwise.
+    /// a [ProbabilityDistribution] with no outcomes tracking both values is trivially independent.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use crate::rusted_dice::probability::ProbabilityDistribution;
+    /// let dice_one = ProbabilityDistribution::new_dice(6).add_self_value_constraint(1);
+    /// let dice_two = ProbabilityDistribution::new_dice(6).add_self_value_constraint(2);
+    /// let total = (dice_one.clone() + dice_two).add_self_value_constraint(3);
+    ///
+    /// assert!(total.are_constraints_independent(1, 2));
+    /// assert!(!total.are_constraints_independent(1, 3));
+    /// ```
+    pub fn are_constraints_independent(
+        &self,
+        id_a: ConstraintIdType,
+        id_b: ConstraintIdType,
+    ) -> bool {
+        let mut joint_counts: BTreeMap<(ValueType, ValueType), CountType> = BTreeMap::new();
+        let mut a_counts: BTreeMap<ValueType, CountType> = BTreeMap::new();
+        let mut b_counts: BTreeMap<ValueType, CountType> = BTreeMap::new();
+        let mut total: CountType = 0;
+
+        for (outcome, count) in self.outcome_counts.iter() {
+            let single_value = |id: ConstraintIdType| {
+                let constraint = outcome.constraint_map.map.get(&id)?;
+                match constraint.valid_values.len() {
+                    1 => constraint.valid_values.iter().next().copied(),
+                    _ => None,
+                }
+            };
+            if let (Some(a_value), Some(b_value)) = (single_value(id_a), single_value(id_b)) {
+                *joint_counts.entry((a_value, b_value)).or_insert(0) += count;
+                *a_counts.entry(a_value).or_insert(0) += count;
+                *b_counts.entry(b_value).or_insert(0) += count;
+                total += count;
+            }
+        }
+
+        a_counts.iter().all(|(a_value, a_count)| {
+            b_counts.iter().all(|(b_value, b_count)| {
+                let joint_count = joint_counts.get(&(*a_value, *b_value)).unwrap_or(&0);
+                u128::from(*joint_count) * u128::from(total)
+                    == u128::from(*a_count) * u128::from(*b_count)
+            })
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::constraint_management::Constraint;
+    use crate::probability::{ProbabilityDistribution, ProbabilityOutcome};
+
+    #[test]
+    fn test_are_constraints_independent_empty() {
+        let probability_distribution = ProbabilityDistribution::new_empty_distribution();
+        assert!(probability_distribution.are_constraints_independent(1, 2));
+    }
+
+    #[test]
+    fn test_are_constraints_independent_untracked() {
+        let probability_distribution = ProbabilityDistribution::new_multiple_dice(2, 6);
+        assert!(probability_distribution.are_constraints_independent(1, 2));
+    }
+
+    #[test]
+    fn test_are_constraints_independent_independent_dice() {
+        let dice_one = ProbabilityDistribution::new_dice(6).add_self_value_constraint(1);
+        let dice_two = ProbabilityDistribution::new_dice(4).add_self_value_constraint(2);
+
+        assert!((dice_one.clone() + dice_two.clone()).are_constraints_independent(1, 2));
+        assert!((dice_one * dice_two).are_constraints_independent(2, 1));
+    }
+
+    #[test]
+    fn test_are_constraints_independent_dependent_dice() {
+        let dice_one = ProbabilityDistribution::new_dice(3).add_self_value_constraint(1);
+        let dice_two = ProbabilityDistribution::new_dice(3);
+        let total = (dice_one + dice_two).add_self_value_constraint(2);
+
+        assert!(!total.are_constraints_independent(1, 2));
+    }
+
+    #[test]
+    fn test_are_constraints_independent_missing_pair() {
+        let outcome = |a, b| {
+            ProbabilityOutcome::new_with_constraints(
+                0,
+                vec![
+                    Constraint::new_single_valid_value_constraint(1, a),
+                    Constraint::new_single_valid_value_constraint(2, b),
+                ],
+            )
+        };
+        let probability_distribution =
+            ProbabilityDistribution::new_from_many_probability_outcomes(vec![
+                outcome(1, 1),
+                outcome(1, 2),
+                outcome(2, 1),
+            ]);
+
+        assert!(!probability_distribution.are_constraints_independent(1, 2));
+
+        let probability_distribution =
+            ProbabilityDistribution::new_from_many_probability_outcomes(vec![
+                outcome(1, 1),
+                outcome(1, 2),
+                outcome(2, 1),
+                outcome(2, 2),
+            ]);
+
+        assert!(probability_distribution.are_constraints_independent(1, 2));
+    }
+
+    #[test]
+    fn test_are_constraints_independent_ignores_multi_valued() {
+        let probability_distribution =
+            ProbabilityDistribution::new_from_many_probability_outcomes(vec![
+                ProbabilityOutcome::new_with_constraints(
+                    0,
+                    vec![
+                        Constraint::new_single_valid_value_constraint(1, 1),
+                        Constraint::new_single_valid_value_constraint(2, 1),
+                    ],
+                ),
+                ProbabilityOutcome::new_with_constraints(
+                    0,
+                    vec![
+                        Constraint::new_single_valid_value_constraint(1, 2),
+                        Constraint::new_many_item_constraint(2, vec![1, 2]),
+                    ],
+                ),
+            ]);
+
+        assert!(probability_distribution.are_constraints_independent(1, 2));
+    }
+}
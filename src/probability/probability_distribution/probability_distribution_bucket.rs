@@ -0,0 +1,121 @@
+use crate::probability::ProbabilityDistribution;
+use crate::ValueType;
+
+impl ProbabilityDistribution {
+    /// Groups the values of the [ProbabilityDistribution] into bins of `bin_size`,
+    /// for example grouping a d100 into tens for a coarse histogram.
+    /// every value is moved down to the base of its bin, `value.div_euclid(bin_size) * bin_size`,
+    /// so negative values are floored the same way as positive values, -1 is in the bin starting at -`bin_size`.
+    /// constraint maps are kept, outcomes which end up equal are merged and their counts added.
+    ///
+    /// # Arguments
+    ///
+    /// * `bin_size` - The width of each bin.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `bin_size` is not positive.
+    /// Panics in debug builds if the base of a bin overflows [ValueType].
+    ///
+    /// # Returns
+    ///
+    /// The new [ProbabilityDistribution].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use crate::rusted_dice::probability::ProbabilityDistribution;
+    /// let d100 = ProbabilityDistribution::new_dice(100) - 1;
+    /// let tens: Vec<_> = d100.bucket(10).iter_values().collect();
+    /// assert_eq!(tens, (0..10).map(|bin| (bin * 10, 10)).collect::<Vec<_>>());
+    ///
+    /// let signed = ProbabilityDistribution::new_dice(6) - 4;
+    /// assert_eq!(signed.bucket(2).coefficients(), (-4, vec![1, 0, 2, 0, 2, 0, 1]));
+    /// ```
+    pub fn bucket(&self, bin_size: ValueType) -> Self {
+        if bin_size <= 0 {
+            panic!("Bin size must be positive.");
+        }
+        self.map_values(|value| value.div_euclid(bin_size) * bin_size)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::probability::{ProbabilityDistribution, ProbabilityOutcome};
+
+    #[test]
+    fn test_bucket_empty() {
+        let empty = ProbabilityDistribution::new_empty_distribution();
+        assert_eq!(empty.bucket(10).outcome_counts.len(), 0);
+    }
+
+    #[test]
+    fn test_bucket_zero_based_d100_by_ten() {
+        let d100 = ProbabilityDistribution::new_dice(100) - 1;
+        let tens = d100.bucket(10);
+
+        assert_eq!(tens.outcome_counts.len(), 10);
+        for (probability_outcome, count) in tens.outcome_counts.iter() {
+            assert_eq!(probability_outcome.value % 10, 0);
+            assert_eq!(*count, 10);
+        }
+        assert_eq!(tens.min_value(), Some(0));
+        assert_eq!(tens.max_value(), Some(90));
+    }
+
+    #[test]
+    fn test_bucket_d100_by_ten() {
+        let tens = ProbabilityDistribution::new_dice(100).bucket(10);
+
+        assert_eq!(tens.count_of(0), 9);
+        for value in (10..100).step_by(10) {
+            assert_eq!(tens.count_of(value), 10);
+        }
+        assert_eq!(tens.count_of(100), 1);
+        assert_eq!(tens.total_outcome_count(), 100);
+    }
+
+    #[test]
+    fn test_bucket_negative_values_floor() {
+        let probability_distribution = ProbabilityDistribution::new_dice(10) - 7;
+        let bucketed = probability_distribution.bucket(5);
+
+        assert_eq!(bucketed.count_of(-10), 1);
+        assert_eq!(bucketed.count_of(-5), 5);
+        assert_eq!(bucketed.count_of(0), 4);
+        assert_eq!(bucketed.outcome_counts.len(), 3);
+    }
+
+    #[test]
+    fn test_bucket_size_one_is_unchanged() {
+        let two_d6 = ProbabilityDistribution::new_multiple_dice(2, 6).add_self_value_constraint(1);
+        assert_eq!(two_d6.bucket(1).outcome_counts, two_d6.outcome_counts);
+    }
+
+    #[test]
+    fn test_bucket_keeps_constraints() {
+        let dice = ProbabilityDistribution::new_dice(4).add_self_value_constraint(1);
+        let bucketed = dice.bucket(4);
+        assert_eq!(bucketed.outcome_counts.len(), 4);
+        assert!(bucketed
+            .outcome_counts
+            .keys()
+            .all(
+                |probability_outcome: &ProbabilityOutcome| probability_outcome.value == 0
+                    || probability_outcome.value == 4
+            ));
+    }
+
+    #[test]
+    #[should_panic(expected = "Bin size must be positive.")]
+    fn test_bucket_zero_size_panics() {
+        ProbabilityDistribution::new_dice(6).bucket(0);
+    }
+
+    #[test]
+    #[should_panic(expected = "Bin size must be positive.")]
+    fn test_bucket_negative_size_panics() {
+        ProbabilityDistribution::new_dice(6).bucket(-2);
+    }
+}
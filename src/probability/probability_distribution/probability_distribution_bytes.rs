@@ -0,0 +1,434 @@
+use std::collections::BTreeMap;
+use std::error::Error;
+use std::fmt;
+
+use crate::constraint_management::{Constraint, ConstraintIdType, ConstraintMap};
+use crate::probability::{ProbabilityDistribution, ProbabilityOutcome};
+use crate::{CountType, ValueType};
+
+use super::checked_add_outcome_to_map;
+
+const BYTES_FORMAT_VERSION: u8 = 1;
+
+/// The error returned by [ProbabilityDistribution::from_bytes] when the bytes are not a valid encoding.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum DecodeError {
+    /// the bytes ended part way through the encoding.
+    UnexpectedEnd,
+    /// the encoding was written by an unsupported version of the format.
+    UnsupportedVersion(u8),
+    /// a number in the encoding does not fit in its type.
+    Overflow,
+    /// the encoding was followed by extra bytes.
+    TrailingBytes,
+    /// an outcome in the encoding has a count of 0.
+    ZeroCount,
+}
+
+impl fmt::Display for DecodeError {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DecodeError::UnexpectedEnd => write!(formatter, "Unexpected end of bytes."),
+            DecodeError::UnsupportedVersion(version) => {
+                write!(formatter, "Unsupported format version {}.", version)
+            }
+            DecodeError::Overflow => write!(formatter, "Encoded number does not fit its type."),
+            DecodeError::TrailingBytes => write!(formatter, "Unexpected bytes after the encoding."),
+            DecodeError::ZeroCount => write!(formatter, "Encoded outcome has a count of zero."),
+        }
+    }
+}
+
+impl Error for DecodeError {}
+
+fn write_varint(bytes: &mut Vec<u8>, mut number: u64) {
+    while number >= 0x80 {
+        bytes.push((number as u8 & 0x7f) | 0x80);
+        number >>= 7;
+    }
+    bytes.push(number as u8);
+}
+
+fn write_signed_varint(bytes: &mut Vec<u8>, number: i64) {
+    write_varint(bytes, ((number << 1) ^ (number >> 63)) as u64);
+}
+
+fn read_varint(bytes: &mut &[u8]) -> Result<u64, DecodeError> {
+    let mut number: u64 = 0;
+    for shift in (0..64).step_by(7) {
+        let (byte, rest) = bytes.split_first().ok_or(DecodeError::UnexpectedEnd)?;
+        *bytes = rest;
+        let low_bits = u64::from(byte & 0x7f);
+        if shift == 63 && low_bits > 1 {
+            return Err(DecodeError::Overflow);
+        }
+        number |= low_bits << shift;
+        if byte & 0x80 == 0 {
+            return Ok(number);
+        }
+    }
+    Err(DecodeError::Overflow)
+}
+
+fn read_signed_varint(bytes: &mut &[u8]) -> Result<i64, DecodeError> {
+    let number = read_varint(bytes)?;
+    Ok((number >> 1) as i64 ^ -((number & 1) as i64))
+}
+
+fn read_length(bytes: &mut &[u8]) -> Result<usize, DecodeError> {
+    usize::try_from(read_varint(bytes)?).map_err(|_| DecodeError::Overflow)
+}
+
+fn read_value_offset(bytes: &mut &[u8], previous: ValueType) -> Result<ValueType, DecodeError> {
+    let offset = read_signed_varint(bytes)?;
+    i64::from(previous)
+        .checked_add(offset)
+        .and_then(|value| ValueType::try_from(value).ok())
+        .ok_or(DecodeError::Overflow)
+}
+
+impl ProbabilityDistribution {
+    /// Encodes the [ProbabilityDistribution] into a compact binary format.
+    /// intended for caching computed [ProbabilityDistribution]s, not for reading by people.
+    ///
+    /// the encoding is a version byte, then the number of outcomes, then for each outcome in order
+    /// the offset of its value from the previous value, its count and its constraints.
+    /// each constraint is its id, the number of valid values and the valid values in ascending order,
+    /// each as an offset from the previous valid value. constraints are written in ascending id order,
+    /// so equal [ProbabilityDistribution]s always encode to equal bytes.
+    /// all numbers are LEB128 varints, signed offsets are zigzag encoded.
+    ///
+    /// # Arguments
+    ///
+    /// * `self` - The [ProbabilityDistribution] to encode.
+    ///
+    /// # Returns
+    ///
+    /// The encoded bytes.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use crate::rusted_dice::probability::ProbabilityDistribution;
+    /// let dice = ProbabilityDistribution::new_dice(3);
+    /// assert_eq!(dice.to_bytes(), vec![1, 3, 2, 1, 0, 2, 1, 0, 2, 1, 0]);
+    /// ```
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = vec![BYTES_FORMAT_VERSION];
+        write_varint(&mut bytes, self.outcome_counts.len() as u64);
+
+        let mut previous_value: ValueType = 0;
+        for (outcome, count) in self.outcome_counts.iter() {
+            write_signed_varint(
+                &mut bytes,
+                i64::from(outcome.value) - i64::from(previous_value),
+            );
+            previous_value = outcome.value;
+            write_varint(&mut bytes, *count);
+
+            let mut constraints: Vec<&Constraint> = outcome.constraint_map.map.values().collect();
+            constraints.sort_by_key(|constraint| constraint.id);
+            write_varint(&mut bytes, constraints.len() as u64);
+            for constraint in constraints {
+                write_varint(&mut bytes, constraint.id.into());
+
+                let mut valid_values: Vec<ValueType> =
+                    constraint.valid_values.iter().copied().collect();
+                valid_values.sort();
+                write_varint(&mut bytes, valid_values.len() as u64);
+                let mut previous_valid_value: ValueType = 0;
+                for valid_value in valid_values {
+                    write_signed_varint(
+                        &mut bytes,
+                        i64::from(valid_value) - i64::from(previous_valid_value),
+                    );
+                    previous_valid_value = valid_value;
+                }
+            }
+        }
+        bytes
+    }
+
+    /// Decodes a [ProbabilityDistribution] from the binary format written by [ProbabilityDistribution::to_bytes].
+    ///
+    /// # Arguments
+    ///
+    /// * `bytes` - The encoded bytes.
+    ///
+    /// # Returns
+    ///
+    /// The decoded [ProbabilityDistribution], or a [DecodeError] if the bytes are not a valid encoding.
+    /// outcomes encoded more than once have their counts summed, [DecodeError::Overflow] is returned
+    /// if the sum does not fit in [CountType]. outcomes with a count of 0 are rejected with [DecodeError::ZeroCount].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use crate::rusted_dice::probability::{DecodeError, ProbabilityDistribution, ToTable};
+    /// let two_d6 = ProbabilityDistribution::new_multiple_dice(2, 6);
+    /// let bytes = two_d6.to_bytes();
+    ///
+    /// assert_eq!(
+    ///     ProbabilityDistribution::from_bytes(&bytes).unwrap().to_table(),
+    ///     two_d6.to_table()
+    /// );
+    /// assert_eq!(
+    ///     ProbabilityDistribution::from_bytes(&bytes[..bytes.len() - 1]).unwrap_err(),
+    ///     DecodeError::UnexpectedEnd
+    /// );
+    /// ```
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, DecodeError> {
+        let mut bytes = bytes;
+        let (version, rest) = bytes.split_first().ok_or(DecodeError::UnexpectedEnd)?;
+        if *version != BYTES_FORMAT_VERSION {
+            return Err(DecodeError::UnsupportedVersion(*version));
+        }
+        bytes = rest;
+
+        let mut map: BTreeMap<ProbabilityOutcome, CountType> = BTreeMap::new();
+        let number_of_outcomes = read_length(&mut bytes)?;
+        let mut previous_value: ValueType = 0;
+        for _ in 0..number_of_outcomes {
+            let value = read_value_offset(&mut bytes, previous_value)?;
+            previous_value = value;
+            let count: CountType = read_varint(&mut bytes)?;
+            if count == 0 {
+                return Err(DecodeError::ZeroCount);
+            }
+
+            let number_of_constraints = read_length(&mut bytes)?;
+            let mut constraints: Vec<Constraint> = Vec::new();
+            for _ in 0..number_of_constraints {
+                let id = ConstraintIdType::try_from(read_varint(&mut bytes)?)
+                    .map_err(|_| DecodeError::Overflow)?;
+
+                let number_of_valid_values = read_length(&mut bytes)?;
+                let mut valid_values: Vec<ValueType> = Vec::new();
+                let mut previous_valid_value: ValueType = 0;
+                for _ in 0..number_of_valid_values {
+                    let valid_value = read_value_offset(&mut bytes, previous_valid_value)?;
+                    previous_valid_value = valid_value;
+                    valid_values.push(valid_value);
+                }
+                constraints.push(Constraint::new_many_item_constraint(id, valid_values));
+            }
+
+            checked_add_outcome_to_map(
+                &mut map,
+                ProbabilityOutcome {
+                    value,
+                    constraint_map: ConstraintMap::new_constraint_map(constraints),
+                },
+                count,
+            )
+            .ok_or(DecodeError::Overflow)?;
+        }
+
+        if !bytes.is_empty() {
+            return Err(DecodeError::TrailingBytes);
+        }
+        Ok(ProbabilityDistribution {
+            outcome_counts: map,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{read_signed_varint, read_varint, write_signed_varint, write_varint, DecodeError};
+    use crate::probability::{ProbabilityDistribution, ProbabilityOutcome};
+    use crate::ValueType;
+
+    use proptest::prelude::*;
+
+    fn constrained_three_d6() -> ProbabilityDistribution {
+        let dice_one = ProbabilityDistribution::new_dice(6).add_self_value_constraint(1);
+        let dice_two = ProbabilityDistribution::new_dice(6).add_self_value_constraint(2);
+        let dice_three = ProbabilityDistribution::new_dice(6).add_self_value_constraint(300);
+        (dice_one + dice_two + dice_three).add_self_value_constraint(4)
+    }
+
+    proptest! {
+        #[test]
+        fn test_varint_round_trip(number: u64) {
+            let mut bytes = Vec::new();
+            write_varint(&mut bytes, number);
+            let mut slice = bytes.as_slice();
+            prop_assert_eq!(read_varint(&mut slice), Ok(number));
+            prop_assert!(slice.is_empty());
+        }
+
+        #[test]
+        fn test_signed_varint_round_trip(number: i64) {
+            let mut bytes = Vec::new();
+            write_signed_varint(&mut bytes, number);
+            let mut slice = bytes.as_slice();
+            prop_assert_eq!(read_signed_varint(&mut slice), Ok(number));
+            prop_assert!(slice.is_empty());
+        }
+
+        #[test]
+        fn test_bytes_round_trip_values(values: Vec<ValueType>) {
+            let probability_distribution = ProbabilityDistribution::new_from_many_probability_outcomes(
+                values
+                    .into_iter()
+                    .map(ProbabilityOutcome::new_with_empty_constraint_map)
+                    .collect::<Vec<_>>(),
+            );
+            let decoded = ProbabilityDistribution::from_bytes(&probability_distribution.to_bytes());
+            prop_assert_eq!(decoded.unwrap().outcome_counts, probability_distribution.outcome_counts);
+        }
+    }
+
+    #[test]
+    fn test_bytes_empty() {
+        let probability_distribution = ProbabilityDistribution::new_empty_distribution();
+        assert_eq!(probability_distribution.to_bytes(), vec![1, 0]);
+        assert_eq!(
+            ProbabilityDistribution::from_bytes(&[1, 0])
+                .unwrap()
+                .outcome_counts
+                .len(),
+            0
+        );
+    }
+
+    #[test]
+    fn test_bytes_round_trip_constrained_three_d6() {
+        let probability_distribution = constrained_three_d6();
+        let bytes = probability_distribution.to_bytes();
+
+        assert_eq!(
+            ProbabilityDistribution::from_bytes(&bytes)
+                .unwrap()
+                .outcome_counts,
+            probability_distribution.outcome_counts
+        );
+        assert_eq!(constrained_three_d6().to_bytes(), bytes);
+    }
+
+    #[test]
+    fn test_bytes_large_counts_round_trip() {
+        let probability_distribution = ProbabilityDistribution::new_multiple_dice(12, 20);
+        assert_eq!(
+            ProbabilityDistribution::from_bytes(&probability_distribution.to_bytes())
+                .unwrap()
+                .outcome_counts,
+            probability_distribution.outcome_counts
+        );
+    }
+
+    #[test]
+    fn test_from_bytes_truncated() {
+        let dice_one = ProbabilityDistribution::new_dice(4).add_self_value_constraint(1);
+        let dice_two = ProbabilityDistribution::new_dice(4).add_self_value_constraint(2);
+        let bytes = (dice_one + dice_two).to_bytes();
+        for length in 0..bytes.len() {
+            assert_eq!(
+                ProbabilityDistribution::from_bytes(&bytes[..length]).unwrap_err(),
+                DecodeError::UnexpectedEnd
+            );
+        }
+    }
+
+    #[test]
+    fn test_from_bytes_trailing_bytes() {
+        let mut bytes = ProbabilityDistribution::new_dice(2).to_bytes();
+        bytes.push(0);
+        assert_eq!(
+            ProbabilityDistribution::from_bytes(&bytes).unwrap_err(),
+            DecodeError::TrailingBytes
+        );
+    }
+
+    #[test]
+    fn test_from_bytes_unsupported_version() {
+        assert_eq!(
+            ProbabilityDistribution::from_bytes(&[7, 0]).unwrap_err(),
+            DecodeError::UnsupportedVersion(7)
+        );
+    }
+
+    #[test]
+    fn test_from_bytes_overflow() {
+        let mut bytes = vec![1, 1];
+        write_signed_varint(&mut bytes, i64::from(ValueType::MAX) + 1);
+        bytes.extend([1, 0]);
+        assert_eq!(
+            ProbabilityDistribution::from_bytes(&bytes).unwrap_err(),
+            DecodeError::Overflow
+        );
+
+        let bytes = [
+            1, 1, 0, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0x7f,
+        ];
+        assert_eq!(
+            ProbabilityDistribution::from_bytes(&bytes).unwrap_err(),
+            DecodeError::Overflow
+        );
+
+        let bytes = [1, 1, 0, 1, 1, 0x80, 0x80, 0x04, 0];
+        assert_eq!(
+            ProbabilityDistribution::from_bytes(&bytes).unwrap_err(),
+            DecodeError::Overflow
+        );
+    }
+
+    #[test]
+    fn test_from_bytes_repeated_outcome_sums_counts() {
+        let bytes = [1, 2, 2, 3, 0, 0, 4, 0];
+        assert_eq!(
+            ProbabilityDistribution::from_bytes(&bytes)
+                .unwrap()
+                .outcome_counts
+                .get(&ProbabilityOutcome::new_with_empty_constraint_map(1)),
+            Some(&7)
+        );
+    }
+
+    #[test]
+    fn test_from_bytes_repeated_outcome_count_overflow() {
+        let mut bytes = vec![1, 2];
+        for value_offset in [2, 0] {
+            bytes.push(value_offset);
+            write_varint(&mut bytes, u64::MAX);
+            bytes.push(0);
+        }
+        assert_eq!(
+            ProbabilityDistribution::from_bytes(&bytes).unwrap_err(),
+            DecodeError::Overflow
+        );
+    }
+
+    #[test]
+    fn test_from_bytes_zero_count() {
+        assert_eq!(
+            ProbabilityDistribution::from_bytes(&[1, 1, 0, 0, 0]).unwrap_err(),
+            DecodeError::ZeroCount
+        );
+    }
+
+    #[test]
+    fn test_decode_error_display() {
+        assert_eq!(
+            DecodeError::UnexpectedEnd.to_string(),
+            "Unexpected end of bytes."
+        );
+        assert_eq!(
+            DecodeError::UnsupportedVersion(3).to_string(),
+            "Unsupported format version 3."
+        );
+        assert_eq!(
+            DecodeError::Overflow.to_string(),
+            "Encoded number does not fit its type."
+        );
+        assert_eq!(
+            DecodeError::TrailingBytes.to_string(),
+            "Unexpected bytes after the encoding."
+        );
+        assert_eq!(
+            DecodeError::ZeroCount.to_string(),
+            "Encoded outcome has a count of zero."
+        );
+    }
+}
@@ -0,0 +1,224 @@
+use std::collections::BTreeMap;
+
+use crate::probability::{ProbabilityDistribution, ProbabilityOutcome};
+use crate::{CountType, ValueType};
+
+impl ProbabilityDistribution {
+    /// Creates a new [ProbabilityDistribution] with [ProbabilityOutcome]s representing rolling M, N sided dice,
+    /// with every accumulated count clamped to at most `count_cap`.
+    ///
+    /// this is an approximation, counts which would be larger than `count_cap` are replaced by `count_cap`
+    /// and the clamped counts are used for the remaining dice, so the result is only exact while every
+    /// intermediate count stays below `count_cap`. it exists so that very large pools, whose exact counts
+    /// would overflow [CountType], can be built without panicking. the set of values is always exact,
+    /// and the counts of the tails, which are small, stay exact.
+    ///
+    /// for negative n, Zero n and Zero m the behaviour matches [ProbabilityDistribution::new_multiple_dice].
+    ///
+    /// for Zero `count_cap`, the [ProbabilityDistribution] will have no [ProbabilityOutcome]s,
+    /// as an outcome with a count of 0 can not be rolled.
+    ///
+    /// # Arguments
+    ///
+    /// * `number_of_dice` - [u16] The the number of dice to roll.
+    /// * `number_of_sides` - [ValueType] The the number of sides the dice has.
+    /// * `count_cap` - [CountType] The largest count any [ProbabilityOutcome] can have.
+    ///
+    /// # Returns
+    ///
+    /// The new [ProbabilityDistribution].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use crate::rusted_dice::probability::ProbabilityDistribution;
+    /// # use crate::rusted_dice::probability::ToTable;
+    /// let three_d4 = ProbabilityDistribution::new_multiple_dice_capped(3, 4, 8);
+    ///
+    /// assert_eq!(
+    ///     three_d4
+    ///         .to_table()
+    ///         .to_string()
+    ///         .replace("\r\n", "\n"),
+    ///     "\
+    ///     +-------+-------+\n\
+    ///     | value | count |\n\
+    ///     +=======+=======+\n\
+    ///     | 3     | 1     |\n\
+    ///     +-------+-------+\n\
+    ///     | 4     | 3     |\n\
+    ///     +-------+-------+\n\
+    ///     | 5     | 6     |\n\
+    ///     +-------+-------+\n\
+    ///     | 6     | 8     |\n\
+    ///     +-------+-------+\n\
+    ///     | 7     | 8     |\n\
+    ///     +-------+-------+\n\
+    ///     | 8     | 8     |\n\
+    ///     +-------+-------+\n\
+    ///     | 9     | 8     |\n\
+    ///     +-------+-------+\n\
+    ///     | 10    | 6     |\n\
+    ///     +-------+-------+\n\
+    ///     | 11    | 3     |\n\
+    ///     +-------+-------+\n\
+    ///     | 12    | 1     |\n\
+    ///     +-------+-------+\n\
+    ///     ");
+    /// ```
+    pub fn new_multiple_dice_capped(
+        number_of_dice: u16,
+        number_of_sides: ValueType,
+        count_cap: CountType,
+    ) -> ProbabilityDistribution {
+        if number_of_dice == 0 || number_of_sides == 0 || count_cap == 0 {
+            return ProbabilityDistribution::new_empty_distribution();
+        }
+
+        let single_dice = ProbabilityDistribution::new_dice(number_of_sides);
+        let mut combined_probability_distribution = ProbabilityDistribution {
+            outcome_counts: single_dice
+                .outcome_counts
+                .iter()
+                .map(|(outcome, count)| (outcome.clone(), (*count).min(count_cap)))
+                .collect(),
+        };
+
+        for _ in 1..number_of_dice {
+            let mut new_outcome_counts: BTreeMap<ProbabilityOutcome, CountType> = BTreeMap::new();
+            for (outcome_one, count_one) in combined_probability_distribution.outcome_counts.iter()
+            {
+                for (outcome_two, count_two) in single_dice.outcome_counts.iter() {
+                    let new_count = new_outcome_counts
+                        .entry(ProbabilityOutcome::new_with_empty_constraint_map(
+                            outcome_one.value + outcome_two.value,
+                        ))
+                        .or_insert(0);
+                    *new_count = new_count
+                        .saturating_add(count_one.saturating_mul(*count_two))
+                        .min(count_cap);
+                }
+            }
+            combined_probability_distribution = ProbabilityDistribution {
+                outcome_counts: new_outcome_counts,
+            };
+        }
+
+        combined_probability_distribution
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::probability::{ProbabilityDistribution, ProbabilityOutcome, ToTable};
+    use crate::CountType;
+
+    #[test]
+    fn test_new_multiple_dice_capped_zero() {
+        assert_eq!(
+            ProbabilityDistribution::new_multiple_dice_capped(0, 6, 10)
+                .outcome_counts
+                .len(),
+            0
+        );
+        assert_eq!(
+            ProbabilityDistribution::new_multiple_dice_capped(3, 0, 10)
+                .outcome_counts
+                .len(),
+            0
+        );
+    }
+
+    #[test]
+    fn test_new_multiple_dice_capped_zero_cap() {
+        assert_eq!(
+            ProbabilityDistribution::new_multiple_dice_capped(1, 6, 0)
+                .outcome_counts
+                .len(),
+            0
+        );
+        assert_eq!(
+            ProbabilityDistribution::new_multiple_dice_capped(2, 6, 0)
+                .outcome_counts
+                .len(),
+            0
+        );
+    }
+
+    #[test]
+    fn test_new_multiple_dice_capped_single_dice_within_cap() {
+        let probability_distribution = ProbabilityDistribution::new_multiple_dice_capped(1, 6, 1);
+        assert_eq!(
+            probability_distribution.to_table(),
+            ProbabilityDistribution::new_dice(6).to_table()
+        );
+        assert!(probability_distribution
+            .outcome_counts
+            .values()
+            .all(|count| *count <= 1));
+    }
+
+    #[test]
+    fn test_new_multiple_dice_capped_large_cap_is_exact() {
+        for (number_of_dice, number_of_sides) in [(1, 6), (3, 6), (4, -4), (5, 10)] {
+            assert_eq!(
+                ProbabilityDistribution::new_multiple_dice_capped(
+                    number_of_dice,
+                    number_of_sides,
+                    CountType::MAX
+                )
+                .to_table(),
+                ProbabilityDistribution::new_multiple_dice(number_of_dice, number_of_sides)
+                    .to_table()
+            );
+        }
+    }
+
+    #[test]
+    fn test_new_multiple_dice_capped_overflowing_pool() {
+        let count_cap = CountType::MAX / 2;
+        let probability_distribution =
+            ProbabilityDistribution::new_multiple_dice_capped(12, 100, count_cap);
+        let counts: Vec<CountType> = probability_distribution
+            .outcome_counts
+            .values()
+            .copied()
+            .collect();
+
+        assert_eq!(counts.len(), 1189);
+        assert!(counts.iter().all(|count| *count <= count_cap));
+        assert_eq!(counts.iter().max(), Some(&count_cap));
+        assert_eq!(
+            probability_distribution
+                .outcome_counts
+                .get(&ProbabilityOutcome::new_with_empty_constraint_map(12)),
+            Some(&1)
+        );
+        assert_eq!(
+            probability_distribution
+                .outcome_counts
+                .get(&ProbabilityOutcome::new_with_empty_constraint_map(13)),
+            Some(&12)
+        );
+    }
+
+    #[test]
+    fn test_new_multiple_dice_capped_shape_preserved() {
+        let probability_distribution =
+            ProbabilityDistribution::new_multiple_dice_capped(20, 6, 1000);
+        let counts: Vec<CountType> = probability_distribution
+            .outcome_counts
+            .values()
+            .copied()
+            .collect();
+
+        assert_eq!(
+            counts,
+            counts.iter().rev().copied().collect::<Vec<CountType>>()
+        );
+        let middle = counts.len() / 2;
+        assert!(counts[..=middle].windows(2).all(|pair| pair[0] <= pair[1]));
+        assert!(counts[middle..].windows(2).all(|pair| pair[0] >= pair[1]));
+        assert_eq!(counts[middle], 1000);
+    }
+}
@@ -0,0 +1,127 @@
+use crate::probability::ProbabilityDistribution;
+use crate::ValueType;
+
+impl ProbabilityDistribution {
+    /// Restricts every value of the [ProbabilityDistribution] to the inclusive range `min` to `max`,
+    /// for example a "minimum damage 1" rule. values outside the range are moved to the nearest bound,
+    /// constraint maps are kept, outcomes which end up equal are merged and their counts added.
+    ///
+    /// # Arguments
+    ///
+    /// * `min` - The lowest [ValueType] allowed.
+    /// * `max` - The highest [ValueType] allowed.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `min` is greater than `max`.
+    ///
+    /// # Returns
+    ///
+    /// The new [ProbabilityDistribution].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use crate::rusted_dice::probability::ProbabilityDistribution;
+    /// # use crate::rusted_dice::probability::ToTable;
+    /// let damage = ProbabilityDistribution::new_dice(4) - 2;
+    ///
+    /// assert_eq!(
+    ///     damage
+    ///         .clamp(1, 10)
+    ///         .to_table()
+    ///         .to_string()
+    ///         .replace("\r\n", "\n"),
+    ///     "\
+    ///     +-------+-------+\n\
+    ///     | value | count |\n\
+    ///     +=======+=======+\n\
+    ///     | 1     | 3     |\n\
+    ///     +-------+-------+\n\
+    ///     | 2     | 1     |\n\
+    ///     +-------+-------+\n\
+    ///     ");
+    /// ```
+    pub fn clamp(&self, min: ValueType, max: ValueType) -> Self {
+        if min > max {
+            panic!("Clamp min must not be greater than max.");
+        }
+        self.map_values(|value| value.clamp(min, max))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::constraint_management::Constraint;
+    use crate::probability::{ProbabilityDistribution, ProbabilityOutcome};
+
+    #[test]
+    fn test_clamp_empty() {
+        let probability_distribution =
+            ProbabilityDistribution::new_empty_distribution().clamp(1, 6);
+        assert_eq!(probability_distribution.outcome_counts.len(), 0);
+    }
+
+    #[test]
+    fn test_clamp_wide_range_unchanged() {
+        let probability_distribution = ProbabilityDistribution::new_multiple_dice(2, 6);
+        assert_eq!(
+            probability_distribution.clamp(-100, 100).outcome_counts,
+            probability_distribution.outcome_counts
+        );
+    }
+
+    #[test]
+    fn test_clamp_single_value() {
+        let probability_distribution = ProbabilityDistribution::new_multiple_dice(2, 6).clamp(4, 4);
+
+        assert_eq!(probability_distribution.outcome_counts.len(), 1);
+        assert_eq!(
+            probability_distribution
+                .outcome_counts
+                .get(&ProbabilityOutcome::new_with_empty_constraint_map(4)),
+            Some(&36)
+        );
+    }
+
+    #[test]
+    fn test_clamp_d20() {
+        let probability_distribution = ProbabilityDistribution::new_dice(20).clamp(5, 15);
+
+        assert_eq!(probability_distribution.outcome_counts.len(), 11);
+        assert_eq!(probability_distribution.total_outcome_count(), 20);
+        for (value, count) in [(5, 5), (6, 1), (10, 1), (14, 1), (15, 6)] {
+            assert_eq!(
+                probability_distribution
+                    .outcome_counts
+                    .get(&ProbabilityOutcome::new_with_empty_constraint_map(value)),
+                Some(&count)
+            );
+        }
+    }
+
+    #[test]
+    fn test_clamp_keeps_constraints() {
+        let probability_distribution = ProbabilityDistribution::new_dice(3)
+            .add_self_value_constraint(2)
+            .clamp(2, 2);
+
+        for face in 1..=3 {
+            assert_eq!(
+                probability_distribution.outcome_counts.get(
+                    &ProbabilityOutcome::new_with_constraints(
+                        2,
+                        vec![Constraint::new_single_valid_value_constraint(2, face)],
+                    )
+                ),
+                Some(&1)
+            );
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "Clamp min must not be greater than max.")]
+    fn test_clamp_min_greater_than_max() {
+        ProbabilityDistribution::new_dice(6).clamp(4, 3);
+    }
+}
@@ -0,0 +1,214 @@
+use std::collections::BTreeMap;
+
+use crate::probability::{ProbabilityDistribution, ProbabilityOutcome};
+use crate::{CountType, ValueType};
+
+use super::{add_outcome_to_map, collapse_outcome_to_count_map};
+
+impl ProbabilityDistribution {
+    /// Returns the coefficients of the generating function of the [ProbabilityDistribution].
+    /// outcomes sharing a value but differing in constraints are counted together.
+    ///
+    /// the coefficients are dense, index i holds the count of the value `offset + i`,
+    /// values between the lowest and highest value that can not be rolled have a count of 0.
+    ///
+    /// # Arguments
+    ///
+    /// * `self` - The [ProbabilityDistribution] to get the coefficients from.
+    ///
+    /// # Returns
+    ///
+    /// A tuple of the lowest value as the offset and the [Vec] of [CountType] coefficients.
+    /// an empty [ProbabilityDistribution] returns an offset of 0 and no coefficients.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use crate::rusted_dice::probability::ProbabilityDistribution;
+    /// let two_d4 = ProbabilityDistribution::new_multiple_dice(2, 4);
+    /// assert_eq!(two_d4.coefficients(), (2, vec![1, 2, 3, 4, 3, 2, 1]));
+    ///
+    /// let d4_times_two = ProbabilityDistribution::new_dice(4) * 2;
+    /// assert_eq!(d4_times_two.coefficients(), (2, vec![1, 0, 1, 0, 1, 0, 1]));
+    /// ```
+    pub fn coefficients(&self) -> (ValueType, Vec<CountType>) {
+        let value_to_count_map = collapse_outcome_to_count_map(&self.outcome_counts);
+
+        let (offset, max_value) = match (
+            value_to_count_map.first_key_value(),
+            value_to_count_map.last_key_value(),
+        ) {
+            (Some((min_value, _)), Some((max_value, _))) => (*min_value, *max_value),
+            _ => return (0, Vec::new()),
+        };
+
+        let mut coefficients = vec![0; (max_value as i64 - offset as i64 + 1) as usize];
+        for (value, count) in value_to_count_map {
+            coefficients[(value as i64 - offset as i64) as usize] = count;
+        }
+        (offset, coefficients)
+    }
+
+    /// Creates a new [ProbabilityDistribution] from the coefficients of a generating function.
+    /// the inverse of [ProbabilityDistribution::coefficients].
+    ///
+    /// each coefficient at index i becomes a [ProbabilityOutcome] with value `offset + i`
+    /// and an empty constraint map, coefficients of 0 are skipped.
+    ///
+    /// # Arguments
+    ///
+    /// * `offset` - The [ValueType] of the first coefficient.
+    /// * `counts` - The [CountType] coefficients.
+    ///
+    /// # Returns
+    ///
+    /// The new [ProbabilityDistribution].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use crate::rusted_dice::probability::ProbabilityDistribution;
+    /// # use crate::rusted_dice::probability::ToTable;
+    /// let probability_distribution = ProbabilityDistribution::from_coefficients(-1, &[1, 0, 2]);
+    ///
+    /// assert_eq!(
+    ///     probability_distribution
+    ///         .to_table()
+    ///         .to_string()
+    ///         .replace("\r\n", "\n"),
+    ///     "\
+    ///     +-------+-------+\n\
+    ///     | value | count |\n\
+    ///     +=======+=======+\n\
+    ///     | -1    | 1     |\n\
+    ///     +-------+-------+\n\
+    ///     | 1     | 2     |\n\
+    ///     +-------+-------+\n\
+    ///     ");
+    /// ```
+    pub fn from_coefficients(offset: ValueType, counts: &[CountType]) -> Self {
+        let mut map = BTreeMap::new();
+        for (index, count) in counts.iter().enumerate() {
+            if *count != 0 {
+                add_outcome_to_map(
+                    &mut map,
+                    ProbabilityOutcome::new_with_empty_constraint_map(offset + index as ValueType),
+                    *count,
+                );
+            }
+        }
+        ProbabilityDistribution {
+            outcome_counts: map,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::constraint_management::Constraint;
+    use crate::probability::{ProbabilityDistribution, ProbabilityOutcome};
+
+    #[test]
+    fn test_coefficients_empty() {
+        let probability_distribution = ProbabilityDistribution::new_empty_distribution();
+        assert_eq!(probability_distribution.coefficients(), (0, vec![]));
+    }
+
+    #[test]
+    fn test_coefficients_single_outcome() {
+        let probability_distribution = ProbabilityDistribution::new_from_single_probability_outcome(
+            ProbabilityOutcome::new_with_empty_constraint_map(-7),
+        );
+        assert_eq!(probability_distribution.coefficients(), (-7, vec![1]));
+    }
+
+    #[test]
+    fn test_coefficients_two_d6() {
+        let probability_distribution = ProbabilityDistribution::new_multiple_dice(2, 6);
+        let (offset, coefficients) = probability_distribution.coefficients();
+
+        assert_eq!(offset, 2);
+        assert_eq!(coefficients, vec![1, 2, 3, 4, 5, 6, 5, 4, 3, 2, 1]);
+        assert_eq!(
+            coefficients,
+            coefficients.iter().rev().copied().collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn test_coefficients_gaps() {
+        let probability_distribution =
+            ProbabilityDistribution::new_from_many_probability_outcomes(vec![
+                ProbabilityOutcome::new_with_empty_constraint_map(-1),
+                ProbabilityOutcome::new_with_empty_constraint_map(3),
+                ProbabilityOutcome::new_with_empty_constraint_map(3),
+            ]);
+        assert_eq!(
+            probability_distribution.coefficients(),
+            (-1, vec![1, 0, 0, 0, 2])
+        );
+    }
+
+    #[test]
+    fn test_coefficients_merges_constraints() {
+        let probability_distribution =
+            ProbabilityDistribution::new_from_many_probability_outcomes(vec![
+                ProbabilityOutcome::new_with_empty_constraint_map(1),
+                ProbabilityOutcome::new_with_constraints(
+                    1,
+                    vec![Constraint::new_single_valid_value_constraint(1, 1)],
+                ),
+                ProbabilityOutcome::new_with_constraints(
+                    2,
+                    vec![Constraint::new_single_valid_value_constraint(1, 2)],
+                ),
+            ]);
+        assert_eq!(probability_distribution.coefficients(), (1, vec![2, 1]));
+    }
+
+    #[test]
+    fn test_from_coefficients_empty() {
+        let probability_distribution = ProbabilityDistribution::from_coefficients(10, &[]);
+        assert_eq!(probability_distribution.outcome_counts.len(), 0);
+    }
+
+    #[test]
+    fn test_from_coefficients_all_zero() {
+        let probability_distribution = ProbabilityDistribution::from_coefficients(10, &[0, 0]);
+        assert_eq!(probability_distribution.outcome_counts.len(), 0);
+    }
+
+    #[test]
+    fn test_from_coefficients_skips_zeros() {
+        let probability_distribution = ProbabilityDistribution::from_coefficients(3, &[0, 4, 0, 5]);
+
+        assert_eq!(
+            probability_distribution
+                .outcome_counts
+                .get(&ProbabilityOutcome::new_with_empty_constraint_map(4)),
+            Some(&4)
+        );
+        assert_eq!(
+            probability_distribution
+                .outcome_counts
+                .get(&ProbabilityOutcome::new_with_empty_constraint_map(6)),
+            Some(&5)
+        );
+        assert_eq!(probability_distribution.outcome_counts.len(), 2);
+    }
+
+    #[test]
+    fn test_coefficients_round_trip() {
+        for probability_distribution in [
+            ProbabilityDistribution::new_multiple_dice(3, 6),
+            ProbabilityDistribution::new_multiple_dice(2, -4),
+            ProbabilityDistribution::new_dice(6) * 3 - 10,
+        ] {
+            let (offset, coefficients) = probability_distribution.coefficients();
+            assert_eq!(
+                ProbabilityDistribution::from_coefficients(offset, &coefficients).outcome_counts,
+                probability_distribution.outcome_counts
+            );
+        }
+    }
+}
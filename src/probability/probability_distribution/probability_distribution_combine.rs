@@ -13,6 +13,8 @@ impl Combine for ProbabilityDistribution {
     ///
     /// values are combined using the [BinaryOperation] function.
     /// constraint maps are combined using the ConstraintMap::add function.
+    /// counts are not checked for overflow,
+    /// see [ProbabilityDistribution::combine_checked_counts] for a checked version.
     ///
     /// # Arguments
     ///
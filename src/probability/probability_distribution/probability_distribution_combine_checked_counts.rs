@@ -0,0 +1,182 @@
+use std::collections::BTreeMap;
+use std::error::Error;
+use std::fmt;
+
+use crate::constraint_management::IsTheoreticallyPossible;
+use crate::probability::{BinaryOperation, Combine, ProbabilityDistribution, ProbabilityOutcome};
+use crate::CountType;
+
+use super::checked_add_outcome_to_map;
+
+/// The error returned by [ProbabilityDistribution::combine_checked_counts]
+/// when a count does not fit in [CountType].
+///
+/// # Example
+///
+/// ```
+/// # use crate::rusted_dice::probability::CountOverflow;
+/// assert_eq!(CountOverflow.to_string(), "Outcome counts overflowed CountType.");
+/// ```
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct CountOverflow;
+
+impl fmt::Display for CountOverflow {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(formatter, "Outcome counts overflowed CountType.")
+    }
+}
+
+impl Error for CountOverflow {}
+
+impl ProbabilityDistribution {
+    /// Combine this instance with another instance using the specified [BinaryOperation],
+    /// checking every count multiplication and addition for overflow.
+    /// in the order: self [BinaryOperation] `other`
+    ///
+    /// behaves like [Combine::combine], which does not check its counts,
+    /// useful for large dice pools where the number of ways to roll an outcome grows quickly.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The [ProbabilityDistribution] to combine with.
+    /// * `binary_operation` - the [BinaryOperation] function.
+    ///
+    /// # Returns
+    ///
+    /// The new [ProbabilityDistribution], or [CountOverflow] if any count does not fit in [CountType].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use std::collections::BTreeMap;
+    /// # use crate::rusted_dice::probability::{CountOverflow, FromCountMap, ProbabilityDistribution};
+    /// let d10 = ProbabilityDistribution::new_dice(10);
+    /// let mut ten_d10 = d10.clone();
+    /// for _ in 1..10 {
+    ///     ten_d10 = ten_d10.combine_checked_counts(&d10, |a, b| a + b).unwrap();
+    /// }
+    /// assert_eq!(ten_d10.total_outcome_count(), 10_000_000_000);
+    ///
+    /// let huge = ProbabilityDistribution::from_value_count_map(BTreeMap::from([(1, u64::MAX)]));
+    /// assert_eq!(huge.combine_checked_counts(&huge, |a, b| a + b).unwrap_err(), CountOverflow);
+    /// ```
+    pub fn combine_checked_counts(
+        &self,
+        other: &Self,
+        binary_operation: BinaryOperation,
+    ) -> Result<Self, CountOverflow> {
+        let mut new_outcome_counts: BTreeMap<ProbabilityOutcome, CountType> = BTreeMap::new();
+
+        for (value_one, count_one) in self.outcome_counts.iter() {
+            for (value_two, count_two) in other.outcome_counts.iter() {
+                let new_value = value_one.combine(value_two.clone(), binary_operation);
+                if new_value.constraint_map.is_theoretically_possible() {
+                    let new_count = count_one.checked_mul(*count_two).ok_or(CountOverflow)?;
+                    checked_add_outcome_to_map(&mut new_outcome_counts, new_value, new_count)
+                        .ok_or(CountOverflow)?;
+                }
+            }
+        }
+        Ok(ProbabilityDistribution {
+            outcome_counts: new_outcome_counts,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use crate::constraint_management::Constraint;
+    use crate::probability::{
+        Combine, CountOverflow, FromCountMap, ProbabilityDistribution, ProbabilityOutcome, ToTable,
+    };
+    use crate::CountType;
+
+    #[test]
+    fn test_combine_checked_counts_matches_combine() {
+        let dice_one = ProbabilityDistribution::new_dice(6).add_self_value_constraint(1);
+        let dice_two = ProbabilityDistribution::new_dice(4)
+            + Constraint::new_many_item_constraint(1, vec![1, 2, 3]);
+
+        assert_eq!(
+            dice_one
+                .combine_checked_counts(&dice_two, |a, b| a * b)
+                .unwrap()
+                .to_table(),
+            dice_one.combine(dice_two, |a, b| a * b).to_table()
+        );
+    }
+
+    #[test]
+    fn test_combine_checked_counts_empty() {
+        let dice = ProbabilityDistribution::new_dice(6);
+        let empty = ProbabilityDistribution::new_empty_distribution();
+        assert!(dice
+            .combine_checked_counts(&empty, |_, _| panic!("This should not be called"))
+            .unwrap()
+            .outcome_counts
+            .is_empty());
+    }
+
+    #[test]
+    fn test_ten_d10_exceeds_u32() {
+        let ten_d10 = ProbabilityDistribution::new_multiple_dice(10, 10);
+        let total_outcome_count = ten_d10.total_outcome_count();
+
+        assert_eq!(total_outcome_count, 10_000_000_000);
+        assert!(total_outcome_count > CountType::from(u32::MAX));
+        assert_eq!(
+            ten_d10
+                .outcome_counts
+                .get(&ProbabilityOutcome::new_with_empty_constraint_map(55)),
+            Some(&432_457_640)
+        );
+    }
+
+    #[test]
+    fn test_combine_checked_counts_ten_d10() {
+        let d10 = ProbabilityDistribution::new_dice(10);
+        let mut ten_d10 = d10.clone();
+        for _ in 1..10 {
+            ten_d10 = ten_d10.combine_checked_counts(&d10, |a, b| a + b).unwrap();
+        }
+        assert_eq!(
+            ten_d10.to_table(),
+            ProbabilityDistribution::new_multiple_dice(10, 10).to_table()
+        );
+    }
+
+    #[test]
+    fn test_combine_checked_counts_multiplication_overflow() {
+        let large = ProbabilityDistribution::from_value_count_map(BTreeMap::from([(
+            0,
+            CountType::MAX / 2 + 1,
+        )]));
+        let doubled = ProbabilityDistribution::from_value_count_map(BTreeMap::from([(0, 2)]));
+        assert_eq!(
+            large
+                .combine_checked_counts(&doubled, |a, b| a + b)
+                .unwrap_err(),
+            CountOverflow
+        );
+    }
+
+    #[test]
+    fn test_combine_checked_counts_addition_overflow() {
+        let large = ProbabilityDistribution::from_value_count_map(BTreeMap::from([
+            (0, 1 << 32),
+            (1, 1 << 32),
+        ]));
+        let also_large = ProbabilityDistribution::from_value_count_map(BTreeMap::from([
+            (0, 1 << 31),
+            (1, 1 << 31),
+        ]));
+        assert_eq!(
+            large
+                .combine_checked_counts(&also_large, |a, b| a + b)
+                .unwrap_err(),
+            CountOverflow
+        );
+    }
+}
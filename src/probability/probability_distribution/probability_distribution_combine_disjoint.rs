@@ -0,0 +1,188 @@
+use std::error::Error;
+use std::fmt;
+
+use crate::constraint_management::ConstraintIdType;
+use crate::probability::{BinaryOperation, Combine, ProbabilityDistribution};
+
+/// The error returned by [ProbabilityDistribution::combine_disjoint]
+/// when both [ProbabilityDistribution]s track values under the same constraint ids.
+///
+/// holds every shared constraint id, sorted ascending.
+///
+/// # Example
+///
+/// ```
+/// # use crate::rusted_dice::probability::ConstraintIdConflict;
+/// let constraint_id_conflict = ConstraintIdConflict { ids: vec![1, 3] };
+/// assert_eq!(
+///     constraint_id_conflict.to_string(),
+///     "Constraint ids are used by both distributions: 1, 3."
+/// );
+/// ```
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct ConstraintIdConflict {
+    pub ids: Vec<ConstraintIdType>,
+}
+
+impl fmt::Display for ConstraintIdConflict {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let ids: Vec<String> = self.ids.iter().map(|id| id.to_string()).collect();
+        write!(
+            formatter,
+            "Constraint ids are used by both distributions: {}.",
+            ids.join(", ")
+        )
+    }
+}
+
+impl Error for ConstraintIdConflict {}
+
+impl ProbabilityDistribution {
+    /// Combine this instance with another independent instance using the specified [BinaryOperation].
+    /// in the order: self [BinaryOperation] `other`
+    ///
+    /// the same as [Combine::combine], but refuses to combine two [ProbabilityDistribution]s
+    /// which share any constraint id, as intersecting those constraints would wrongly narrow
+    /// dice that are independent. remap the ids of one of them first.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The [ProbabilityDistribution] to perform the [BinaryOperation] with.
+    /// * `binary_operation` - the [BinaryOperation] function.
+    ///
+    /// # Returns
+    ///
+    /// The combined [ProbabilityDistribution],
+    /// or a [ConstraintIdConflict] listing the shared constraint ids.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use crate::rusted_dice::probability::{ConstraintIdConflict, ProbabilityDistribution};
+    /// # use crate::rusted_dice::probability::ToTable;
+    /// let dice_one = ProbabilityDistribution::new_dice(6).add_self_value_constraint(1);
+    /// let dice_two = ProbabilityDistribution::new_dice(6).add_self_value_constraint(2);
+    ///
+    /// let combined = dice_one.combine_disjoint(dice_two.clone(), |a, b| a + b).unwrap();
+    /// assert_eq!(combined.total_outcome_count(), 36);
+    ///
+    /// assert_eq!(
+    ///     combined.combine_disjoint(dice_two, |a, b| a + b).unwrap_err(),
+    ///     ConstraintIdConflict { ids: vec![2] }
+    /// );
+    /// ```
+    pub fn combine_disjoint(
+        &self,
+        other: Self,
+        binary_operation: BinaryOperation,
+    ) -> Result<Self, ConstraintIdConflict> {
+        let ids: Vec<ConstraintIdType> = self
+            .constraint_ids()
+            .intersection(&other.constraint_ids())
+            .copied()
+            .collect();
+        if !ids.is_empty() {
+            return Err(ConstraintIdConflict { ids });
+        }
+        Ok(self.combine(other, binary_operation))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::constraint_management::Constraint;
+    use crate::probability::{
+        Combine, ConstraintIdConflict, ProbabilityDistribution, ProbabilityOutcome, ToTable,
+    };
+    use crate::ValueType;
+
+    fn _add(lhs: ValueType, rhs: ValueType) -> ValueType {
+        lhs + rhs
+    }
+
+    #[test]
+    fn test_combine_disjoint_untracked() {
+        let dice_one = ProbabilityDistribution::new_dice(4);
+        let dice_two = ProbabilityDistribution::new_dice(6);
+
+        assert_eq!(
+            dice_one
+                .combine_disjoint(dice_two.clone(), _add)
+                .unwrap()
+                .to_table(),
+            (dice_one + dice_two).to_table()
+        );
+    }
+
+    #[test]
+    fn test_combine_disjoint_disjoint_ids() {
+        let dice_one = ProbabilityDistribution::new_dice(4).add_self_value_constraint(1);
+        let dice_two = ProbabilityDistribution::new_dice(4).add_self_value_constraint(2);
+
+        assert_eq!(
+            dice_one
+                .combine_disjoint(dice_two.clone(), _add)
+                .unwrap()
+                .to_table(),
+            dice_one.combine(dice_two, _add).to_table()
+        );
+    }
+
+    #[test]
+    fn test_combine_disjoint_shared_ids() {
+        let dice_one = (ProbabilityDistribution::new_dice(4).add_self_value_constraint(3)
+            + ProbabilityDistribution::new_dice(4).add_self_value_constraint(1))
+        .add_self_value_constraint(5);
+        let dice_two = ProbabilityDistribution::new_dice(4).add_self_value_constraint(5)
+            + ProbabilityDistribution::new_dice(4).add_self_value_constraint(2)
+            + ProbabilityDistribution::new_dice(4).add_self_value_constraint(3);
+
+        assert_eq!(
+            dice_one.combine_disjoint(dice_two, _add).unwrap_err(),
+            ConstraintIdConflict { ids: vec![3, 5] }
+        );
+    }
+
+    #[test]
+    fn test_combine_disjoint_shared_id_on_some_outcomes() {
+        let probability_distribution =
+            ProbabilityDistribution::new_from_many_probability_outcomes(vec![
+                ProbabilityOutcome::new_with_empty_constraint_map(1),
+                ProbabilityOutcome::new_with_constraints(
+                    2,
+                    vec![Constraint::new_single_valid_value_constraint(7, 2)],
+                ),
+            ]);
+        let dice = ProbabilityDistribution::new_dice(2).add_self_value_constraint(7);
+
+        assert_eq!(
+            probability_distribution
+                .combine_disjoint(dice, _add)
+                .unwrap_err(),
+            ConstraintIdConflict { ids: vec![7] }
+        );
+    }
+
+    #[test]
+    fn test_combine_disjoint_empty() {
+        let empty = ProbabilityDistribution::new_empty_distribution();
+        let dice = ProbabilityDistribution::new_dice(2).add_self_value_constraint(1);
+
+        assert_eq!(
+            empty
+                .combine_disjoint(dice, _add)
+                .unwrap()
+                .outcome_counts
+                .len(),
+            0
+        );
+    }
+
+    #[test]
+    fn test_constraint_id_conflict_display() {
+        assert_eq!(
+            ConstraintIdConflict { ids: vec![4] }.to_string(),
+            "Constraint ids are used by both distributions: 4."
+        );
+    }
+}
@@ -0,0 +1,288 @@
+use std::collections::{BTreeMap, BTreeSet};
+use std::error::Error;
+use std::fmt;
+
+use crate::constraint_management::IsTheoreticallyPossible;
+use crate::probability::{ProbabilityDistribution, ProbabilityOutcome};
+use crate::{CountType, ValueType};
+
+use super::add_outcome_to_map;
+
+/// The error returned by [ProbabilityDistribution::combine_map_checked]
+/// when the combining function fails for some pairs of values.
+///
+/// holds every distinct pair of values the combining function failed on, sorted ascending.
+///
+/// # Example
+///
+/// ```
+/// # use crate::rusted_dice::probability::CombineOverflow;
+/// let combine_overflow = CombineOverflow {
+///     pairs: vec![(1, 2), (3, 4)],
+/// };
+/// assert_eq!(
+///     combine_overflow.to_string(),
+///     "Combining values overflowed for 2 pairs: (1, 2), (3, 4)."
+/// );
+/// ```
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct CombineOverflow {
+    pub pairs: Vec<(ValueType, ValueType)>,
+}
+
+impl fmt::Display for CombineOverflow {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let pairs: Vec<String> = self
+            .pairs
+            .iter()
+            .map(|(value_one, value_two)| format!("({}, {})", value_one, value_two))
+            .collect();
+        write!(
+            formatter,
+            "Combining values overflowed for {} pairs: {}.",
+            self.pairs.len(),
+            pairs.join(", ")
+        )
+    }
+}
+
+impl Error for CombineOverflow {}
+
+impl ProbabilityDistribution {
+    /// Combine this instance with another instance using any function of two values.
+    /// in the order: `f(self, other)`
+    ///
+    /// unlike [Combine::combine](crate::probability::Combine::combine), `f` can be a closure
+    /// which captures its environment.
+    /// constraint maps are combined using the ConstraintMap::add function,
+    /// outcomes whose constraint maps are not possible are dropped.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The [ProbabilityDistribution] to combine with.
+    /// * `f` - The function used to combine the values.
+    ///
+    /// # Returns
+    ///
+    /// The new [ProbabilityDistribution].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use crate::rusted_dice::probability::ProbabilityDistribution;
+    /// # use crate::rusted_dice::probability::ToTable;
+    /// let dice = ProbabilityDistribution::new_dice(2);
+    /// let bonus = 10;
+    ///
+    /// let combined = dice.combine_map(&dice, |value_one, value_two| {
+    ///     value_one * value_one - value_two * value_two + bonus
+    /// });
+    ///
+    /// assert_eq!(
+    ///     combined
+    ///         .to_table()
+    ///         .to_string()
+    ///         .replace("\r\n", "\n"),
+    ///     "\
+    ///     +-------+-------+\n\
+    ///     | value | count |\n\
+    ///     +=======+=======+\n\
+    ///     | 7     | 1     |\n\
+    ///     +-------+-------+\n\
+    ///     | 10    | 2     |\n\
+    ///     +-------+-------+\n\
+    ///     | 13    | 1     |\n\
+    ///     +-------+-------+\n\
+    ///     ");
+    /// ```
+    pub fn combine_map<F: Fn(ValueType, ValueType) -> ValueType>(
+        &self,
+        other: &Self,
+        f: F,
+    ) -> Self {
+        match self.combine_map_checked(other, |value_one, value_two| Some(f(value_one, value_two)))
+        {
+            Ok(probability_distribution) => probability_distribution,
+            Err(_) => unreachable!("combining with an infallible function can not fail"),
+        }
+    }
+
+    /// Combine this instance with another instance using a fallible function of two values.
+    /// in the order: `f(self, other)`
+    ///
+    /// `f` returns `None` when combining two values overflows, for example [i32::checked_mul].
+    /// every failing pair is collected instead of panicking part way through.
+    /// constraint maps are combined using the ConstraintMap::add function,
+    /// outcomes whose constraint maps are not possible are dropped and `f` is not called for them.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The [ProbabilityDistribution] to combine with.
+    /// * `f` - The function used to combine the values.
+    ///
+    /// # Returns
+    ///
+    /// The new [ProbabilityDistribution],
+    /// or a [CombineOverflow] error holding every pair of values `f` returned `None` for.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use crate::rusted_dice::probability::{CombineOverflow, ProbabilityDistribution};
+    /// let dice = ProbabilityDistribution::new_dice(3);
+    /// let large = ProbabilityDistribution::from(i32::MAX / 2);
+    ///
+    /// assert!(dice.combine_map_checked(&dice, i32::checked_mul).is_ok());
+    /// assert_eq!(
+    ///     dice.combine_map_checked(&large, i32::checked_mul).unwrap_err(),
+    ///     CombineOverflow {
+    ///         pairs: vec![(3, i32::MAX / 2)]
+    ///     }
+    /// );
+    /// ```
+    pub fn combine_map_checked<F: Fn(ValueType, ValueType) -> Option<ValueType>>(
+        &self,
+        other: &Self,
+        f: F,
+    ) -> Result<Self, CombineOverflow> {
+        let mut new_outcome_counts: BTreeMap<ProbabilityOutcome, CountType> = BTreeMap::new();
+        let mut overflowing_pairs: BTreeSet<(ValueType, ValueType)> = BTreeSet::new();
+
+        for (outcome_one, count_one) in self.outcome_counts.iter() {
+            for (outcome_two, count_two) in other.outcome_counts.iter() {
+                let constraint_map =
+                    outcome_one.constraint_map.clone() + outcome_two.constraint_map.clone();
+                if !constraint_map.is_theoretically_possible() {
+                    continue;
+                }
+                match f(outcome_one.value, outcome_two.value) {
+                    Some(value) => add_outcome_to_map(
+                        &mut new_outcome_counts,
+                        ProbabilityOutcome {
+                            value,
+                            constraint_map,
+                        },
+                        count_one * count_two,
+                    ),
+                    None => {
+                        overflowing_pairs.insert((outcome_one.value, outcome_two.value));
+                    }
+                }
+            }
+        }
+
+        if overflowing_pairs.is_empty() {
+            Ok(ProbabilityDistribution {
+                outcome_counts: new_outcome_counts,
+            })
+        } else {
+            Err(CombineOverflow {
+                pairs: overflowing_pairs.into_iter().collect(),
+            })
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::CombineOverflow;
+    use crate::probability::{ProbabilityDistribution, ToTable};
+    use crate::ValueType;
+
+    #[test]
+    fn test_combine_map_empty() {
+        let empty = ProbabilityDistribution::new_empty_distribution();
+        let dice = ProbabilityDistribution::new_dice(6);
+
+        assert_eq!(
+            empty
+                .combine_map(&dice, |_, _| panic!("This should not be called"))
+                .outcome_counts
+                .len(),
+            0
+        );
+        assert_eq!(
+            dice.combine_map(&empty, |_, _| panic!("This should not be called"))
+                .outcome_counts
+                .len(),
+            0
+        );
+    }
+
+    #[test]
+    fn test_combine_map_matches_operator() {
+        let dice_one = ProbabilityDistribution::new_dice(6).add_self_value_constraint(1);
+        let dice_two = ProbabilityDistribution::new_dice(4).add_self_value_constraint(2);
+
+        assert_eq!(
+            dice_one
+                .combine_map(&dice_two, |value_one, value_two| value_one + value_two)
+                .to_table(),
+            (dice_one.clone() + dice_two.clone()).to_table()
+        );
+    }
+
+    #[test]
+    fn test_combine_map_capturing_closure() {
+        let dice = ProbabilityDistribution::new_dice(6);
+        let cap: ValueType = 3;
+
+        let combined = dice.combine_map(&dice, |value_one, value_two| {
+            (value_one - value_two).abs().min(cap)
+        });
+
+        assert_eq!(
+            combined.to_table(),
+            ProbabilityDistribution::from_coefficients(0, &[6, 10, 8, 12]).to_table()
+        );
+    }
+
+    #[test]
+    fn test_combine_map_drops_impossible_constraints() {
+        let dice = ProbabilityDistribution::new_dice(3).add_self_value_constraint(1);
+        let combined = dice.combine_map(&dice, |value_one, value_two| value_one * value_two);
+
+        assert_eq!(combined.total_outcome_count(), 3);
+    }
+
+    #[test]
+    fn test_combine_map_checked_ok() {
+        let dice = ProbabilityDistribution::new_dice(6);
+        assert_eq!(
+            dice.combine_map_checked(&dice, ValueType::checked_add)
+                .unwrap()
+                .to_table(),
+            ProbabilityDistribution::new_multiple_dice(2, 6).to_table()
+        );
+    }
+
+    #[test]
+    fn test_combine_map_checked_overflow() {
+        let large = ProbabilityDistribution::from_coefficients(ValueType::MAX - 2, &[1, 1, 1]);
+        let dice = ProbabilityDistribution::new_dice(2);
+
+        assert_eq!(
+            large
+                .combine_map_checked(&dice, ValueType::checked_add)
+                .unwrap_err(),
+            CombineOverflow {
+                pairs: vec![
+                    (ValueType::MAX - 1, 2),
+                    (ValueType::MAX, 1),
+                    (ValueType::MAX, 2)
+                ]
+            }
+        );
+    }
+
+    #[test]
+    fn test_combine_overflow_display() {
+        assert_eq!(
+            CombineOverflow {
+                pairs: vec![(ValueType::MAX, 1)]
+            }
+            .to_string(),
+            "Combining values overflowed for 1 pairs: (2147483647, 1)."
+        );
+    }
+}
@@ -0,0 +1,152 @@
+use std::collections::BTreeMap;
+
+use crate::constraint_management::IsTheoreticallyPossible;
+use crate::probability::{ProbabilityDistribution, ProbabilityOutcome};
+use crate::CountType;
+
+use super::add_outcome_to_map;
+
+impl ProbabilityDistribution {
+    /// Combine this instance with another instance using a saturating operation on [ProbabilityOutcome]s.
+    /// in the order: `saturating_operation(self, other)`
+    ///
+    /// an alternative to [Combine::combine](crate::probability::Combine::combine) which never overflows,
+    /// for example with [ProbabilityOutcome::saturating_add].
+    /// outcomes that differ before saturating can be the same after, their counts are added together,
+    /// so every result clamped to [i32::MAX] is counted as one outcome.
+    /// outcomes whose constraint maps are not possible are dropped.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The [ProbabilityDistribution] to combine with.
+    /// * `saturating_operation` - The function used to combine the outcomes.
+    ///
+    /// # Returns
+    ///
+    /// The new [ProbabilityDistribution].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use crate::rusted_dice::probability::{ProbabilityDistribution, ProbabilityOutcome};
+    /// # use crate::rusted_dice::probability::ToTable;
+    /// let dice = ProbabilityDistribution::new_dice(3);
+    /// let large = ProbabilityDistribution::from(i32::MAX - 1);
+    ///
+    /// assert_eq!(
+    ///     large
+    ///         .combine_saturating(&dice, ProbabilityOutcome::saturating_add)
+    ///         .to_table()
+    ///         .to_string()
+    ///         .replace("\r\n", "\n"),
+    ///     "\
+    ///     +------------+-------+\n\
+    ///     | value      | count |\n\
+    ///     +============+=======+\n\
+    ///     | 2147483647 | 3     |\n\
+    ///     +------------+-------+\n\
+    ///     ");
+    /// ```
+    pub fn combine_saturating(
+        &self,
+        other: &Self,
+        saturating_operation: fn(&ProbabilityOutcome, &ProbabilityOutcome) -> ProbabilityOutcome,
+    ) -> Self {
+        let mut new_outcome_counts: BTreeMap<ProbabilityOutcome, CountType> = BTreeMap::new();
+
+        for (outcome_one, count_one) in self.outcome_counts.iter() {
+            for (outcome_two, count_two) in other.outcome_counts.iter() {
+                let new_outcome = saturating_operation(outcome_one, outcome_two);
+                if new_outcome.constraint_map.is_theoretically_possible() {
+                    add_outcome_to_map(&mut new_outcome_counts, new_outcome, count_one * count_two);
+                }
+            }
+        }
+
+        ProbabilityDistribution {
+            outcome_counts: new_outcome_counts,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::probability::{ProbabilityDistribution, ProbabilityOutcome, ToTable};
+    use crate::ValueType;
+
+    #[test]
+    fn test_combine_saturating_empty() {
+        let dice = ProbabilityDistribution::new_dice(6);
+        let empty = ProbabilityDistribution::new_empty_distribution();
+        assert_eq!(
+            empty
+                .combine_saturating(&dice, ProbabilityOutcome::saturating_add)
+                .outcome_counts
+                .len(),
+            0
+        );
+    }
+
+    #[test]
+    fn test_combine_saturating_matches_operators() {
+        let dice_one = ProbabilityDistribution::new_dice(4).add_self_value_constraint(1);
+        let dice_two = ProbabilityDistribution::new_dice(3) - 2;
+
+        assert_eq!(
+            dice_one
+                .combine_saturating(&dice_two, ProbabilityOutcome::saturating_add)
+                .to_table(),
+            (dice_one.clone() + dice_two.clone()).to_table()
+        );
+        assert_eq!(
+            dice_one
+                .combine_saturating(&dice_two, ProbabilityOutcome::saturating_sub)
+                .to_table(),
+            (dice_one.clone() - dice_two.clone()).to_table()
+        );
+        assert_eq!(
+            dice_one
+                .combine_saturating(&dice_two, ProbabilityOutcome::saturating_mul)
+                .to_table(),
+            (dice_one * dice_two).to_table()
+        );
+    }
+
+    #[test]
+    fn test_combine_saturating_max_merges_outcomes() {
+        let dice = ProbabilityDistribution::new_dice(4);
+        let near_max = ProbabilityDistribution::from(ValueType::MAX - 2);
+
+        let combined = near_max.combine_saturating(&dice, ProbabilityOutcome::saturating_add);
+
+        assert_eq!(combined.coefficients(), (ValueType::MAX - 1, vec![1, 3]));
+        assert_eq!(combined.total_outcome_count(), 4);
+    }
+
+    #[test]
+    fn test_combine_saturating_min() {
+        let dice = ProbabilityDistribution::new_dice(3);
+        let min = ProbabilityDistribution::from(ValueType::MIN);
+
+        assert_eq!(
+            min.combine_saturating(&dice, ProbabilityOutcome::saturating_sub)
+                .coefficients(),
+            (ValueType::MIN, vec![3])
+        );
+        assert_eq!(
+            min.combine_saturating(&dice, ProbabilityOutcome::saturating_mul)
+                .coefficients(),
+            (ValueType::MIN, vec![3])
+        );
+    }
+
+    #[test]
+    fn test_combine_saturating_drops_impossible_constraints() {
+        let dice = ProbabilityDistribution::new_dice(3).add_self_value_constraint(1);
+        assert_eq!(
+            dice.combine_saturating(&dice, ProbabilityOutcome::saturating_add)
+                .to_table(),
+            (dice.clone() + dice).to_table()
+        );
+    }
+}
@@ -0,0 +1,264 @@
+use crate::probability::ProbabilityDistribution;
+use crate::ValueType;
+
+impl ProbabilityDistribution {
+    /// Compares this instance with another instance, giving a [ProbabilityDistribution] over 0 and 1.
+    /// each pair of values gives 1 if the value of `self` is greater than the value of `other`, otherwise 0.
+    /// counts are multiplied as with [Combine::combine](crate::probability::Combine::combine),
+    /// constraint maps are combined using the ConstraintMap::add function,
+    /// outcomes whose constraint maps are not possible are dropped.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The [ProbabilityDistribution] to compare with.
+    ///
+    /// # Returns
+    ///
+    /// The new [ProbabilityDistribution].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use crate::rusted_dice::probability::ProbabilityDistribution;
+    /// # use crate::rusted_dice::probability::ToTable;
+    /// let dice = ProbabilityDistribution::new_dice(2);
+    ///
+    /// assert_eq!(
+    ///     dice.greater_than(&dice)
+    ///         .to_table()
+    ///         .to_string()
+    ///         .replace("\r\n", "\n"),
+    ///     "\
+    ///     +-------+-------+\n\
+    ///     | value | count |\n\
+    ///     +=======+=======+\n\
+    ///     | 0     | 3     |\n\
+    ///     +-------+-------+\n\
+    ///     | 1     | 1     |\n\
+    ///     +-------+-------+\n\
+    ///     ");
+    /// ```
+    pub fn greater_than(&self, other: &Self) -> Self {
+        self.combine_map(other, |value_one, value_two| {
+            ValueType::from(value_one > value_two)
+        })
+    }
+
+    /// Compares this instance with another instance, giving a [ProbabilityDistribution] over 0 and 1.
+    /// each pair of values gives 1 if the value of `self` is less than the value of `other`, otherwise 0.
+    /// counts are multiplied as with [Combine::combine](crate::probability::Combine::combine),
+    /// constraint maps are combined using the ConstraintMap::add function,
+    /// outcomes whose constraint maps are not possible are dropped.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The [ProbabilityDistribution] to compare with.
+    ///
+    /// # Returns
+    ///
+    /// The new [ProbabilityDistribution].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use crate::rusted_dice::probability::ProbabilityDistribution;
+    /// # use crate::rusted_dice::probability::ToTable;
+    /// let dice = ProbabilityDistribution::new_dice(2);
+    ///
+    /// assert_eq!(
+    ///     dice.less_than(&dice)
+    ///         .to_table()
+    ///         .to_string()
+    ///         .replace("\r\n", "\n"),
+    ///     "\
+    ///     +-------+-------+\n\
+    ///     | value | count |\n\
+    ///     +=======+=======+\n\
+    ///     | 0     | 3     |\n\
+    ///     +-------+-------+\n\
+    ///     | 1     | 1     |\n\
+    ///     +-------+-------+\n\
+    ///     ");
+    /// ```
+    pub fn less_than(&self, other: &Self) -> Self {
+        self.combine_map(other, |value_one, value_two| {
+            ValueType::from(value_one < value_two)
+        })
+    }
+
+    /// Compares this instance with another instance, giving a [ProbabilityDistribution] over 0 and 1.
+    /// each pair of values gives 1 if the value of `self` is equal to the value of `other`, otherwise 0.
+    /// counts are multiplied as with [Combine::combine](crate::probability::Combine::combine),
+    /// constraint maps are combined using the ConstraintMap::add function,
+    /// outcomes whose constraint maps are not possible are dropped.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The [ProbabilityDistribution] to compare with.
+    ///
+    /// # Returns
+    ///
+    /// The new [ProbabilityDistribution].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use crate::rusted_dice::probability::ProbabilityDistribution;
+    /// # use crate::rusted_dice::probability::ToTable;
+    /// let dice = ProbabilityDistribution::new_dice(2);
+    ///
+    /// assert_eq!(
+    ///     dice.equal_to(&dice)
+    ///         .to_table()
+    ///         .to_string()
+    ///         .replace("\r\n", "\n"),
+    ///     "\
+    ///     +-------+-------+\n\
+    ///     | value | count |\n\
+    ///     +=======+=======+\n\
+    ///     | 0     | 2     |\n\
+    ///     +-------+-------+\n\
+    ///     | 1     | 2     |\n\
+    ///     +-------+-------+\n\
+    ///     ");
+    /// ```
+    pub fn equal_to(&self, other: &Self) -> Self {
+        self.combine_map(other, |value_one, value_two| {
+            ValueType::from(value_one == value_two)
+        })
+    }
+
+    /// Compares this instance with another instance, giving a [ProbabilityDistribution] over 0 and 1.
+    /// each pair of values gives 1 if the value of `self` is greater than or equal to the value of `other`, otherwise 0.
+    /// counts are multiplied as with [Combine::combine](crate::probability::Combine::combine),
+    /// constraint maps are combined using the ConstraintMap::add function,
+    /// outcomes whose constraint maps are not possible are dropped.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The [ProbabilityDistribution] to compare with.
+    ///
+    /// # Returns
+    ///
+    /// The new [ProbabilityDistribution].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use crate::rusted_dice::probability::ProbabilityDistribution;
+    /// # use crate::rusted_dice::probability::ToTable;
+    /// let dice = ProbabilityDistribution::new_dice(2);
+    ///
+    /// assert_eq!(
+    ///     dice.greater_or_equal(&dice)
+    ///         .to_table()
+    ///         .to_string()
+    ///         .replace("\r\n", "\n"),
+    ///     "\
+    ///     +-------+-------+\n\
+    ///     | value | count |\n\
+    ///     +=======+=======+\n\
+    ///     | 0     | 1     |\n\
+    ///     +-------+-------+\n\
+    ///     | 1     | 3     |\n\
+    ///     +-------+-------+\n\
+    ///     ");
+    /// ```
+    pub fn greater_or_equal(&self, other: &Self) -> Self {
+        self.combine_map(other, |value_one, value_two| {
+            ValueType::from(value_one >= value_two)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::constraint_management::Constraint;
+    use crate::probability::{ProbabilityDistribution, ProbabilityOutcome};
+    use crate::{CountType, ValueType};
+
+    fn count_of(probability_distribution: &ProbabilityDistribution, value: ValueType) -> CountType {
+        *probability_distribution
+            .outcome_counts
+            .get(&ProbabilityOutcome::new_with_empty_constraint_map(value))
+            .unwrap_or(&0)
+    }
+
+    #[test]
+    fn test_comparison_empty() {
+        let empty = ProbabilityDistribution::new_empty_distribution();
+        let dice = ProbabilityDistribution::new_dice(6);
+        for probability_distribution in [
+            empty.greater_than(&dice),
+            dice.less_than(&empty),
+            empty.equal_to(&empty),
+            dice.greater_or_equal(&empty),
+        ] {
+            assert_eq!(probability_distribution.outcome_counts.len(), 0);
+        }
+    }
+
+    #[test]
+    fn test_greater_than_d2() {
+        let dice = ProbabilityDistribution::new_dice(2);
+        let probability_distribution = dice.greater_than(&dice);
+
+        assert_eq!(probability_distribution.outcome_counts.len(), 2);
+        assert_eq!(count_of(&probability_distribution, 0), 3);
+        assert_eq!(count_of(&probability_distribution, 1), 1);
+    }
+
+    #[test]
+    fn test_greater_than_d20() {
+        let dice = ProbabilityDistribution::new_dice(20);
+        let probability_distribution = dice.greater_than(&dice);
+
+        assert_eq!(count_of(&probability_distribution, 1), 190);
+        assert_eq!(count_of(&probability_distribution, 0), 210);
+    }
+
+    #[test]
+    fn test_comparisons_partition_pairs() {
+        let one = ProbabilityDistribution::new_multiple_dice(2, 6);
+        let two = ProbabilityDistribution::new_dice(12);
+
+        let greater = count_of(&one.greater_than(&two), 1);
+        let less = count_of(&one.less_than(&two), 1);
+        let equal = count_of(&one.equal_to(&two), 1);
+
+        assert_eq!(greater + less + equal, 36 * 12);
+        assert_eq!(count_of(&one.greater_or_equal(&two), 1), greater + equal);
+        assert_eq!(count_of(&two.greater_than(&one), 1), less);
+    }
+
+    #[test]
+    fn test_equal_to_matches_probability_tie() {
+        let one = ProbabilityDistribution::new_multiple_dice(3, 4);
+        let two = ProbabilityDistribution::new_multiple_dice(2, 6);
+        let equal = one.equal_to(&two);
+
+        assert_eq!(
+            count_of(&equal, 1) as f64 / equal.total_outcome_count() as f64,
+            one.probability_tie(&two)
+        );
+    }
+
+    #[test]
+    fn test_comparison_drops_impossible_constraints() {
+        let dice = ProbabilityDistribution::new_dice(3).add_self_value_constraint(1);
+        let probability_distribution = dice.greater_or_equal(&dice);
+
+        assert_eq!(probability_distribution.total_outcome_count(), 3);
+        for face in 1..=3 {
+            assert_eq!(
+                probability_distribution.outcome_counts.get(
+                    &ProbabilityOutcome::new_with_constraints(
+                        1,
+                        vec![Constraint::new_single_valid_value_constraint(1, face)],
+                    )
+                ),
+                Some(&1)
+            );
+        }
+    }
+}
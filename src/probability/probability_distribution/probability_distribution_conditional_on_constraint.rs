@@ -0,0 +1,158 @@
+use std::collections::{BTreeMap, HashMap};
+
+use crate::constraint_management::{AreConstraintsCompiledWith, ConstraintIdType, IdToValueMap};
+use crate::probability::ProbabilityDistribution;
+use crate::ValueType;
+
+impl ProbabilityDistribution {
+    /// Conditions the [ProbabilityDistribution] on the constraint `id` having the value `value`.
+    /// only outcomes whose constraint map is compliant with `id` being `value` are kept,
+    /// outcomes without a constraint for `id` are unconstrained and are kept.
+    ///
+    /// counts of the kept outcomes are unchanged.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The [ConstraintIdType] of the tracked value.
+    /// * `value` - The [ValueType] the tracked value is known to have.
+    ///
+    /// # Returns
+    ///
+    /// The new [ProbabilityDistribution].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use crate::rusted_dice::probability::ProbabilityDistribution;
+    /// # use crate::rusted_dice::probability::ToTable;
+    /// let dice_one = ProbabilityDistribution::new_dice(3).add_self_value_constraint(1);
+    /// let dice_two = ProbabilityDistribution::new_dice(3);
+    ///
+    /// let conditioned = (dice_one + dice_two).conditional_on_constraint(1, 3);
+    ///
+    /// assert_eq!(
+    ///     conditioned
+    ///         .to_table()
+    ///         .to_string()
+    ///         .replace("\r\n", "\n"),
+    ///     "\
+    ///     +-------+-------+---+\n\
+    ///     | value | count | 1 |\n\
+    ///     +=======+=======+===+\n\
+    ///     | 4     | 1     | 3 |\n\
+    ///     +-------+-------+---+\n\
+    ///     | 5     | 1     | 3 |\n\
+    ///     +-------+-------+---+\n\
+    ///     | 6     | 1     | 3 |\n\
+    ///     +-------+-------+---+\n\
+    ///     ");
+    /// ```
+    pub fn conditional_on_constraint(&self, id: ConstraintIdType, value: ValueType) -> Self {
+        let mut id_value_map: IdToValueMap = HashMap::new();
+        id_value_map.insert(id, value);
+
+        let outcome_counts: BTreeMap<_, _> = self
+            .outcome_counts
+            .iter()
+            .filter(|(outcome, _)| {
+                outcome
+                    .constraint_map
+                    .is_compliant_with(id_value_map.clone())
+            })
+            .map(|(outcome, count)| (outcome.clone(), *count))
+            .collect();
+
+        ProbabilityDistribution { outcome_counts }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::constraint_management::Constraint;
+    use crate::probability::{ProbabilityDistribution, ProbabilityOutcome, ToTable};
+
+    #[test]
+    fn test_conditional_on_constraint_empty() {
+        let probability_distribution = ProbabilityDistribution::new_empty_distribution();
+        assert_eq!(
+            probability_distribution
+                .conditional_on_constraint(1, 1)
+                .outcome_counts
+                .len(),
+            0
+        );
+    }
+
+    #[test]
+    fn test_conditional_on_constraint_unconstrained_kept() {
+        let dice = ProbabilityDistribution::new_dice(6);
+        assert_eq!(
+            dice.conditional_on_constraint(1, 3).to_table(),
+            dice.to_table()
+        );
+    }
+
+    #[test]
+    fn test_conditional_on_constraint_mixed_outcomes() {
+        let unconstrained = ProbabilityOutcome::new_with_empty_constraint_map(1);
+        let permitted = ProbabilityOutcome::new_with_constraints(
+            2,
+            vec![Constraint::new_many_item_constraint(4, vec![1, 2])],
+        );
+        let excluded = ProbabilityOutcome::new_with_constraints(
+            3,
+            vec![Constraint::new_many_item_constraint(4, vec![3])],
+        );
+        let other_id = ProbabilityOutcome::new_with_constraints(
+            4,
+            vec![Constraint::new_many_item_constraint(5, vec![3])],
+        );
+        let probability_distribution =
+            ProbabilityDistribution::new_from_many_probability_outcomes(vec![
+                unconstrained.clone(),
+                permitted.clone(),
+                permitted.clone(),
+                excluded.clone(),
+                other_id.clone(),
+            ]);
+
+        let conditioned = probability_distribution.conditional_on_constraint(4, 2);
+
+        assert_eq!(conditioned.outcome_counts.get(&unconstrained), Some(&1));
+        assert_eq!(conditioned.outcome_counts.get(&permitted), Some(&2));
+        assert_eq!(conditioned.outcome_counts.get(&excluded), None);
+        assert_eq!(conditioned.outcome_counts.get(&other_id), Some(&1));
+        assert_eq!(conditioned.outcome_counts.len(), 3);
+    }
+
+    #[test]
+    fn test_conditional_on_constraint_tracked_dice() {
+        let dice_one = ProbabilityDistribution::new_dice(6).add_self_value_constraint(1);
+        let dice_two = ProbabilityDistribution::new_dice(6).add_self_value_constraint(2);
+        let two_d6 = dice_one + dice_two;
+
+        let conditioned = two_d6.conditional_on_constraint(1, 6);
+
+        assert_eq!(conditioned.total_outcome_count(), 6);
+        assert_eq!(
+            conditioned.to_table().to_string().replace("\r\n", "\n"),
+            "\
+            +-------+-------+---+---+\n\
+            | value | count | 1 | 2 |\n\
+            +=======+=======+===+===+\n\
+            | 7     | 1     | 6 | 1 |\n\
+            +-------+-------+---+---+\n\
+            | 8     | 1     | 6 | 2 |\n\
+            +-------+-------+---+---+\n\
+            | 9     | 1     | 6 | 3 |\n\
+            +-------+-------+---+---+\n\
+            | 10    | 1     | 6 | 4 |\n\
+            +-------+-------+---+---+\n\
+            | 11    | 1     | 6 | 5 |\n\
+            +-------+-------+---+---+\n\
+            | 12    | 1     | 6 | 6 |\n\
+            +-------+-------+---+---+\n\
+            "
+        );
+    }
+}
@@ -0,0 +1,91 @@
+use std::collections::BTreeSet;
+
+use crate::constraint_management::ConstraintIdType;
+use crate::probability::ProbabilityDistribution;
+
+impl ProbabilityDistribution {
+    /// Collects every constraint id used by any outcome of the [ProbabilityDistribution].
+    ///
+    /// # Returns
+    ///
+    /// The [BTreeSet] of [ConstraintIdType]s, sorted ascending.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use crate::rusted_dice::probability::ProbabilityDistribution;
+    /// let probability_distribution = ProbabilityDistribution::new_dice(4).add_self_value_constraint(3)
+    ///     + ProbabilityDistribution::new_dice(6).add_self_value_constraint(1)
+    ///     + ProbabilityDistribution::new_dice(8);
+    /// assert_eq!(
+    ///     probability_distribution.constraint_ids().into_iter().collect::<Vec<_>>(),
+    ///     vec![1, 3]
+    /// );
+    /// ```
+    pub fn constraint_ids(&self) -> BTreeSet<ConstraintIdType> {
+        self.outcome_counts
+            .keys()
+            .flat_map(|outcome| outcome.constraint_map.map.keys().copied())
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeSet;
+
+    use crate::constraint_management::{Constraint, ConstraintIdType};
+    use crate::probability::{ProbabilityDistribution, ProbabilityOutcome};
+
+    #[test]
+    fn test_constraint_ids_empty() {
+        let probability_distribution = ProbabilityDistribution::new_empty_distribution();
+        assert!(probability_distribution.constraint_ids().is_empty());
+    }
+
+    #[test]
+    fn test_constraint_ids_unconstrained() {
+        let probability_distribution = ProbabilityDistribution::new_multiple_dice(3, 6);
+        assert!(probability_distribution.constraint_ids().is_empty());
+    }
+
+    #[test]
+    fn test_constraint_ids_mixed_outcomes() {
+        let probability_distribution =
+            ProbabilityDistribution::new_from_many_probability_outcomes(vec![
+                ProbabilityOutcome::new_with_empty_constraint_map(1),
+                ProbabilityOutcome::new_with_constraints(
+                    2,
+                    vec![
+                        Constraint::new_single_valid_value_constraint(9, 2),
+                        Constraint::new_single_valid_value_constraint(4, 2),
+                    ],
+                ),
+                ProbabilityOutcome::new_with_constraints(
+                    3,
+                    vec![Constraint::new_many_item_constraint(4, vec![1, 2, 3])],
+                ),
+                ProbabilityOutcome::new_with_constraints(
+                    4,
+                    vec![Constraint::new_empty_constraint(0)],
+                ),
+            ]);
+
+        let expected: BTreeSet<ConstraintIdType> = probability_distribution
+            .outcome_counts
+            .keys()
+            .map(|outcome| outcome.constraint_map.map.keys().copied().collect())
+            .fold(BTreeSet::new(), |ids, outcome_ids: BTreeSet<_>| {
+                ids.union(&outcome_ids).copied().collect()
+            });
+
+        assert_eq!(probability_distribution.constraint_ids(), expected);
+        assert_eq!(
+            probability_distribution
+                .constraint_ids()
+                .into_iter()
+                .collect::<Vec<_>>(),
+            vec![0, 4, 9]
+        );
+    }
+}
@@ -0,0 +1,110 @@
+use crate::probability::ProbabilityDistribution;
+
+impl ProbabilityDistribution {
+    /// Creates the [ProbabilityDistribution] of the sum of one, two, ... up to `times` rolls of `single_die`.
+    /// useful to show how the sum of many dice builds up towards a bell curve.
+    ///
+    /// # Arguments
+    ///
+    /// * `single_die` - The [ProbabilityDistribution] of a single roll.
+    /// * `times` - The number of rolls in the last step.
+    ///
+    /// # Returns
+    ///
+    /// The [Vec] of `times` [ProbabilityDistribution]s, index i holds the sum of i + 1 rolls.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use crate::rusted_dice::probability::ProbabilityDistribution;
+    /// # use crate::rusted_dice::probability::ToTable;
+    /// let steps = ProbabilityDistribution::convolution_steps(&ProbabilityDistribution::new_dice(2), 2);
+    ///
+    /// assert_eq!(steps.len(), 2);
+    /// assert_eq!(
+    ///     steps[1]
+    ///         .to_table()
+    ///         .to_string()
+    ///         .replace("\r\n", "\n"),
+    ///     "\
+    ///     +-------+-------+\n\
+    ///     | value | count |\n\
+    ///     +=======+=======+\n\
+    ///     | 2     | 1     |\n\
+    ///     +-------+-------+\n\
+    ///     | 3     | 2     |\n\
+    ///     +-------+-------+\n\
+    ///     | 4     | 1     |\n\
+    ///     +-------+-------+\n\
+    ///     ");
+    /// ```
+    pub fn convolution_steps(single_die: &ProbabilityDistribution, times: u16) -> Vec<Self> {
+        let mut steps: Vec<Self> = Vec::with_capacity(times as usize);
+        for _ in 0..times {
+            let next_step = match steps.last() {
+                None => single_die.clone(),
+                Some(previous_step) => previous_step.clone() + single_die.clone(),
+            };
+            steps.push(next_step);
+        }
+        steps
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::probability::{ProbabilityDistribution, ToTable};
+
+    #[test]
+    fn test_convolution_steps_zero_times() {
+        let steps =
+            ProbabilityDistribution::convolution_steps(&ProbabilityDistribution::new_dice(6), 0);
+        assert!(steps.is_empty());
+    }
+
+    #[test]
+    fn test_convolution_steps_first_and_last() {
+        let single_die = ProbabilityDistribution::new_dice(6);
+        let steps = ProbabilityDistribution::convolution_steps(&single_die, 5);
+
+        assert_eq!(steps.len(), 5);
+        assert_eq!(steps[0].to_table(), single_die.to_table());
+        assert_eq!(
+            steps[4].to_table(),
+            ProbabilityDistribution::new_multiple_dice(5, 6).to_table()
+        );
+    }
+
+    #[test]
+    fn test_convolution_steps_every_step() {
+        let steps =
+            ProbabilityDistribution::convolution_steps(&ProbabilityDistribution::new_dice(4), 4);
+        for (index, step) in steps.iter().enumerate() {
+            assert_eq!(
+                step.to_table(),
+                ProbabilityDistribution::new_multiple_dice(index as u16 + 1, 4).to_table()
+            );
+        }
+    }
+
+    #[test]
+    fn test_convolution_steps_empty_die() {
+        let steps = ProbabilityDistribution::convolution_steps(
+            &ProbabilityDistribution::new_empty_distribution(),
+            3,
+        );
+        assert_eq!(steps.len(), 3);
+        assert!(steps.iter().all(|step| step.outcome_counts.is_empty()));
+    }
+
+    #[test]
+    fn test_convolution_steps_keeps_constraints() {
+        let single_die = ProbabilityDistribution::new_dice(3).add_self_value_constraint(1);
+        let steps = ProbabilityDistribution::convolution_steps(&single_die, 2);
+
+        assert_eq!(
+            steps[1].to_table(),
+            (single_die.clone() + single_die).to_table()
+        );
+    }
+}
@@ -0,0 +1,97 @@
+use crate::probability::ProbabilityDistribution;
+use crate::{CountType, ValueType};
+
+impl ProbabilityDistribution {
+    /// Checks if any outcome of the [ProbabilityDistribution] has the given value,
+    /// regardless of its constraints.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - The [ValueType] to look for.
+    ///
+    /// # Returns
+    ///
+    /// `true` if the value is a possible outcome, `false` otherwise.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use crate::rusted_dice::probability::ProbabilityDistribution;
+    /// let two_d6 = ProbabilityDistribution::new_multiple_dice(2, 6);
+    /// assert!(two_d6.contains_value(7));
+    /// assert!(!two_d6.contains_value(1));
+    /// ```
+    pub fn contains_value(&self, value: ValueType) -> bool {
+        self.outcome_counts
+            .keys()
+            .any(|probability_outcome| probability_outcome.value == value)
+    }
+
+    /// Counts the ways to roll the given value,
+    /// summing the counts of every outcome with that value regardless of its constraints.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - The [ValueType] to count.
+    ///
+    /// # Returns
+    ///
+    /// The [CountType] of the value, 0 if the value is not a possible outcome.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use crate::rusted_dice::probability::ProbabilityDistribution;
+    /// let two_d6 = ProbabilityDistribution::new_multiple_dice(2, 6);
+    /// assert_eq!(two_d6.count_of(7), 6);
+    /// assert_eq!(two_d6.count_of(1), 0);
+    /// ```
+    pub fn count_of(&self, value: ValueType) -> CountType {
+        self.outcome_counts
+            .iter()
+            .filter(|(probability_outcome, _)| probability_outcome.value == value)
+            .map(|(_, count)| count)
+            .sum()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::constraint_management::Constraint;
+    use crate::probability::ProbabilityDistribution;
+
+    #[test]
+    fn test_empty() {
+        let probability_distribution = ProbabilityDistribution::new_empty_distribution();
+        assert!(!probability_distribution.contains_value(0));
+        assert_eq!(probability_distribution.count_of(0), 0);
+    }
+
+    #[test]
+    fn test_two_d6() {
+        let two_d6 = ProbabilityDistribution::new_multiple_dice(2, 6);
+        assert_eq!(two_d6.count_of(7), 6);
+        assert_eq!(two_d6.count_of(2), 1);
+        assert_eq!(two_d6.count_of(13), 0);
+        assert!(two_d6.contains_value(12));
+        assert!(!two_d6.contains_value(1));
+    }
+
+    #[test]
+    fn test_sums_across_constraints() {
+        let d4 = ProbabilityDistribution::new_dice(4).add_self_value_constraint(1);
+        let other_d4 = ProbabilityDistribution::new_dice(4)
+            + Constraint::new_many_item_constraint(1, vec![1, 2]);
+        let probability_distribution = d4 + other_d4;
+
+        assert!(probability_distribution.outcome_counts.len() > 7);
+        assert_eq!(probability_distribution.count_of(2), 1);
+        assert_eq!(probability_distribution.count_of(3), 2);
+        assert_eq!(probability_distribution.count_of(8), 0);
+        assert!(!probability_distribution.contains_value(8));
+        let total: u64 = (2..=7)
+            .map(|value| probability_distribution.count_of(value))
+            .sum();
+        assert_eq!(total, probability_distribution.total_outcome_count());
+    }
+}
@@ -0,0 +1,127 @@
+use std::collections::BTreeMap;
+
+use crate::probability::{ProbabilityDistribution, ProbabilityOutcome};
+use crate::CountType;
+
+use super::{add_outcome_to_map, collapse_outcome_to_count_map};
+
+impl ProbabilityDistribution {
+    /// Creates the cumulative [ProbabilityDistribution], the count of each value is replaced
+    /// by the running total of the counts of all values up to and including it.
+    /// outcomes sharing a value but differing in constraints are counted together,
+    /// the outcomes of the result have empty constraint maps.
+    ///
+    /// the count of the highest value is the total count of `self`,
+    /// dividing a count by it gives the probability of rolling at most that value.
+    ///
+    /// # Returns
+    ///
+    /// The new cumulative [ProbabilityDistribution].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use crate::rusted_dice::probability::ProbabilityDistribution;
+    /// # use crate::rusted_dice::probability::ToTable;
+    /// let two_d3 = ProbabilityDistribution::new_multiple_dice(2, 3);
+    ///
+    /// assert_eq!(
+    ///     two_d3
+    ///         .to_cumulative()
+    ///         .to_table()
+    ///         .to_string()
+    ///         .replace("\r\n", "\n"),
+    ///     "\
+    ///     +-------+-------+\n\
+    ///     | value | count |\n\
+    ///     +=======+=======+\n\
+    ///     | 2     | 1     |\n\
+    ///     +-------+-------+\n\
+    ///     | 3     | 3     |\n\
+    ///     +-------+-------+\n\
+    ///     | 4     | 6     |\n\
+    ///     +-------+-------+\n\
+    ///     | 5     | 8     |\n\
+    ///     +-------+-------+\n\
+    ///     | 6     | 9     |\n\
+    ///     +-------+-------+\n\
+    ///     ");
+    /// ```
+    pub fn to_cumulative(&self) -> ProbabilityDistribution {
+        let mut map = BTreeMap::new();
+        let mut running_total: CountType = 0;
+        for (value, count) in collapse_outcome_to_count_map(&self.outcome_counts) {
+            running_total += count;
+            add_outcome_to_map(
+                &mut map,
+                ProbabilityOutcome::new_with_empty_constraint_map(value),
+                running_total,
+            );
+        }
+        ProbabilityDistribution {
+            outcome_counts: map,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::probability::ProbabilityDistribution;
+
+    #[test]
+    fn test_to_cumulative_empty() {
+        let probability_distribution = ProbabilityDistribution::new_empty_distribution();
+        assert_eq!(
+            probability_distribution
+                .to_cumulative()
+                .outcome_counts
+                .len(),
+            0
+        );
+    }
+
+    #[test]
+    fn test_to_cumulative_d6() {
+        let d6 = ProbabilityDistribution::new_dice(6);
+        assert_eq!(
+            d6.to_cumulative().coefficients(),
+            (1, vec![1, 2, 3, 4, 5, 6])
+        );
+    }
+
+    #[test]
+    fn test_to_cumulative_gaps() {
+        let d4_times_two = ProbabilityDistribution::new_dice(4) * 2;
+        assert_eq!(
+            d4_times_two.to_cumulative().coefficients(),
+            (2, vec![1, 0, 2, 0, 3, 0, 4])
+        );
+    }
+
+    #[test]
+    fn test_to_cumulative_matches_probability_at_most() {
+        let three_d6 = ProbabilityDistribution::new_multiple_dice(3, 6);
+        let cumulative = three_d6.to_cumulative();
+        let total = three_d6.total_outcome_count() as f64;
+
+        for (outcome, count) in cumulative.outcome_counts.iter() {
+            assert_eq!(
+                *count as f64 / total,
+                three_d6.probability_at_most(outcome.value)
+            );
+        }
+    }
+
+    #[test]
+    fn test_to_cumulative_merges_constraints() {
+        let dice_one = ProbabilityDistribution::new_dice(3).add_self_value_constraint(1);
+        let dice_two = ProbabilityDistribution::new_dice(3).add_self_value_constraint(2);
+        let cumulative = (dice_one + dice_two).to_cumulative();
+
+        assert_eq!(cumulative.coefficients(), (2, vec![1, 3, 6, 8, 9]));
+        assert!(cumulative
+            .outcome_counts
+            .keys()
+            .all(|outcome| outcome.constraint_map.map.is_empty()));
+    }
+}
@@ -0,0 +1,207 @@
+use std::collections::{BTreeMap, BTreeSet};
+
+use crate::constraint_management::ConstraintIdType;
+use crate::probability::{ProbabilityDistribution, ProbabilityOutcome};
+
+use super::add_outcome_to_map;
+
+impl ProbabilityDistribution {
+    /// Removes constraints that carry the same information as a constraint with a lower id.
+    /// useful to tidy tables after combining distributions that tracked the same dice twice.
+    ///
+    /// a constraint id is redundant if, on every outcome, it has the same valid values as a lower constraint id,
+    /// or both ids are missing. the lowest id of each group of redundant ids is kept, the others are dropped.
+    /// counts are unchanged.
+    ///
+    /// # Returns
+    ///
+    /// The new [ProbabilityDistribution].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use crate::rusted_dice::probability::ProbabilityDistribution;
+    /// # use crate::rusted_dice::probability::ToTable;
+    /// let dice = ProbabilityDistribution::new_dice(2)
+    ///     .add_self_value_constraint(1)
+    ///     .add_self_value_constraint(2);
+    ///
+    /// assert_eq!(
+    ///     dice.deduplicate_constraint_columns()
+    ///         .to_table()
+    ///         .to_string()
+    ///         .replace("\r\n", "\n"),
+    ///     "\
+    ///     +-------+-------+---+\n\
+    ///     | value | count | 1 |\n\
+    ///     +=======+=======+===+\n\
+    ///     | 1     | 1     | 1 |\n\
+    ///     +-------+-------+---+\n\
+    ///     | 2     | 1     | 2 |\n\
+    ///     +-------+-------+---+\n\
+    ///     ");
+    /// ```
+    pub fn deduplicate_constraint_columns(&self) -> Self {
+        let ids = self.constraint_ids();
+
+        let is_same_column = |id_a: ConstraintIdType, id_b: ConstraintIdType| {
+            self.outcome_counts.keys().all(|outcome| {
+                let valid_values_a = outcome
+                    .constraint_map
+                    .map
+                    .get(&id_a)
+                    .map(|constraint| &constraint.valid_values);
+                let valid_values_b = outcome
+                    .constraint_map
+                    .map
+                    .get(&id_b)
+                    .map(|constraint| &constraint.valid_values);
+                valid_values_a == valid_values_b
+            })
+        };
+
+        let mut kept_ids: Vec<ConstraintIdType> = Vec::new();
+        let mut redundant_ids: BTreeSet<ConstraintIdType> = BTreeSet::new();
+        for id in ids {
+            if kept_ids.iter().any(|kept_id| is_same_column(*kept_id, id)) {
+                redundant_ids.insert(id);
+            } else {
+                kept_ids.push(id);
+            }
+        }
+
+        let mut outcome_counts = BTreeMap::new();
+        for (outcome, count) in self.outcome_counts.iter() {
+            let mut constraint_map = outcome.constraint_map.clone();
+            constraint_map
+                .map
+                .retain(|id, _| !redundant_ids.contains(id));
+            add_outcome_to_map(
+                &mut outcome_counts,
+                ProbabilityOutcome {
+                    value: outcome.value,
+                    constraint_map,
+                },
+                *count,
+            );
+        }
+        ProbabilityDistribution { outcome_counts }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::constraint_management::Constraint;
+    use crate::probability::{ProbabilityDistribution, ProbabilityOutcome, ToTable};
+
+    #[test]
+    fn test_deduplicate_constraint_columns_empty() {
+        let probability_distribution = ProbabilityDistribution::new_empty_distribution();
+        assert_eq!(
+            probability_distribution
+                .deduplicate_constraint_columns()
+                .outcome_counts
+                .len(),
+            0
+        );
+    }
+
+    #[test]
+    fn test_deduplicate_constraint_columns_no_duplicates() {
+        let dice_one = ProbabilityDistribution::new_dice(3).add_self_value_constraint(1);
+        let dice_two = ProbabilityDistribution::new_dice(3).add_self_value_constraint(2);
+        let two_d3 = dice_one + dice_two;
+
+        assert_eq!(
+            two_d3.deduplicate_constraint_columns().to_table(),
+            two_d3.to_table()
+        );
+    }
+
+    #[test]
+    fn test_deduplicate_constraint_columns_identical_ids() {
+        let dice_one = ProbabilityDistribution::new_dice(3)
+            .add_self_value_constraint(4)
+            .add_self_value_constraint(2);
+        let dice_two = ProbabilityDistribution::new_dice(3).add_self_value_constraint(3);
+        let two_d3 = dice_one + dice_two;
+
+        let deduplicated = two_d3.deduplicate_constraint_columns();
+
+        assert_eq!(deduplicated.total_outcome_count(), 9);
+        for outcome in deduplicated.outcome_counts.keys() {
+            let mut ids: Vec<_> = outcome.constraint_map.map.keys().copied().collect();
+            ids.sort();
+            assert_eq!(ids, vec![2, 3]);
+        }
+        let expected = ProbabilityDistribution::new_dice(3).add_self_value_constraint(2)
+            + ProbabilityDistribution::new_dice(3).add_self_value_constraint(3);
+        assert_eq!(deduplicated.to_table(), expected.to_table());
+    }
+
+    #[test]
+    fn test_deduplicate_constraint_columns_partial_match_kept() {
+        let probability_distribution =
+            ProbabilityDistribution::new_from_many_probability_outcomes(vec![
+                ProbabilityOutcome::new_with_constraints(
+                    1,
+                    vec![
+                        Constraint::new_single_valid_value_constraint(1, 1),
+                        Constraint::new_single_valid_value_constraint(2, 1),
+                    ],
+                ),
+                ProbabilityOutcome::new_with_constraints(
+                    2,
+                    vec![
+                        Constraint::new_single_valid_value_constraint(1, 2),
+                        Constraint::new_single_valid_value_constraint(2, 3),
+                    ],
+                ),
+            ]);
+
+        assert_eq!(
+            probability_distribution
+                .deduplicate_constraint_columns()
+                .to_table(),
+            probability_distribution.to_table()
+        );
+    }
+
+    #[test]
+    fn test_deduplicate_constraint_columns_missing_on_some_outcomes() {
+        let probability_distribution =
+            ProbabilityDistribution::new_from_many_probability_outcomes(vec![
+                ProbabilityOutcome::new_with_empty_constraint_map(0),
+                ProbabilityOutcome::new_with_constraints(
+                    1,
+                    vec![
+                        Constraint::new_many_item_constraint(5, vec![1, 2]),
+                        Constraint::new_many_item_constraint(6, vec![1, 2]),
+                    ],
+                ),
+                ProbabilityOutcome::new_with_constraints(
+                    2,
+                    vec![Constraint::new_many_item_constraint(5, vec![3])],
+                ),
+            ]);
+
+        let deduplicated = probability_distribution.deduplicate_constraint_columns();
+
+        assert_eq!(deduplicated.to_table(), probability_distribution.to_table());
+    }
+
+    #[test]
+    fn test_deduplicate_constraint_columns_three_identical_ids() {
+        let dice = ProbabilityDistribution::new_dice(4)
+            .add_self_value_constraint(9)
+            .add_self_value_constraint(7)
+            .add_self_value_constraint(8);
+
+        assert_eq!(
+            dice.deduplicate_constraint_columns().to_table(),
+            ProbabilityDistribution::new_dice(4)
+                .add_self_value_constraint(7)
+                .to_table()
+        );
+    }
+}
@@ -0,0 +1,123 @@
+use crate::probability::ProbabilityDistribution;
+
+use super::collapse_outcome_to_count_map;
+
+impl ProbabilityDistribution {
+    /// Returns a short human readable summary of the [ProbabilityDistribution].
+    /// outcomes sharing a value but differing in constraints are counted together.
+    ///
+    /// the summary lists the number of distinct values, the total count, the lowest and highest value,
+    /// the mean, the standard deviation and every value tied for the highest count.
+    /// the mean and standard deviation are rounded to 3 decimal places.
+    ///
+    /// # Arguments
+    ///
+    /// * `self` - The [ProbabilityDistribution] to describe.
+    ///
+    /// # Returns
+    ///
+    /// The summary as a [String], or `"empty distribution"` if the [ProbabilityDistribution] is empty.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use crate::rusted_dice::probability::ProbabilityDistribution;
+    /// let two_d6 = ProbabilityDistribution::new_multiple_dice(2, 6);
+    /// assert_eq!(
+    ///     two_d6.describe(),
+    ///     "11 values with a total count of 36, ranging from 2 to 12, \
+    ///     mean 7, standard deviation 2.415, mode 7"
+    /// );
+    ///
+    /// let empty = ProbabilityDistribution::new_empty_distribution();
+    /// assert_eq!(empty.describe(), "empty distribution");
+    /// ```
+    pub fn describe(&self) -> String {
+        let value_to_count_map = collapse_outcome_to_count_map(&self.outcome_counts);
+        let (min_value, max_value) = match (
+            value_to_count_map.first_key_value(),
+            value_to_count_map.last_key_value(),
+        ) {
+            (Some((min_value, _)), Some((max_value, _))) => (*min_value, *max_value),
+            _ => return "empty distribution".to_string(),
+        };
+
+        let peak_count = value_to_count_map.values().max().copied().unwrap_or(0);
+        let mode: Vec<String> = value_to_count_map
+            .iter()
+            .filter(|(_, count)| **count == peak_count)
+            .map(|(value, _)| value.to_string())
+            .collect();
+
+        format!(
+            "{} values with a total count of {}, ranging from {} to {}, mean {}, standard deviation {}, mode {}",
+            value_to_count_map.len(),
+            self.total_outcome_count(),
+            min_value,
+            max_value,
+            round_for_description(self.mean()),
+            round_for_description(self.standard_deviation()),
+            mode.join(", ")
+        )
+    }
+}
+
+fn round_for_description(value: f64) -> f64 {
+    // adding 0.0 turns a rounded -0.0 into 0.0
+    (value * 1000.0).round() / 1000.0 + 0.0
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::constraint_management::Constraint;
+    use crate::probability::{ProbabilityDistribution, ProbabilityOutcome};
+
+    #[test]
+    fn test_describe_empty() {
+        let probability_distribution = ProbabilityDistribution::new_empty_distribution();
+        assert_eq!(probability_distribution.describe(), "empty distribution");
+    }
+
+    #[test]
+    fn test_describe_dice() {
+        let description = ProbabilityDistribution::new_dice(6).describe();
+
+        assert!(description.contains("mean 3.5,"));
+        assert!(description.contains("ranging from 1 to 6,"));
+        assert_eq!(
+            description,
+            "6 values with a total count of 6, ranging from 1 to 6, \
+            mean 3.5, standard deviation 1.708, mode 1, 2, 3, 4, 5, 6"
+        );
+    }
+
+    #[test]
+    fn test_describe_single_outcome() {
+        let probability_distribution = ProbabilityDistribution::new_from_single_probability_outcome(
+            ProbabilityOutcome::new_with_empty_constraint_map(-4),
+        );
+        assert_eq!(
+            probability_distribution.describe(),
+            "1 values with a total count of 1, ranging from -4 to -4, \
+            mean -4, standard deviation 0, mode -4"
+        );
+    }
+
+    #[test]
+    fn test_describe_merges_constraints() {
+        let probability_distribution =
+            ProbabilityDistribution::new_from_many_probability_outcomes(vec![
+                ProbabilityOutcome::new_with_empty_constraint_map(1),
+                ProbabilityOutcome::new_with_constraints(
+                    1,
+                    vec![Constraint::new_single_valid_value_constraint(1, 1)],
+                ),
+                ProbabilityOutcome::new_with_empty_constraint_map(3),
+            ]);
+        assert_eq!(
+            probability_distribution.describe(),
+            "2 values with a total count of 3, ranging from 1 to 3, \
+            mean 1.667, standard deviation 0.943, mode 1"
+        );
+    }
+}
@@ -0,0 +1,97 @@
+use crate::probability::ProbabilityDistribution;
+use crate::ValueType;
+
+impl ProbabilityDistribution {
+    /// Creates a new [ProbabilityDistribution] by rolling M, N sided dice and adding them together,
+    /// as [ProbabilityDistribution::new_multiple_dice] does, along with a description of the roll.
+    /// useful for tooltips and labels.
+    ///
+    /// the description has the form `"3d6 (3 dice, 6 sides, range 3–18)"`,
+    /// a roll with no outcomes is described with `"empty"` in place of the range.
+    ///
+    /// # Arguments
+    ///
+    /// * `number_of_dice` - [u16] The the number of dice to roll.
+    /// * `number_of_sides` - [ValueType] The the number of sides the dice has.
+    ///
+    /// # Returns
+    ///
+    /// A tuple of the new [ProbabilityDistribution] and its [String] description.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use crate::rusted_dice::probability::ProbabilityDistribution;
+    /// # use crate::rusted_dice::probability::ToTable;
+    /// let (three_d6, description) = ProbabilityDistribution::new_multiple_dice_described(3, 6);
+    ///
+    /// assert_eq!(description, "3d6 (3 dice, 6 sides, range 3–18)");
+    /// assert_eq!(
+    ///     three_d6.to_table(),
+    ///     ProbabilityDistribution::new_multiple_dice(3, 6).to_table()
+    /// );
+    /// ```
+    pub fn new_multiple_dice_described(
+        number_of_dice: u16,
+        number_of_sides: ValueType,
+    ) -> (ProbabilityDistribution, String) {
+        let probability_distribution =
+            ProbabilityDistribution::new_multiple_dice(number_of_dice, number_of_sides);
+
+        let range = match (
+            probability_distribution.outcome_counts.first_key_value(),
+            probability_distribution.outcome_counts.last_key_value(),
+        ) {
+            (Some((min_outcome, _)), Some((max_outcome, _))) => {
+                format!("range {}–{}", min_outcome.value, max_outcome.value)
+            }
+            _ => "empty".to_string(),
+        };
+        let description = format!(
+            "{}d{} ({} dice, {} sides, {})",
+            number_of_dice, number_of_sides, number_of_dice, number_of_sides, range
+        );
+
+        (probability_distribution, description)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::probability::{ProbabilityDistribution, ToTable};
+
+    #[test]
+    fn test_new_multiple_dice_described_two_d8() {
+        let (two_d8, description) = ProbabilityDistribution::new_multiple_dice_described(2, 8);
+
+        assert!(description.contains("range 2–16"));
+        assert_eq!(description, "2d8 (2 dice, 8 sides, range 2–16)");
+        assert_eq!(
+            two_d8.to_table(),
+            ProbabilityDistribution::new_multiple_dice(2, 8).to_table()
+        );
+    }
+
+    #[test]
+    fn test_new_multiple_dice_described_single_dice() {
+        let (_, description) = ProbabilityDistribution::new_multiple_dice_described(1, 20);
+        assert_eq!(description, "1d20 (1 dice, 20 sides, range 1–20)");
+    }
+
+    #[test]
+    fn test_new_multiple_dice_described_negative_sides() {
+        let (_, description) = ProbabilityDistribution::new_multiple_dice_described(2, -4);
+        assert_eq!(description, "2d-4 (2 dice, -4 sides, range -8–-2)");
+    }
+
+    #[test]
+    fn test_new_multiple_dice_described_empty() {
+        let (zero_dice, description) = ProbabilityDistribution::new_multiple_dice_described(0, 6);
+        assert_eq!(zero_dice.outcome_counts.len(), 0);
+        assert_eq!(description, "0d6 (0 dice, 6 sides, empty)");
+
+        let (zero_sides, description) = ProbabilityDistribution::new_multiple_dice_described(3, 0);
+        assert_eq!(zero_sides.outcome_counts.len(), 0);
+        assert_eq!(description, "3d0 (3 dice, 0 sides, empty)");
+    }
+}
@@ -0,0 +1,155 @@
+use std::collections::BTreeSet;
+
+use crate::probability::{ProbabilityDistribution, ProbabilityOutcome};
+use crate::CountType;
+
+/// A single [ProbabilityOutcome] whose count differs between two [ProbabilityDistribution]s.
+///
+/// a count of `None` means the [ProbabilityOutcome] is not present in that [ProbabilityDistribution].
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct OutcomeDiff {
+    pub outcome: ProbabilityOutcome,
+    pub count_a: Option<CountType>,
+    pub count_b: Option<CountType>,
+}
+
+/// a helper function to list the differences between the outcomes of two [ProbabilityDistribution]s.
+/// [ProbabilityOutcome]s are compared including their constraint maps.
+///
+/// # Arguments
+///
+/// * `a` - The first [ProbabilityDistribution].
+/// * `b` - The second [ProbabilityDistribution].
+///
+/// # Returns
+///
+/// A [Vec] of [OutcomeDiff], one for every [ProbabilityOutcome] present in only one of the
+/// [ProbabilityDistribution]s or present in both with different counts, sorted by [ProbabilityOutcome].
+///
+/// # Example
+/// ```
+/// # use crate::rusted_dice::probability::{diff, OutcomeDiff};
+/// # use crate::rusted_dice::probability::{ProbabilityDistribution, ProbabilityOutcome};
+/// let dice = ProbabilityDistribution::new_dice(4);
+/// let shifted = ProbabilityDistribution::new_dice(4) + 1;
+///
+/// assert_eq!(
+///     diff(&dice, &shifted),
+///     vec![
+///         OutcomeDiff {
+///             outcome: ProbabilityOutcome::new_with_empty_constraint_map(1),
+///             count_a: Some(1),
+///             count_b: None,
+///         },
+///         OutcomeDiff {
+///             outcome: ProbabilityOutcome::new_with_empty_constraint_map(5),
+///             count_a: None,
+///             count_b: Some(1),
+///         },
+///     ]
+/// );
+/// assert_eq!(diff(&dice, &dice), vec![]);
+/// ```
+pub fn diff(a: &ProbabilityDistribution, b: &ProbabilityDistribution) -> Vec<OutcomeDiff> {
+    let outcomes: BTreeSet<&ProbabilityOutcome> = a
+        .outcome_counts
+        .keys()
+        .chain(b.outcome_counts.keys())
+        .collect();
+
+    outcomes
+        .into_iter()
+        .filter_map(|outcome| {
+            let count_a = a.outcome_counts.get(outcome).copied();
+            let count_b = b.outcome_counts.get(outcome).copied();
+            (count_a != count_b).then(|| OutcomeDiff {
+                outcome: outcome.clone(),
+                count_a,
+                count_b,
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{diff, OutcomeDiff};
+    use crate::constraint_management::Constraint;
+    use crate::probability::{ProbabilityDistribution, ProbabilityOutcome};
+
+    #[test]
+    fn test_diff_empty() {
+        let probability_distribution = ProbabilityDistribution::new_empty_distribution();
+        assert_eq!(
+            diff(&probability_distribution, &probability_distribution),
+            vec![]
+        );
+    }
+
+    #[test]
+    fn test_diff_equal() {
+        let dice_one = ProbabilityDistribution::new_dice(6).add_self_value_constraint(1);
+        let dice_two = ProbabilityDistribution::new_dice(6).add_self_value_constraint(2);
+        let two_d6 = dice_one + dice_two;
+        assert_eq!(diff(&two_d6, &two_d6.clone()), vec![]);
+    }
+
+    #[test]
+    fn test_diff_single_differing_count() {
+        let mut nearly_equal = ProbabilityDistribution::new_multiple_dice(2, 6);
+        let changed_outcome = ProbabilityOutcome::new_with_empty_constraint_map(7);
+        nearly_equal
+            .outcome_counts
+            .insert(changed_outcome.clone(), 5);
+
+        assert_eq!(
+            diff(
+                &ProbabilityDistribution::new_multiple_dice(2, 6),
+                &nearly_equal
+            ),
+            vec![OutcomeDiff {
+                outcome: changed_outcome,
+                count_a: Some(6),
+                count_b: Some(5),
+            }]
+        );
+    }
+
+    #[test]
+    fn test_diff_constraint_maps() {
+        let unconstrained = ProbabilityDistribution::new_dice(2);
+        let constrained = ProbabilityDistribution::new_dice(2).add_self_value_constraint(3);
+
+        assert_eq!(
+            diff(&unconstrained, &constrained),
+            vec![
+                OutcomeDiff {
+                    outcome: ProbabilityOutcome::new_with_empty_constraint_map(1),
+                    count_a: Some(1),
+                    count_b: None,
+                },
+                OutcomeDiff {
+                    outcome: ProbabilityOutcome::new_with_constraints(
+                        1,
+                        vec![Constraint::new_single_valid_value_constraint(3, 1)]
+                    ),
+                    count_a: None,
+                    count_b: Some(1),
+                },
+                OutcomeDiff {
+                    outcome: ProbabilityOutcome::new_with_empty_constraint_map(2),
+                    count_a: Some(1),
+                    count_b: None,
+                },
+                OutcomeDiff {
+                    outcome: ProbabilityOutcome::new_with_constraints(
+                        2,
+                        vec![Constraint::new_single_valid_value_constraint(3, 2)]
+                    ),
+                    count_a: None,
+                    count_b: Some(1),
+                },
+            ]
+        );
+    }
+}
@@ -75,7 +75,8 @@ impl Div<ValueType> for ProbabilityDistribution {
     type Output = Self;
 
     /// Implements the divide operator for [ProbabilityDistribution] / [ValueType].
-    /// values are combined using the divide function.
+    /// values are combined using the divide function, which truncates toward zero,
+    /// see [ProbabilityDistribution::div_floor] to round toward negative infinity instead.
     /// constraint map is taken from the [ProbabilityDistribution].
     ///
     /// # Arguments
@@ -0,0 +1,180 @@
+use crate::{
+    probability::{Combine, ProbabilityDistribution},
+    ValueType,
+};
+
+fn _div_floor(lhs: ValueType, rhs: ValueType) -> ValueType {
+    let quotient = lhs / rhs;
+    if lhs % rhs != 0 && (lhs < 0) != (rhs < 0) {
+        quotient - 1
+    } else {
+        quotient
+    }
+}
+
+impl ProbabilityDistribution {
+    /// Divides every value in the [ProbabilityDistribution] by `divisor`, rounding toward negative infinity.
+    ///
+    /// the divide operator truncates toward zero, so the two differ when exactly one of the value
+    /// and the `divisor` is negative, -5 / 2 is -2 but `div_floor` gives -3.
+    /// for a positive `divisor` this matches [ValueType::div_euclid].
+    /// values which round to the same result are merged, constraint maps are kept.
+    ///
+    /// # Arguments
+    ///
+    /// * `divisor` - The [ValueType] to divide by.
+    ///
+    /// # Returns
+    ///
+    /// The new [ProbabilityDistribution].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `divisor` is 0, as with the divide operator.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use crate::rusted_dice::probability::ProbabilityDistribution;
+    /// # use crate::rusted_dice::probability::ToTable;
+    /// let dice = ProbabilityDistribution::new_dice(3) - 4;
+    ///
+    /// assert_eq!(
+    ///     dice.div_floor(2)
+    ///         .to_table()
+    ///         .to_string()
+    ///         .replace("\r\n", "\n"),
+    ///     "\
+    ///     +-------+-------+\n\
+    ///     | value | count |\n\
+    ///     +=======+=======+\n\
+    ///     | -2    | 1     |\n\
+    ///     +-------+-------+\n\
+    ///     | -1    | 2     |\n\
+    ///     +-------+-------+\n\
+    ///     ");
+    /// ```
+    pub fn div_floor(&self, divisor: ValueType) -> Self {
+        self.combine_value_type(divisor, _div_floor)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::_div_floor;
+    use crate::constraint_management::Constraint;
+    use crate::probability::{ProbabilityDistribution, ProbabilityOutcome, ToTable};
+    use crate::ValueType;
+
+    #[test]
+    fn test_div_floor_values() {
+        let cases = [
+            (5, 2, 2),
+            (4, 2, 2),
+            (-5, 2, -3),
+            (-4, 2, -2),
+            (-1, 2, -1),
+            (5, -2, -3),
+            (-5, -2, 2),
+            (-6, -2, 3),
+            (7, 3, 2),
+            (-7, 3, -3),
+            (0, 7, 0),
+            (0, -7, 0),
+        ];
+        for (lhs, rhs, expected) in cases {
+            assert_eq!(_div_floor(lhs, rhs), expected, "{} / {}", lhs, rhs);
+        }
+    }
+
+    #[test]
+    fn test_div_floor_matches_div_euclid_for_positive_divisors() {
+        for lhs in -20..=20 {
+            for rhs in 1..=7 {
+                assert_eq!(_div_floor(lhs, rhs), ValueType::div_euclid(lhs, rhs));
+            }
+        }
+    }
+
+    #[test]
+    fn test_div_floor_extremes() {
+        assert_eq!(_div_floor(i32::MAX, 2), 1073741823);
+        assert_eq!(_div_floor(i32::MIN, 2), -1073741824);
+        assert_eq!(_div_floor(i32::MIN + 1, 2), -1073741824);
+        assert_eq!(_div_floor(i32::MAX, i32::MIN), -1);
+        assert_eq!(_div_floor(i32::MIN, i32::MAX), -2);
+    }
+
+    #[test]
+    fn test_div_floor_empty() {
+        let probability_distribution = ProbabilityDistribution::new_empty_distribution();
+        assert_eq!(
+            probability_distribution.div_floor(2).outcome_counts.len(),
+            0
+        );
+    }
+
+    #[test]
+    fn test_div_floor_differs_from_div_for_negative_values() {
+        let dice = ProbabilityDistribution::new_dice(3) - 4;
+
+        let floored = dice.div_floor(2);
+        assert_eq!(
+            floored
+                .outcome_counts
+                .get(&ProbabilityOutcome::new_with_empty_constraint_map(-2)),
+            Some(&1)
+        );
+        assert_eq!(
+            floored
+                .outcome_counts
+                .get(&ProbabilityOutcome::new_with_empty_constraint_map(-1)),
+            Some(&2)
+        );
+        assert_eq!(floored.outcome_counts.len(), 2);
+
+        let truncated = dice / 2;
+        assert_eq!(
+            truncated
+                .outcome_counts
+                .get(&ProbabilityOutcome::new_with_empty_constraint_map(-1)),
+            Some(&2)
+        );
+        assert_eq!(
+            truncated
+                .outcome_counts
+                .get(&ProbabilityOutcome::new_with_empty_constraint_map(0)),
+            Some(&1)
+        );
+    }
+
+    #[test]
+    fn test_div_floor_matches_div_for_positive_values() {
+        let dice = ProbabilityDistribution::new_multiple_dice(2, 6);
+        assert_eq!(dice.div_floor(3).to_table(), (dice / 3).to_table());
+    }
+
+    #[test]
+    fn test_div_floor_keeps_constraints() {
+        let dice = (ProbabilityDistribution::new_dice(3) - 4)
+            + Constraint::new_many_item_constraint(1, vec![1, 2]);
+        let floored = dice.div_floor(2);
+
+        assert_eq!(
+            floored
+                .outcome_counts
+                .get(&ProbabilityOutcome::new_with_constraints(
+                    -1,
+                    vec![Constraint::new_many_item_constraint(1, vec![1, 2])]
+                )),
+            Some(&2)
+        );
+        assert_eq!(floored.outcome_counts.len(), 2);
+    }
+
+    #[test]
+    #[should_panic(expected = "attempt to divide by zero")]
+    fn test_div_floor_by_zero() {
+        ProbabilityDistribution::new_dice(6).div_floor(0);
+    }
+}
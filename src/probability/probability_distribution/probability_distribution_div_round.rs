@@ -0,0 +1,155 @@
+use crate::{
+    probability::{Combine, ProbabilityDistribution},
+    ValueType,
+};
+
+use super::div_round_with;
+
+fn _div_round(lhs: ValueType, rhs: ValueType) -> ValueType {
+    div_round_with(lhs, rhs, |_| true)
+}
+
+impl ProbabilityDistribution {
+    /// Divides every value in the [ProbabilityDistribution] by `divisor`,
+    /// rounding to the nearest integer and rounding ties away from zero.
+    ///
+    /// useful for "half damage, rounded" rules, unlike the divide operator which truncates toward zero.
+    /// values which round to the same result are merged, constraint maps are kept.
+    ///
+    /// # Arguments
+    ///
+    /// * `divisor` - The [ValueType] to divide by.
+    ///
+    /// # Returns
+    ///
+    /// The new [ProbabilityDistribution].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `divisor` is 0, as with the divide operator.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use crate::rusted_dice::probability::ProbabilityDistribution;
+    /// # use crate::rusted_dice::probability::ToTable;
+    /// let dice = ProbabilityDistribution::new_dice(5);
+    ///
+    /// assert_eq!(
+    ///     dice.div_round(2)
+    ///         .to_table()
+    ///         .to_string()
+    ///         .replace("\r\n", "\n"),
+    ///     "\
+    ///     +-------+-------+\n\
+    ///     | value | count |\n\
+    ///     +=======+=======+\n\
+    ///     | 1     | 2     |\n\
+    ///     +-------+-------+\n\
+    ///     | 2     | 2     |\n\
+    ///     +-------+-------+\n\
+    ///     | 3     | 1     |\n\
+    ///     +-------+-------+\n\
+    ///     ");
+    /// ```
+    pub fn div_round(&self, divisor: ValueType) -> Self {
+        self.combine_value_type(divisor, _div_round)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::_div_round;
+    use crate::constraint_management::Constraint;
+    use crate::probability::{ProbabilityDistribution, ProbabilityOutcome};
+
+    #[test]
+    fn test_div_round_values() {
+        let cases = [
+            (1, 2, 1),
+            (2, 2, 1),
+            (3, 2, 2),
+            (4, 2, 2),
+            (5, 2, 3),
+            (7, 3, 2),
+            (8, 3, 3),
+            (-1, 2, -1),
+            (-3, 2, -2),
+            (-5, 2, -3),
+            (5, -2, -3),
+            (-5, -2, 3),
+            (-7, 3, -2),
+            (-8, 3, -3),
+            (0, 7, 0),
+        ];
+        for (lhs, rhs, expected) in cases {
+            assert_eq!(_div_round(lhs, rhs), expected, "{} / {}", lhs, rhs);
+        }
+    }
+
+    #[test]
+    fn test_div_round_extremes() {
+        assert_eq!(_div_round(i32::MAX, 2), 1073741824);
+        assert_eq!(_div_round(i32::MIN, 2), -1073741824);
+        assert_eq!(_div_round(i32::MAX, i32::MIN), -1);
+        assert_eq!(_div_round(i32::MIN, i32::MAX), -1);
+        assert_eq!(_div_round(i32::MIN, i32::MIN), 1);
+    }
+
+    #[test]
+    fn test_div_round_empty() {
+        let probability_distribution = ProbabilityDistribution::new_empty_distribution();
+        assert_eq!(
+            probability_distribution.div_round(2).outcome_counts.len(),
+            0
+        );
+    }
+
+    #[test]
+    fn test_div_round_counts() {
+        let halved = ProbabilityDistribution::new_dice(5).div_round(2);
+
+        assert_eq!(
+            halved
+                .outcome_counts
+                .get(&ProbabilityOutcome::new_with_empty_constraint_map(1)),
+            Some(&2)
+        );
+        assert_eq!(
+            halved
+                .outcome_counts
+                .get(&ProbabilityOutcome::new_with_empty_constraint_map(2)),
+            Some(&2)
+        );
+        assert_eq!(
+            halved
+                .outcome_counts
+                .get(&ProbabilityOutcome::new_with_empty_constraint_map(3)),
+            Some(&1)
+        );
+        assert_eq!(halved.outcome_counts.len(), 3);
+    }
+
+    #[test]
+    fn test_div_round_keeps_constraints() {
+        let dice = ProbabilityDistribution::new_dice(3).add_self_value_constraint(1);
+        let halved = dice.div_round(2);
+
+        assert_eq!(
+            halved
+                .outcome_counts
+                .get(&ProbabilityOutcome::new_with_constraints(
+                    2,
+                    vec![Constraint::new_single_valid_value_constraint(1, 3)]
+                )),
+            Some(&1)
+        );
+        assert_eq!(halved.outcome_counts.len(), 3);
+    }
+
+    #[test]
+    #[should_panic(expected = "attempt to divide by zero")]
+    fn test_div_round_by_zero() {
+        ProbabilityDistribution::new_dice(6).div_round(0);
+    }
+}
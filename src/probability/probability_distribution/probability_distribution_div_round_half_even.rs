@@ -0,0 +1,170 @@
+use crate::{
+    probability::{Combine, ProbabilityDistribution},
+    ValueType,
+};
+
+use super::div_round_with;
+
+fn _div_round_half_even(lhs: ValueType, rhs: ValueType) -> ValueType {
+    div_round_with(lhs, rhs, |quotient| quotient % 2 != 0)
+}
+
+impl ProbabilityDistribution {
+    /// Divides every value in the [ProbabilityDistribution] by `divisor`,
+    /// rounding to the nearest integer and rounding ties to the nearest even integer.
+    ///
+    /// rounding ties to even, also called banker's rounding, does not bias the results up or down,
+    /// so repeatedly halving a [ProbabilityDistribution] keeps its mean close to the exact result.
+    /// values which round to the same result are merged, constraint maps are kept.
+    ///
+    /// # Arguments
+    ///
+    /// * `divisor` - The [ValueType] to divide by.
+    ///
+    /// # Returns
+    ///
+    /// The new [ProbabilityDistribution].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `divisor` is 0, as with the divide operator.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use crate::rusted_dice::probability::ProbabilityDistribution;
+    /// # use crate::rusted_dice::probability::ToTable;
+    /// let dice = ProbabilityDistribution::new_dice(5);
+    ///
+    /// assert_eq!(
+    ///     dice.div_round_half_even(2)
+    ///         .to_table()
+    ///         .to_string()
+    ///         .replace("\r\n", "\n"),
+    ///     "\
+    ///     +-------+-------+\n\
+    ///     | value | count |\n\
+    ///     +=======+=======+\n\
+    ///     | 0     | 1     |\n\
+    ///     +-------+-------+\n\
+    ///     | 1     | 1     |\n\
+    ///     +-------+-------+\n\
+    ///     | 2     | 3     |\n\
+    ///     +-------+-------+\n\
+    ///     ");
+    /// ```
+    pub fn div_round_half_even(&self, divisor: ValueType) -> Self {
+        self.combine_value_type(divisor, _div_round_half_even)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::_div_round_half_even;
+    use crate::constraint_management::Constraint;
+    use crate::probability::{ProbabilityDistribution, ProbabilityOutcome};
+
+    #[test]
+    fn test_div_round_half_even_values() {
+        let cases = [
+            (1, 2, 0),
+            (2, 2, 1),
+            (3, 2, 2),
+            (4, 2, 2),
+            (5, 2, 2),
+            (7, 2, 4),
+            (7, 3, 2),
+            (8, 3, 3),
+            (-1, 2, 0),
+            (-3, 2, -2),
+            (-5, 2, -2),
+            (5, -2, -2),
+            (-5, -2, 2),
+            (-8, 3, -3),
+            (0, 7, 0),
+        ];
+        for (lhs, rhs, expected) in cases {
+            assert_eq!(
+                _div_round_half_even(lhs, rhs),
+                expected,
+                "{} / {}",
+                lhs,
+                rhs
+            );
+        }
+    }
+
+    #[test]
+    fn test_div_round_half_even_extremes() {
+        assert_eq!(_div_round_half_even(i32::MAX, 2), 1073741824);
+        assert_eq!(_div_round_half_even(i32::MIN, 2), -1073741824);
+        assert_eq!(_div_round_half_even(i32::MAX, i32::MIN), -1);
+        assert_eq!(_div_round_half_even(i32::MIN, i32::MAX), -1);
+    }
+
+    #[test]
+    fn test_div_round_half_even_empty() {
+        let probability_distribution = ProbabilityDistribution::new_empty_distribution();
+        assert_eq!(
+            probability_distribution
+                .div_round_half_even(2)
+                .outcome_counts
+                .len(),
+            0
+        );
+    }
+
+    #[test]
+    fn test_div_round_half_even_counts() {
+        let halved = ProbabilityDistribution::new_multiple_dice(2, 4).div_round_half_even(2);
+
+        assert_eq!(
+            halved
+                .outcome_counts
+                .get(&ProbabilityOutcome::new_with_empty_constraint_map(1)),
+            Some(&1)
+        );
+        assert_eq!(
+            halved
+                .outcome_counts
+                .get(&ProbabilityOutcome::new_with_empty_constraint_map(2)),
+            Some(&9)
+        );
+        assert_eq!(
+            halved
+                .outcome_counts
+                .get(&ProbabilityOutcome::new_with_empty_constraint_map(3)),
+            Some(&3)
+        );
+        assert_eq!(
+            halved
+                .outcome_counts
+                .get(&ProbabilityOutcome::new_with_empty_constraint_map(4)),
+            Some(&3)
+        );
+        assert_eq!(halved.total_outcome_count(), 16);
+    }
+
+    #[test]
+    fn test_div_round_half_even_keeps_constraints() {
+        let dice = ProbabilityDistribution::new_dice(3).add_self_value_constraint(1);
+        let halved = dice.div_round_half_even(2);
+
+        assert_eq!(
+            halved
+                .outcome_counts
+                .get(&ProbabilityOutcome::new_with_constraints(
+                    2,
+                    vec![Constraint::new_single_valid_value_constraint(1, 3)]
+                )),
+            Some(&1)
+        );
+        assert_eq!(halved.outcome_counts.len(), 3);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_div_round_half_even_by_zero() {
+        ProbabilityDistribution::new_dice(6).div_round_half_even(0);
+    }
+}
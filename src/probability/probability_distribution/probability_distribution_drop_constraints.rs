@@ -0,0 +1,114 @@
+use std::collections::BTreeMap;
+
+use crate::probability::{ProbabilityDistribution, ProbabilityOutcome};
+
+use super::add_outcome_to_map;
+
+impl ProbabilityDistribution {
+    /// Removes every constraint from the [ProbabilityDistribution].
+    /// each outcome keeps its value with an empty constraint map,
+    /// outcomes which end up equal are merged and their counts added.
+    ///
+    /// # Returns
+    ///
+    /// The new [ProbabilityDistribution].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use crate::rusted_dice::probability::ProbabilityDistribution;
+    /// let dice = ProbabilityDistribution::new_dice(4).add_self_value_constraint(1)
+    ///     + ProbabilityDistribution::new_dice(4);
+    /// assert_eq!(dice.len(), 16);
+    ///
+    /// let unconstrained = dice.drop_constraints();
+    /// assert_eq!(unconstrained.len(), 7);
+    /// assert_eq!(
+    ///     unconstrained.outcome_counts,
+    ///     ProbabilityDistribution::new_multiple_dice(2, 4).outcome_counts
+    /// );
+    /// ```
+    pub fn drop_constraints(&self) -> Self {
+        let mut outcome_counts = BTreeMap::new();
+        for (probability_outcome, count) in self.outcome_counts.iter() {
+            add_outcome_to_map(
+                &mut outcome_counts,
+                ProbabilityOutcome::new_with_empty_constraint_map(probability_outcome.value),
+                *count,
+            );
+        }
+        ProbabilityDistribution { outcome_counts }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::constraint_management::Constraint;
+    use crate::probability::{ProbabilityDistribution, ProbabilityOutcome};
+
+    #[test]
+    fn test_drop_constraints_empty() {
+        let probability_distribution = ProbabilityDistribution::new_empty_distribution();
+        assert!(probability_distribution.drop_constraints().is_empty());
+    }
+
+    #[test]
+    fn test_drop_constraints_merges_equal_values() {
+        let probability_distribution =
+            ProbabilityDistribution::new_from_many_probability_outcomes(vec![
+                ProbabilityOutcome::new_with_constraints(
+                    5,
+                    vec![Constraint::new_single_valid_value_constraint(1, 2)],
+                ),
+                ProbabilityOutcome::new_with_constraints(
+                    5,
+                    vec![Constraint::new_single_valid_value_constraint(2, 3)],
+                ),
+            ]);
+        assert_eq!(probability_distribution.len(), 2);
+
+        let unconstrained = probability_distribution.drop_constraints();
+        assert_eq!(unconstrained.len(), 1);
+        assert_eq!(
+            unconstrained
+                .outcome_counts
+                .get(&ProbabilityOutcome::new_with_empty_constraint_map(5)),
+            Some(&2)
+        );
+    }
+
+    #[test]
+    fn test_drop_constraints_keeps_counts() {
+        let probability_distribution = ProbabilityDistribution::new_multiple_dice(3, 4)
+            .add_self_value_constraint(1)
+            + ProbabilityDistribution::new_dice(6).add_self_value_constraint(2);
+        let unconstrained = probability_distribution.drop_constraints();
+
+        assert_eq!(
+            unconstrained.total_outcome_count(),
+            probability_distribution.total_outcome_count()
+        );
+        assert_eq!(
+            unconstrained.iter_values().collect::<Vec<_>>(),
+            probability_distribution.iter_values().collect::<Vec<_>>()
+        );
+        assert!(unconstrained
+            .outcome_counts
+            .keys()
+            .all(|probability_outcome| probability_outcome.constraint_map.map.is_empty()));
+    }
+
+    #[test]
+    fn test_drop_constraints_keeps_impossible_outcomes() {
+        let probability_distribution = ProbabilityDistribution::new_from_single_probability_outcome(
+            ProbabilityOutcome::new_with_constraints(3, vec![Constraint::new_empty_constraint(1)]),
+        );
+        assert_eq!(
+            probability_distribution
+                .drop_constraints()
+                .outcome_counts
+                .get(&ProbabilityOutcome::new_with_empty_constraint_map(3)),
+            Some(&1)
+        );
+    }
+}
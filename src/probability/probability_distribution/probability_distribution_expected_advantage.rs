@@ -0,0 +1,69 @@
+use crate::probability::ProbabilityDistribution;
+
+impl ProbabilityDistribution {
+    /// Calculates the expected amount the [ProbabilityDistribution] beats `other` by, `E[self - other]`.
+    /// as expectation is linear this is the difference of the [ProbabilityDistribution::mean]s,
+    /// so the difference distribution is never built.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The [ProbabilityDistribution] to compare against.
+    ///
+    /// # Returns
+    ///
+    /// The signed expected advantage as a [f64], positive when `self` is expected to roll higher,
+    /// [f64::NAN] if either [ProbabilityDistribution] is empty.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use crate::rusted_dice::probability::ProbabilityDistribution;
+    /// let d8 = ProbabilityDistribution::new_dice(8);
+    /// let d6 = ProbabilityDistribution::new_dice(6);
+    /// assert_eq!(d8.expected_advantage_over(&d6), 1.0);
+    /// assert_eq!(d6.expected_advantage_over(&d8), -1.0);
+    /// ```
+    pub fn expected_advantage_over(&self, other: &Self) -> f64 {
+        self.mean() - other.mean()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::probability::ProbabilityDistribution;
+
+    #[test]
+    fn test_expected_advantage_over_empty() {
+        let empty = ProbabilityDistribution::new_empty_distribution();
+        let dice = ProbabilityDistribution::new_dice(6);
+        assert!(empty.expected_advantage_over(&dice).is_nan());
+        assert!(dice.expected_advantage_over(&empty).is_nan());
+    }
+
+    #[test]
+    fn test_expected_advantage_over_self() {
+        let probability_distribution = ProbabilityDistribution::new_multiple_dice(3, 6);
+        assert_eq!(
+            probability_distribution.expected_advantage_over(&probability_distribution),
+            0.0
+        );
+    }
+
+    #[test]
+    fn test_expected_advantage_over_d8_d6() {
+        assert_eq!(
+            ProbabilityDistribution::new_dice(8)
+                .expected_advantage_over(&ProbabilityDistribution::new_dice(6)),
+            1.0
+        );
+    }
+
+    #[test]
+    fn test_expected_advantage_over_matches_difference_mean() {
+        let one = ProbabilityDistribution::new_multiple_dice(2, 6) + 3;
+        let two = ProbabilityDistribution::new_multiple_dice(3, 4);
+        assert!(
+            (one.expected_advantage_over(&two) - (one.clone() - two.clone()).mean()).abs() < 1e-12
+        );
+    }
+}
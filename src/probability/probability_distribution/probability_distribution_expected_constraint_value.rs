@@ -0,0 +1,124 @@
+use crate::constraint_management::ConstraintIdType;
+use crate::probability::ProbabilityDistribution;
+
+impl ProbabilityDistribution {
+    /// Calculates the expected value of the tracked value of a constraint.
+    /// the count weighted average of the single valid value of constraint `id`,
+    /// taken over the outcomes which have a constraint for `id`.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The [ConstraintIdType] of the tracked value.
+    ///
+    /// # Returns
+    ///
+    /// The expected tracked value as a [f64].
+    /// [None] if no outcome has a constraint for `id`,
+    /// or if any constraint for `id` does not have exactly one valid value.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use crate::rusted_dice::probability::ProbabilityDistribution;
+    /// let dice_one = ProbabilityDistribution::new_dice(4).add_self_value_constraint(1);
+    /// let dice_two = ProbabilityDistribution::new_dice(6).add_self_value_constraint(2);
+    /// let total = dice_one + dice_two;
+    ///
+    /// assert_eq!(total.expected_constraint_value(1), Some(2.5));
+    /// assert_eq!(total.expected_constraint_value(2), Some(3.5));
+    /// assert_eq!(total.expected_constraint_value(3), None);
+    /// ```
+    pub fn expected_constraint_value(&self, id: ConstraintIdType) -> Option<f64> {
+        let mut weighted_sum: i128 = 0;
+        let mut total: u128 = 0;
+
+        for (outcome, count) in self.outcome_counts.iter() {
+            let Some(constraint) = outcome.constraint_map.map.get(&id) else {
+                continue;
+            };
+            if constraint.valid_values.len() != 1 {
+                return None;
+            }
+            let value = *constraint.valid_values.iter().next()?;
+            weighted_sum += i128::from(value) * i128::from(*count);
+            total += u128::from(*count);
+        }
+
+        match total {
+            0 => None,
+            _ => Some(weighted_sum as f64 / total as f64),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::constraint_management::Constraint;
+    use crate::probability::{ProbabilityDistribution, ProbabilityOutcome};
+
+    #[test]
+    fn test_expected_constraint_value_empty() {
+        let probability_distribution = ProbabilityDistribution::new_empty_distribution();
+        assert_eq!(probability_distribution.expected_constraint_value(1), None);
+    }
+
+    #[test]
+    fn test_expected_constraint_value_absent() {
+        let probability_distribution = ProbabilityDistribution::new_multiple_dice(2, 6);
+        assert_eq!(probability_distribution.expected_constraint_value(1), None);
+    }
+
+    #[test]
+    fn test_expected_constraint_value_tracked_dice_mean() {
+        let tracked_dice = ProbabilityDistribution::new_dice(6).add_self_value_constraint(1);
+        let probability_distribution =
+            tracked_dice + ProbabilityDistribution::new_multiple_dice(2, 6);
+
+        assert_eq!(
+            probability_distribution.expected_constraint_value(1),
+            Some(3.5)
+        );
+    }
+
+    #[test]
+    fn test_expected_constraint_value_skewed() {
+        let probability_distribution =
+            ProbabilityDistribution::new_from_many_probability_outcomes(vec![
+                ProbabilityOutcome::new_with_constraints(
+                    5,
+                    vec![Constraint::new_single_valid_value_constraint(1, 1)],
+                ),
+                ProbabilityOutcome::new_with_constraints(
+                    6,
+                    vec![Constraint::new_single_valid_value_constraint(1, -4)],
+                ),
+                ProbabilityOutcome::new_with_constraints(
+                    6,
+                    vec![Constraint::new_single_valid_value_constraint(1, -4)],
+                ),
+                ProbabilityOutcome::new_with_empty_constraint_map(100),
+            ]);
+
+        assert_eq!(
+            probability_distribution.expected_constraint_value(1),
+            Some(-7.0 / 3.0)
+        );
+    }
+
+    #[test]
+    fn test_expected_constraint_value_multi_valued() {
+        let probability_distribution =
+            ProbabilityDistribution::new_from_many_probability_outcomes(vec![
+                ProbabilityOutcome::new_with_constraints(
+                    1,
+                    vec![Constraint::new_single_valid_value_constraint(1, 1)],
+                ),
+                ProbabilityOutcome::new_with_constraints(
+                    2,
+                    vec![Constraint::new_many_item_constraint(1, vec![1, 2])],
+                ),
+            ]);
+
+        assert_eq!(probability_distribution.expected_constraint_value(1), None);
+    }
+}
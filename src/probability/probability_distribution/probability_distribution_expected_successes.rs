@@ -0,0 +1,97 @@
+use crate::probability::ProbabilityDistribution;
+use crate::ValueType;
+
+impl ProbabilityDistribution {
+    /// Returns the expected number of successes when rolling M, N sided dice,
+    /// a success being a dice showing at least `target`, as counted by
+    /// [CountSuccesses](crate::probability::pool_aggregator::CountSuccesses).
+    ///
+    /// with s successful faces, the expectation is the closed form `m * s / n`.
+    ///
+    /// for non-positive n, the expectation is 0.0.
+    ///
+    /// # Arguments
+    ///
+    /// * `number_of_dice` - [u16] The the number of dice to roll.
+    /// * `number_of_sides` - [ValueType] The the number of sides the dice has.
+    /// * `target` - The lowest [ValueType] face that counts as a success.
+    ///
+    /// # Returns
+    ///
+    /// The expected number of successes as a [f64].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use crate::rusted_dice::probability::ProbabilityDistribution;
+    /// // 4+ on 6d6
+    /// assert_eq!(ProbabilityDistribution::expected_successes(6, 6, 4), 3.0);
+    /// ```
+    pub fn expected_successes(
+        number_of_dice: u16,
+        number_of_sides: ValueType,
+        target: ValueType,
+    ) -> f64 {
+        if number_of_sides <= 0 {
+            return 0.0;
+        }
+
+        let sides = i64::from(number_of_sides);
+        let successful_faces = (sides - i64::from(target) + 1).clamp(0, sides);
+        f64::from(number_of_dice) * successful_faces as f64 / sides as f64
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::probability::pool_aggregator::CountSuccesses;
+    use crate::probability::ProbabilityDistribution;
+
+    #[test]
+    fn test_expected_successes_impossible() {
+        assert_eq!(ProbabilityDistribution::expected_successes(3, 0, 1), 0.0);
+        assert_eq!(ProbabilityDistribution::expected_successes(3, -6, 1), 0.0);
+    }
+
+    #[test]
+    fn test_expected_successes_zero_dice() {
+        assert_eq!(ProbabilityDistribution::expected_successes(0, 6, 4), 0.0);
+    }
+
+    #[test]
+    fn test_expected_successes_target_outside_faces() {
+        assert_eq!(ProbabilityDistribution::expected_successes(4, 6, 7), 0.0);
+        assert_eq!(ProbabilityDistribution::expected_successes(4, 6, 1), 4.0);
+        assert_eq!(ProbabilityDistribution::expected_successes(4, 6, -3), 4.0);
+    }
+
+    #[test]
+    fn test_expected_successes_large_pool() {
+        assert_eq!(
+            ProbabilityDistribution::expected_successes(u16::MAX, 20, 11),
+            f64::from(u16::MAX) / 2.0
+        );
+    }
+
+    #[test]
+    fn test_expected_successes_matches_new_pool_mean() {
+        for (number_of_dice, number_of_sides, target) in
+            [(1, 6, 4), (4, 6, 5), (5, 10, 8), (3, 8, 1), (3, 8, 9)]
+        {
+            let pool = ProbabilityDistribution::new_pool(
+                number_of_dice,
+                number_of_sides,
+                &CountSuccesses(target),
+            );
+            assert!(
+                (ProbabilityDistribution::expected_successes(
+                    number_of_dice,
+                    number_of_sides,
+                    target
+                ) - pool.mean())
+                .abs()
+                    < 1e-12
+            );
+        }
+    }
+}
@@ -0,0 +1,364 @@
+use std::collections::BTreeMap;
+
+use crate::probability::ProbabilityDistribution;
+use crate::ValueType;
+
+use super::add_outcome_to_map;
+
+impl ProbabilityDistribution {
+    /// Creates a new [ProbabilityDistribution] representing a dice that explodes on any of `explode_faces`.
+    /// rolling an exploding face adds the result of rolling the dice again,
+    /// which can explode again, up to `max_explosions` times.
+    ///
+    /// counts are scaled so that they stay integral,
+    /// the total count of the result is the number of sides to the power of `max_explosions + 1`.
+    ///
+    /// for negative n, the faces of the dice and `explode_faces` are from -1 to -n.
+    /// for Zero n, the [ProbabilityDistribution] will have no [ProbabilityOutcome](crate::probability::ProbabilityOutcome)s.
+    ///
+    /// # Arguments
+    ///
+    /// * `number_of_sides` - [ValueType] The the number of sides the dice has.
+    /// * `explode_faces` - The faces of the dice that trigger an explosion.
+    /// * `max_explosions` - The maximum number of times the dice can explode.
+    ///
+    /// # Returns
+    ///
+    /// The new [ProbabilityDistribution].
+    ///
+    /// # Panics
+    ///
+    /// Panics if any of `explode_faces` is not a face of the dice.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use crate::rusted_dice::probability::ProbabilityDistribution;
+    /// # use crate::rusted_dice::probability::ToTable;
+    /// let exploding_d4 = ProbabilityDistribution::new_exploding_on(4, &[3, 4], 1);
+    ///
+    /// assert_eq!(
+    ///     exploding_d4
+    ///         .to_table()
+    ///         .to_string()
+    ///         .replace("\r\n", "\n"),
+    ///     "\
+    ///     +-------+-------+\n\
+    ///     | value | count |\n\
+    ///     +=======+=======+\n\
+    ///     | 1     | 4     |\n\
+    ///     +-------+-------+\n\
+    ///     | 2     | 4     |\n\
+    ///     +-------+-------+\n\
+    ///     | 4     | 1     |\n\
+    ///     +-------+-------+\n\
+    ///     | 5     | 2     |\n\
+    ///     +-------+-------+\n\
+    ///     | 6     | 2     |\n\
+    ///     +-------+-------+\n\
+    ///     | 7     | 2     |\n\
+    ///     +-------+-------+\n\
+    ///     | 8     | 1     |\n\
+    ///     +-------+-------+\n\
+    ///     ");
+    /// ```
+    pub fn new_exploding_on(
+        number_of_sides: ValueType,
+        explode_faces: &[ValueType],
+        max_explosions: u32,
+    ) -> ProbabilityDistribution {
+        let single_dice = ProbabilityDistribution::new_dice(number_of_sides);
+        for explode_face in explode_faces {
+            if !single_dice
+                .outcome_counts
+                .keys()
+                .any(|outcome| outcome.value == *explode_face)
+            {
+                panic!("Explode faces must be faces of the dice.");
+            }
+        }
+
+        let mut result = single_dice.clone();
+        for _ in 0..max_explosions {
+            let result_total = result.total_outcome_count();
+            let mut new_outcome_counts = BTreeMap::new();
+
+            for (outcome, count) in single_dice.outcome_counts.iter() {
+                if explode_faces.contains(&outcome.value) {
+                    for (exploded_outcome, exploded_count) in
+                        (result.clone() + outcome.value).outcome_counts
+                    {
+                        add_outcome_to_map(
+                            &mut new_outcome_counts,
+                            exploded_outcome,
+                            exploded_count * count,
+                        );
+                    }
+                } else {
+                    add_outcome_to_map(
+                        &mut new_outcome_counts,
+                        outcome.clone(),
+                        count * result_total,
+                    );
+                }
+            }
+
+            result = ProbabilityDistribution {
+                outcome_counts: new_outcome_counts,
+            };
+        }
+        result
+    }
+
+    /// Creates a new [ProbabilityDistribution] representing a dice that explodes on its highest face.
+    /// rolling the highest face adds the result of rolling the dice again,
+    /// which can explode again, up to `max_explosions` times.
+    ///
+    /// an exploding dice without a cap can not be represented, every explosion multiplies the total count
+    /// by the number of sides, the total count of the result is the number of sides to the power of
+    /// `max_explosions + 1`, which has to fit in a [CountType](crate::CountType).
+    /// for a d6 that allows at most 23 explosions.
+    ///
+    /// for negative n, the faces of the dice are from -1 to -n and the dice explodes on -n.
+    /// for Zero n, the [ProbabilityDistribution] will have no [ProbabilityOutcome](crate::probability::ProbabilityOutcome)s.
+    ///
+    /// # Arguments
+    ///
+    /// * `number_of_sides` - [ValueType] The the number of sides the dice has.
+    /// * `max_explosions` - The maximum number of times the dice can explode.
+    ///
+    /// # Returns
+    ///
+    /// The new [ProbabilityDistribution].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use crate::rusted_dice::probability::ProbabilityDistribution;
+    /// # use crate::rusted_dice::probability::ToTable;
+    /// let exploding_d3 = ProbabilityDistribution::new_exploding_dice(3, 1);
+    ///
+    /// assert_eq!(
+    ///     exploding_d3
+    ///         .to_table()
+    ///         .to_string()
+    ///         .replace("\r\n", "\n"),
+    ///     "\
+    ///     +-------+-------+\n\
+    ///     | value | count |\n\
+    ///     +=======+=======+\n\
+    ///     | 1     | 3     |\n\
+    ///     +-------+-------+\n\
+    ///     | 2     | 3     |\n\
+    ///     +-------+-------+\n\
+    ///     | 4     | 1     |\n\
+    ///     +-------+-------+\n\
+    ///     | 5     | 1     |\n\
+    ///     +-------+-------+\n\
+    ///     | 6     | 1     |\n\
+    ///     +-------+-------+\n\
+    ///     ");
+    /// ```
+    pub fn new_exploding_dice(
+        number_of_sides: ValueType,
+        max_explosions: u16,
+    ) -> ProbabilityDistribution {
+        if number_of_sides == 0 {
+            return ProbabilityDistribution::new_empty_distribution();
+        }
+        ProbabilityDistribution::new_exploding_on(
+            number_of_sides,
+            &[number_of_sides],
+            max_explosions.into(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::probability::{ProbabilityDistribution, ProbabilityOutcome, ToTable};
+
+    #[test]
+    fn test_new_exploding_on_zero_sides() {
+        let probability_distribution = ProbabilityDistribution::new_exploding_on(0, &[], 3);
+        assert_eq!(probability_distribution.outcome_counts.len(), 0);
+    }
+
+    #[test]
+    fn test_new_exploding_on_no_explosions() {
+        assert_eq!(
+            ProbabilityDistribution::new_exploding_on(6, &[5, 6], 0).to_table(),
+            ProbabilityDistribution::new_dice(6).to_table()
+        );
+        assert_eq!(
+            ProbabilityDistribution::new_exploding_on(6, &[], 3).total_outcome_count(),
+            6_u64.pow(4)
+        );
+    }
+
+    #[test]
+    fn test_new_exploding_on_d6_five_and_six() {
+        let exploding_d6 = ProbabilityDistribution::new_exploding_on(6, &[5, 6], 1);
+
+        assert_eq!(
+            exploding_d6.to_table().to_string().replace("\r\n", "\n"),
+            "\
+            +-------+-------+\n\
+            | value | count |\n\
+            +=======+=======+\n\
+            | 1     | 6     |\n\
+            +-------+-------+\n\
+            | 2     | 6     |\n\
+            +-------+-------+\n\
+            | 3     | 6     |\n\
+            +-------+-------+\n\
+            | 4     | 6     |\n\
+            +-------+-------+\n\
+            | 6     | 1     |\n\
+            +-------+-------+\n\
+            | 7     | 2     |\n\
+            +-------+-------+\n\
+            | 8     | 2     |\n\
+            +-------+-------+\n\
+            | 9     | 2     |\n\
+            +-------+-------+\n\
+            | 10    | 2     |\n\
+            +-------+-------+\n\
+            | 11    | 2     |\n\
+            +-------+-------+\n\
+            | 12    | 1     |\n\
+            +-------+-------+\n\
+            "
+        );
+    }
+
+    #[test]
+    fn test_new_exploding_on_conserves_mass() {
+        for max_explosions in 0..4 {
+            let exploding_d4 =
+                ProbabilityDistribution::new_exploding_on(4, &[1, 4], max_explosions);
+            assert_eq!(
+                exploding_d4.total_outcome_count(),
+                4_u64.pow(max_explosions + 1)
+            );
+        }
+    }
+
+    #[test]
+    fn test_new_exploding_on_two_explosions() {
+        let exploding_d2 = ProbabilityDistribution::new_exploding_on(2, &[2], 2);
+
+        for (value, count) in [(1, 4), (3, 2), (5, 1), (6, 1)] {
+            assert_eq!(
+                exploding_d2
+                    .outcome_counts
+                    .get(&ProbabilityOutcome::new_with_empty_constraint_map(value)),
+                Some(&count)
+            );
+        }
+        assert_eq!(exploding_d2.outcome_counts.len(), 4);
+    }
+
+    #[test]
+    fn test_new_exploding_on_negative_sides() {
+        let exploding_d3 = ProbabilityDistribution::new_exploding_on(-3, &[-3], 1);
+        assert_eq!(
+            exploding_d3.to_table(),
+            (ProbabilityDistribution::new_exploding_on(3, &[3], 1) * -1).to_table()
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Explode faces must be faces of the dice.")]
+    fn test_new_exploding_on_face_too_large() {
+        ProbabilityDistribution::new_exploding_on(6, &[7], 1);
+    }
+
+    #[test]
+    #[should_panic(expected = "Explode faces must be faces of the dice.")]
+    fn test_new_exploding_on_face_zero() {
+        ProbabilityDistribution::new_exploding_on(6, &[0], 1);
+    }
+
+    #[test]
+    fn test_new_exploding_dice_zero_sides() {
+        let probability_distribution = ProbabilityDistribution::new_exploding_dice(0, 2);
+        assert_eq!(probability_distribution.outcome_counts.len(), 0);
+    }
+
+    #[test]
+    fn test_new_exploding_dice_no_explosions() {
+        assert_eq!(
+            ProbabilityDistribution::new_exploding_dice(8, 0).to_table(),
+            ProbabilityDistribution::new_dice(8).to_table()
+        );
+    }
+
+    #[test]
+    fn test_new_exploding_dice_d6_one_explosion() {
+        let exploding_d6 = ProbabilityDistribution::new_exploding_dice(6, 1);
+
+        assert_eq!(
+            exploding_d6.to_table().to_string().replace("\r\n", "\n"),
+            "\
+            +-------+-------+\n\
+            | value | count |\n\
+            +=======+=======+\n\
+            | 1     | 6     |\n\
+            +-------+-------+\n\
+            | 2     | 6     |\n\
+            +-------+-------+\n\
+            | 3     | 6     |\n\
+            +-------+-------+\n\
+            | 4     | 6     |\n\
+            +-------+-------+\n\
+            | 5     | 6     |\n\
+            +-------+-------+\n\
+            | 7     | 1     |\n\
+            +-------+-------+\n\
+            | 8     | 1     |\n\
+            +-------+-------+\n\
+            | 9     | 1     |\n\
+            +-------+-------+\n\
+            | 10    | 1     |\n\
+            +-------+-------+\n\
+            | 11    | 1     |\n\
+            +-------+-------+\n\
+            | 12    | 1     |\n\
+            +-------+-------+\n\
+            "
+        );
+    }
+
+    #[test]
+    fn test_new_exploding_dice_matches_new_exploding_on() {
+        for max_explosions in 0..4 {
+            assert_eq!(
+                ProbabilityDistribution::new_exploding_dice(4, max_explosions).to_table(),
+                ProbabilityDistribution::new_exploding_on(4, &[4], max_explosions.into())
+                    .to_table()
+            );
+        }
+    }
+
+    #[test]
+    fn test_new_exploding_dice_negative_sides() {
+        assert_eq!(
+            ProbabilityDistribution::new_exploding_dice(-4, 2).to_table(),
+            (ProbabilityDistribution::new_exploding_dice(4, 2) * -1).to_table()
+        );
+    }
+
+    #[test]
+    fn test_new_exploding_dice_largest_cap() {
+        let exploding_d6 = ProbabilityDistribution::new_exploding_dice(6, 23);
+        assert_eq!(exploding_d6.total_outcome_count(), 6_u64.pow(24));
+        assert_eq!(
+            exploding_d6
+                .outcome_counts
+                .last_key_value()
+                .map(|(outcome, count)| (outcome.value, *count)),
+            Some((6 * 23 + 6, 1))
+        );
+    }
+}
@@ -0,0 +1,153 @@
+use crate::probability::ProbabilityDistribution;
+use crate::ValueType;
+
+use super::collapse_outcome_to_count_map;
+
+impl ProbabilityDistribution {
+    /// Returns the lowest value in the [ProbabilityDistribution].
+    ///
+    /// # Returns
+    ///
+    /// The lowest [ValueType], or `None` if the [ProbabilityDistribution] is empty.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use crate::rusted_dice::probability::ProbabilityDistribution;
+    /// let two_d6 = ProbabilityDistribution::new_multiple_dice(2, 6);
+    /// assert_eq!(two_d6.min_value(), Some(2));
+    ///
+    /// let empty = ProbabilityDistribution::new_empty_distribution();
+    /// assert_eq!(empty.min_value(), None);
+    /// ```
+    pub fn min_value(&self) -> Option<ValueType> {
+        self.outcome_counts
+            .first_key_value()
+            .map(|(outcome, _)| outcome.value)
+    }
+
+    /// Returns the highest value in the [ProbabilityDistribution].
+    ///
+    /// # Returns
+    ///
+    /// The highest [ValueType], or `None` if the [ProbabilityDistribution] is empty.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use crate::rusted_dice::probability::ProbabilityDistribution;
+    /// let two_d6 = ProbabilityDistribution::new_multiple_dice(2, 6);
+    /// assert_eq!(two_d6.max_value(), Some(12));
+    ///
+    /// let empty = ProbabilityDistribution::new_empty_distribution();
+    /// assert_eq!(empty.max_value(), None);
+    /// ```
+    pub fn max_value(&self) -> Option<ValueType> {
+        self.outcome_counts
+            .last_key_value()
+            .map(|(outcome, _)| outcome.value)
+    }
+
+    /// Returns every most likely value in the [ProbabilityDistribution].
+    /// outcomes sharing a value but differing in constraints are counted together.
+    ///
+    /// # Returns
+    ///
+    /// The [Vec] of [ValueType]s tied for the highest count, sorted ascending,
+    /// empty if the [ProbabilityDistribution] is empty.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use crate::rusted_dice::probability::ProbabilityDistribution;
+    /// let two_d6 = ProbabilityDistribution::new_multiple_dice(2, 6);
+    /// assert_eq!(two_d6.mode(), vec![7]);
+    ///
+    /// let two_d4 = ProbabilityDistribution::new_multiple_dice(2, 4);
+    /// assert_eq!((two_d4 * 2).mode(), vec![10]);
+    /// ```
+    pub fn mode(&self) -> Vec<ValueType> {
+        let value_to_count_map = collapse_outcome_to_count_map(&self.outcome_counts);
+        let Some(peak_count) = value_to_count_map.values().max().copied() else {
+            return Vec::new();
+        };
+        value_to_count_map
+            .into_iter()
+            .filter(|(_, count)| *count == peak_count)
+            .map(|(value, _)| value)
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::constraint_management::Constraint;
+    use crate::probability::{ProbabilityDistribution, ProbabilityOutcome};
+
+    #[test]
+    fn test_extremes_empty() {
+        let probability_distribution = ProbabilityDistribution::new_empty_distribution();
+        assert_eq!(probability_distribution.min_value(), None);
+        assert_eq!(probability_distribution.max_value(), None);
+        assert!(probability_distribution.mode().is_empty());
+    }
+
+    #[test]
+    fn test_extremes_single_outcome() {
+        let probability_distribution = ProbabilityDistribution::new_from_single_probability_outcome(
+            ProbabilityOutcome::new_with_empty_constraint_map(-7),
+        );
+        assert_eq!(probability_distribution.min_value(), Some(-7));
+        assert_eq!(probability_distribution.max_value(), Some(-7));
+        assert_eq!(probability_distribution.mode(), vec![-7]);
+    }
+
+    #[test]
+    fn test_extremes_negative_values() {
+        let probability_distribution = -ProbabilityDistribution::new_multiple_dice(3, 4);
+        assert_eq!(probability_distribution.min_value(), Some(-12));
+        assert_eq!(probability_distribution.max_value(), Some(-3));
+        assert_eq!(probability_distribution.mode(), vec![-8, -7]);
+    }
+
+    #[test]
+    fn test_extremes_constrained() {
+        let probability_distribution = ProbabilityDistribution::new_dice(4)
+            .add_self_value_constraint(1)
+            + ProbabilityDistribution::new_dice(4).add_self_value_constraint(2);
+        assert_eq!(probability_distribution.min_value(), Some(2));
+        assert_eq!(probability_distribution.max_value(), Some(8));
+        assert_eq!(probability_distribution.mode(), vec![5]);
+    }
+
+    #[test]
+    fn test_mode_two_d6_single() {
+        let probability_distribution = ProbabilityDistribution::new_multiple_dice(2, 6);
+        assert_eq!(probability_distribution.mode(), vec![7]);
+    }
+
+    #[test]
+    fn test_mode_flat_d6() {
+        let probability_distribution = ProbabilityDistribution::new_dice(6);
+        assert_eq!(probability_distribution.mode(), vec![1, 2, 3, 4, 5, 6]);
+    }
+
+    #[test]
+    fn test_mode_merges_constraints() {
+        let probability_distribution =
+            ProbabilityDistribution::new_from_many_probability_outcomes(vec![
+                ProbabilityOutcome::new_with_empty_constraint_map(1),
+                ProbabilityOutcome::new_with_empty_constraint_map(1),
+                ProbabilityOutcome::new_with_empty_constraint_map(2),
+                ProbabilityOutcome::new_with_constraints(
+                    2,
+                    vec![Constraint::new_single_valid_value_constraint(1, 2)],
+                ),
+                ProbabilityOutcome::new_with_constraints(
+                    2,
+                    vec![Constraint::new_single_valid_value_constraint(1, 3)],
+                ),
+            ]);
+        assert_eq!(probability_distribution.mode(), vec![2]);
+    }
+}
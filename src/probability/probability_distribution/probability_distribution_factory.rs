@@ -1,5 +1,6 @@
 use std::collections::BTreeMap;
 
+use crate::constraint_management::Constraint;
 use crate::probability::ProbabilityDistribution;
 use crate::probability::ProbabilityOutcome;
 use crate::ValueType;
@@ -92,13 +93,68 @@ impl ProbabilityDistribution {
     pub fn new_from_many_probability_outcomes(
         probability_outcomes: impl IntoIterator<Item = ProbabilityOutcome>,
     ) -> ProbabilityDistribution {
-        let mut map = BTreeMap::new();
-        for probability_outcome in probability_outcomes {
-            add_outcome_to_map(&mut map, probability_outcome, 1)
-        }
-        ProbabilityDistribution {
-            outcome_counts: map,
-        }
+        probability_outcomes.into_iter().collect()
+    }
+
+    /// Creates a new [ProbabilityDistribution] with a single constant value and an empty constraint map.
+    /// the count will be 1 for this value.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - The [ValueType] of the constant.
+    ///
+    /// # Returns
+    ///
+    /// The new [ProbabilityDistribution].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use crate::rusted_dice::probability::ProbabilityDistribution;
+    /// let dice_plus_two = ProbabilityDistribution::new_dice(6) + ProbabilityDistribution::new_constant(2);
+    /// assert_eq!(dice_plus_two.mean(), 5.5);
+    /// ```
+    pub fn new_constant(value: ValueType) -> ProbabilityDistribution {
+        ProbabilityDistribution::new_from_single_probability_outcome(
+            ProbabilityOutcome::new_with_empty_constraint_map(value),
+        )
+    }
+
+    /// Creates a new [ProbabilityDistribution] with a single constant value carrying the given [Constraint]s.
+    /// the count will be 1 for this value.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - The [ValueType] of the constant.
+    /// * `constraints` - The [Constraint]s of the constant.
+    ///
+    /// # Returns
+    ///
+    /// The new [ProbabilityDistribution].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use crate::rusted_dice::probability::ProbabilityDistribution;
+    /// # use crate::rusted_dice::probability::ProbabilityOutcome;
+    /// # use crate::rusted_dice::constraint_management::Constraint;
+    /// let constraint = Constraint::new_single_valid_value_constraint(1, 4);
+    /// let probability_distribution =
+    ///     ProbabilityDistribution::new_constant_with_constraints(4, vec![constraint.clone()]);
+    /// assert_eq!(
+    ///     probability_distribution
+    ///         .outcome_counts
+    ///         .get(&ProbabilityOutcome::new_with_constraints(4, vec![constraint])),
+    ///     Some(&1)
+    /// );
+    /// ```
+    pub fn new_constant_with_constraints(
+        value: ValueType,
+        constraints: Vec<Constraint>,
+    ) -> ProbabilityDistribution {
+        ProbabilityDistribution::new_from_single_probability_outcome(
+            ProbabilityOutcome::new_with_constraints(value, constraints),
+        )
     }
 
     /// Creates a new [ProbabilityDistribution] with [ProbabilityOutcome]s representing a N sided dice.
@@ -237,8 +293,7 @@ impl ProbabilityDistribution {
         let mut combined_probability_distribution = single_dice.clone();
 
         for _ in 1..number_of_dice {
-            combined_probability_distribution =
-                combined_probability_distribution + single_dice.clone();
+            combined_probability_distribution += single_dice.clone();
         }
 
         combined_probability_distribution
@@ -247,6 +302,7 @@ impl ProbabilityDistribution {
 
 #[cfg(test)]
 mod tests {
+    use crate::constraint_management::Constraint;
     use crate::probability::{ProbabilityDistribution, ProbabilityOutcome, ToTable};
 
     #[test]
@@ -359,6 +415,55 @@ mod tests {
         assert_eq!(probability_distribution.outcome_counts.len(), 3);
     }
 
+    #[test]
+    fn test_new_constant() {
+        let probability_distribution = ProbabilityDistribution::new_constant(-42);
+        assert_eq!(
+            probability_distribution
+                .outcome_counts
+                .get(&ProbabilityOutcome::new_with_empty_constraint_map(-42)),
+            Some(&1)
+        );
+        assert_eq!(probability_distribution.outcome_counts.len(), 1);
+    }
+
+    #[test]
+    fn test_new_constant_in_arithmetic() {
+        let probability_distribution =
+            ProbabilityDistribution::new_dice(6) + ProbabilityDistribution::new_constant(2);
+        assert_eq!(
+            probability_distribution.to_table(),
+            (ProbabilityDistribution::new_dice(6) + 2).to_table()
+        );
+    }
+
+    #[test]
+    fn test_new_constant_with_constraints_empty() {
+        let probability_distribution =
+            ProbabilityDistribution::new_constant_with_constraints(7, vec![]);
+        assert_eq!(
+            probability_distribution.outcome_counts,
+            ProbabilityDistribution::new_constant(7).outcome_counts
+        );
+    }
+
+    #[test]
+    fn test_new_constant_with_constraints() {
+        let constraints = vec![
+            Constraint::new_single_valid_value_constraint(1, 3),
+            Constraint::new_many_item_constraint(2, vec![1, 2]),
+        ];
+        let probability_distribution =
+            ProbabilityDistribution::new_constant_with_constraints(3, constraints.clone());
+        assert_eq!(
+            probability_distribution
+                .outcome_counts
+                .get(&ProbabilityOutcome::new_with_constraints(3, constraints)),
+            Some(&1)
+        );
+        assert_eq!(probability_distribution.outcome_counts.len(), 1);
+    }
+
     #[test]
     fn test_new_dice_zero() {
         let probability_distribution = ProbabilityDistribution::new_dice(0);
@@ -0,0 +1,127 @@
+use crate::probability::{ProbabilityDistribution, ProbabilityOutcome};
+
+impl ProbabilityDistribution {
+    /// Keeps only the outcomes of the [ProbabilityDistribution] passing `predicate`, with their counts unchanged,
+    /// for example discarding every roll below 3. unlike constraints, which gate combination,
+    /// the removed outcomes are gone entirely.
+    ///
+    /// the [total_outcome_count](ProbabilityDistribution::total_outcome_count) of the result no longer matches the original,
+    /// probabilities taken from the result, such as by [to_probabilities](ProbabilityDistribution::to_probabilities),
+    /// are renormalized over the kept outcomes.
+    ///
+    /// # Arguments
+    ///
+    /// * `predicate` - The function deciding whether each [ProbabilityOutcome] is kept.
+    ///
+    /// # Returns
+    ///
+    /// The new [ProbabilityDistribution].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use crate::rusted_dice::probability::ProbabilityDistribution;
+    /// let d20 = ProbabilityDistribution::new_dice(20);
+    /// let high_rolls = d20.filter(|outcome| outcome.value >= 11);
+    ///
+    /// assert_eq!(high_rolls.total_outcome_count(), 10);
+    /// assert_eq!(high_rolls.mean(), 15.5);
+    /// ```
+    pub fn filter<F: Fn(&ProbabilityOutcome) -> bool>(&self, predicate: F) -> Self {
+        ProbabilityDistribution {
+            outcome_counts: self
+                .outcome_counts
+                .iter()
+                .filter(|(outcome, _)| predicate(outcome))
+                .map(|(outcome, count)| (outcome.clone(), *count))
+                .collect(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::constraint_management::Constraint;
+    use crate::probability::{ProbabilityDistribution, ProbabilityOutcome};
+
+    #[test]
+    fn test_filter_empty() {
+        let probability_distribution =
+            ProbabilityDistribution::new_empty_distribution().filter(|_| true);
+        assert_eq!(probability_distribution.outcome_counts.len(), 0);
+    }
+
+    #[test]
+    fn test_filter_keep_all() {
+        let probability_distribution = ProbabilityDistribution::new_multiple_dice(2, 6);
+        assert_eq!(
+            probability_distribution.filter(|_| true).outcome_counts,
+            probability_distribution.outcome_counts
+        );
+    }
+
+    #[test]
+    fn test_filter_keep_none() {
+        let probability_distribution =
+            ProbabilityDistribution::new_multiple_dice(2, 6).filter(|_| false);
+        assert_eq!(probability_distribution.outcome_counts.len(), 0);
+    }
+
+    #[test]
+    fn test_filter_d20_high_half() {
+        let probability_distribution =
+            ProbabilityDistribution::new_dice(20).filter(|outcome| outcome.value >= 11);
+
+        assert_eq!(probability_distribution.outcome_counts.len(), 10);
+        for value in 11..=20 {
+            assert_eq!(
+                probability_distribution
+                    .outcome_counts
+                    .get(&ProbabilityOutcome::new_with_empty_constraint_map(value)),
+                Some(&1)
+            );
+        }
+    }
+
+    #[test]
+    fn test_filter_keeps_counts() {
+        let probability_distribution = ProbabilityDistribution::new_multiple_dice(2, 6)
+            .filter(|outcome| outcome.value % 2 == 0);
+
+        assert_eq!(probability_distribution.outcome_counts.len(), 6);
+        assert_eq!(probability_distribution.total_outcome_count(), 18);
+        assert_eq!(
+            probability_distribution
+                .outcome_counts
+                .get(&ProbabilityOutcome::new_with_empty_constraint_map(6)),
+            Some(&5)
+        );
+    }
+
+    #[test]
+    fn test_filter_on_constraints() {
+        let probability_distribution = (ProbabilityDistribution::new_dice(3)
+            .add_self_value_constraint(1)
+            + ProbabilityDistribution::new_dice(3))
+        .filter(|outcome| {
+            outcome
+                .constraint_map
+                .map
+                .get(&1)
+                .is_some_and(|constraint| constraint.valid_values.contains(&3))
+        });
+
+        assert_eq!(probability_distribution.total_outcome_count(), 3);
+        for value in 4..=6 {
+            assert_eq!(
+                probability_distribution.outcome_counts.get(
+                    &ProbabilityOutcome::new_with_constraints(
+                        value,
+                        vec![Constraint::new_single_valid_value_constraint(1, 3)],
+                    )
+                ),
+                Some(&1)
+            );
+        }
+    }
+}
@@ -0,0 +1,135 @@
+use std::collections::BTreeSet;
+
+use crate::probability::{ProbabilityDistribution, ValueToCountMap};
+use crate::ValueType;
+
+use super::collapse_outcome_to_count_map;
+
+impl ProbabilityDistribution {
+    /// Checks whether the [ProbabilityDistribution] first order stochastically dominates `other`,
+    /// for every value the probability of rolling at most that value is no larger for `self` than for `other`.
+    /// so `self` is at least as likely as `other` to roll any value or higher.
+    ///
+    /// outcomes sharing a value but differing in constraints are counted together,
+    /// probabilities are compared exactly using the counts.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The [ProbabilityDistribution] to compare against.
+    ///
+    /// # Returns
+    ///
+    /// `true` if `self` dominates `other`, `false` otherwise.
+    /// an empty [ProbabilityDistribution] neither dominates nor is dominated.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use crate::rusted_dice::probability::ProbabilityDistribution;
+    /// let d8 = ProbabilityDistribution::new_dice(8);
+    /// let d6 = ProbabilityDistribution::new_dice(6);
+    ///
+    /// assert!(d8.first_order_dominates(&d6));
+    /// assert!(!d6.first_order_dominates(&d8));
+    /// ```
+    pub fn first_order_dominates(&self, other: &Self) -> bool {
+        let self_counts = collapse_outcome_to_count_map(&self.outcome_counts);
+        let other_counts = collapse_outcome_to_count_map(&other.outcome_counts);
+        let self_total = u128::from(self.total_outcome_count());
+        let other_total = u128::from(other.total_outcome_count());
+        if self_total == 0 || other_total == 0 {
+            return false;
+        }
+
+        let values: BTreeSet<ValueType> = self_counts
+            .keys()
+            .chain(other_counts.keys())
+            .copied()
+            .collect();
+        let (mut self_cumulative, mut other_cumulative): (u128, u128) = (0, 0);
+        for value in values {
+            let count_of =
+                |counts: &ValueToCountMap| u128::from(counts.get(&value).copied().unwrap_or(0));
+            self_cumulative += count_of(&self_counts);
+            other_cumulative += count_of(&other_counts);
+            if self_cumulative * other_total > other_cumulative * self_total {
+                return false;
+            }
+        }
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::probability::ProbabilityDistribution;
+
+    #[test]
+    fn test_first_order_dominates_empty() {
+        let empty = ProbabilityDistribution::new_empty_distribution();
+        let d6 = ProbabilityDistribution::new_dice(6);
+
+        assert!(!empty.first_order_dominates(&d6));
+        assert!(!d6.first_order_dominates(&empty));
+        assert!(!empty.first_order_dominates(&empty));
+    }
+
+    #[test]
+    fn test_first_order_dominates_bigger_dice() {
+        let d8 = ProbabilityDistribution::new_dice(8);
+        let d6 = ProbabilityDistribution::new_dice(6);
+
+        assert!(d8.first_order_dominates(&d6));
+        assert!(!d6.first_order_dominates(&d8));
+    }
+
+    #[test]
+    fn test_first_order_dominates_itself() {
+        let three_d6 = ProbabilityDistribution::new_multiple_dice(3, 6);
+        assert!(three_d6.first_order_dominates(&three_d6));
+    }
+
+    #[test]
+    fn test_first_order_dominates_shifted() {
+        let d6 = ProbabilityDistribution::new_dice(6);
+        let d6_plus_one = ProbabilityDistribution::new_dice(6) + 1;
+
+        assert!(d6_plus_one.first_order_dominates(&d6));
+        assert!(!d6.first_order_dominates(&d6_plus_one));
+    }
+
+    #[test]
+    fn test_first_order_dominates_crossing() {
+        // same mean, but 2d6 is more concentrated, the cumulative probabilities cross
+        let two_d6 = ProbabilityDistribution::new_multiple_dice(2, 6);
+        let d11_plus_one = ProbabilityDistribution::new_dice(11) + 1;
+
+        assert!(!two_d6.first_order_dominates(&d11_plus_one));
+        assert!(!d11_plus_one.first_order_dominates(&two_d6));
+    }
+
+    #[test]
+    fn test_first_order_dominates_different_totals() {
+        let d6 = ProbabilityDistribution::new_dice(6);
+        let d6_twice = ProbabilityDistribution::new_from_many_probability_outcomes(
+            d6.outcome_counts
+                .keys()
+                .flat_map(|outcome| [outcome.clone(), outcome.clone()])
+                .collect::<Vec<_>>(),
+        );
+
+        assert!(d6.first_order_dominates(&d6_twice));
+        assert!(d6_twice.first_order_dominates(&d6));
+    }
+
+    #[test]
+    fn test_first_order_dominates_advantage() {
+        let advantage = ProbabilityDistribution::new_keep_highest(2, 20, 1);
+        let disadvantage = ProbabilityDistribution::new_keep_lowest(2, 20, 1);
+        let d20 = ProbabilityDistribution::new_dice(20);
+
+        assert!(advantage.first_order_dominates(&d20));
+        assert!(d20.first_order_dominates(&disadvantage));
+        assert!(!disadvantage.first_order_dominates(&advantage));
+    }
+}
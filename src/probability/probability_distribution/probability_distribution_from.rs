@@ -0,0 +1,115 @@
+use crate::probability::{ProbabilityDistribution, ProbabilityOutcome};
+use crate::ValueType;
+
+impl From<ValueType> for ProbabilityDistribution {
+    /// Converts a [ValueType] into a [ProbabilityDistribution] with a single constant [ProbabilityOutcome].
+    /// the [ProbabilityOutcome] has an empty constraint map and a count of 1.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - The [ValueType] of the single [ProbabilityOutcome].
+    ///
+    /// # Returns
+    ///
+    /// The new [ProbabilityDistribution].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use crate::rusted_dice::probability::ProbabilityDistribution;
+    /// # use crate::rusted_dice::probability::ProbabilityOutcome;
+    /// let probability_distribution: ProbabilityDistribution = 5.into();
+    /// assert_eq!(
+    ///     probability_distribution
+    ///         .outcome_counts
+    ///         .get(&ProbabilityOutcome::new_with_empty_constraint_map(5)),
+    ///     Some(&1)
+    /// );
+    /// assert_eq!(probability_distribution.outcome_counts.len(), 1);
+    /// ```
+    fn from(value: ValueType) -> Self {
+        ProbabilityDistribution::new_from_single_probability_outcome(
+            ProbabilityOutcome::new_with_empty_constraint_map(value),
+        )
+    }
+}
+
+impl From<ProbabilityOutcome> for ProbabilityDistribution {
+    /// Converts a [ProbabilityOutcome] into a [ProbabilityDistribution] containing only that [ProbabilityOutcome].
+    /// the count will be 1 for this [ProbabilityOutcome].
+    ///
+    /// # Arguments
+    ///
+    /// * `probability_outcome` - The [ProbabilityOutcome] to add to the [ProbabilityDistribution].
+    ///
+    /// # Returns
+    ///
+    /// The new [ProbabilityDistribution].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use crate::rusted_dice::probability::ProbabilityDistribution;
+    /// # use crate::rusted_dice::probability::ProbabilityOutcome;
+    /// # use crate::rusted_dice::constraint_management::Constraint;
+    /// let probability_outcome = ProbabilityOutcome::new_with_constraints(
+    ///     3,
+    ///     vec![Constraint::new_single_valid_value_constraint(1, 3)],
+    /// );
+    /// let probability_distribution = ProbabilityDistribution::from(probability_outcome.clone());
+    /// assert_eq!(probability_distribution.outcome_counts.get(&probability_outcome), Some(&1));
+    /// assert_eq!(probability_distribution.outcome_counts.len(), 1);
+    /// ```
+    fn from(probability_outcome: ProbabilityOutcome) -> Self {
+        ProbabilityDistribution::new_from_single_probability_outcome(probability_outcome)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::constraint_management::Constraint;
+    use crate::probability::{ProbabilityDistribution, ProbabilityOutcome, ToTable};
+    use crate::ValueType;
+
+    use proptest::prelude::*;
+
+    proptest! {
+        #[test]
+        fn test_from_value_type(test_value: ValueType) {
+            let probability_distribution: ProbabilityDistribution = test_value.into();
+            assert_eq!(
+                probability_distribution
+                    .outcome_counts
+                    .get(&ProbabilityOutcome::new_with_empty_constraint_map(test_value)),
+                Some(&1)
+            );
+            assert_eq!(probability_distribution.outcome_counts.len(), 1);
+        }
+    }
+
+    #[test]
+    fn test_from_probability_outcome() {
+        let probability_outcome = ProbabilityOutcome::new_with_constraints(
+            12,
+            vec![Constraint::new_many_item_constraint(3, vec![1, 2])],
+        );
+        let probability_distribution: ProbabilityDistribution = probability_outcome.clone().into();
+
+        assert_eq!(
+            probability_distribution.to_table(),
+            ProbabilityDistribution::new_from_single_probability_outcome(probability_outcome)
+                .to_table()
+        );
+        assert_eq!(probability_distribution.outcome_counts.len(), 1);
+    }
+
+    #[test]
+    fn test_from_value_type_in_arithmetic() {
+        let probability_distribution =
+            ProbabilityDistribution::new_dice(6) + ProbabilityDistribution::from(2);
+        assert_eq!(
+            probability_distribution.to_table(),
+            (ProbabilityDistribution::new_dice(6) + 2).to_table()
+        );
+    }
+}
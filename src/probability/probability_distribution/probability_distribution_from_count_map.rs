@@ -0,0 +1,141 @@
+use std::collections::BTreeMap;
+
+use crate::probability::{ProbabilityDistribution, ProbabilityOutcome, ValueToCountMap};
+
+use super::{add_outcome_to_map, collapse_outcome_to_count_map};
+
+/// A trait for probability distributions to be converted to and from a [ValueToCountMap]
+pub trait FromCountMap {
+    fn from_value_count_map(map: ValueToCountMap) -> Self;
+    fn to_value_count_map(&self) -> ValueToCountMap;
+}
+
+impl FromCountMap for ProbabilityDistribution {
+    /// Creates a new [ProbabilityDistribution] from a [ValueToCountMap].
+    /// each value becomes a [ProbabilityOutcome] with an empty constraint map, values with a count of 0 are skipped.
+    ///
+    /// # Arguments
+    ///
+    /// * `map` - The [ValueToCountMap] of values to their counts.
+    ///
+    /// # Returns
+    ///
+    /// The new [ProbabilityDistribution].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use std::collections::BTreeMap;
+    /// # use crate::rusted_dice::probability::{FromCountMap, ProbabilityDistribution};
+    /// # use crate::rusted_dice::probability::ToTable;
+    /// let probability_distribution =
+    ///     ProbabilityDistribution::from_value_count_map(BTreeMap::from([(1, 2), (4, 1)]));
+    ///
+    /// assert_eq!(
+    ///     probability_distribution
+    ///         .to_table()
+    ///         .to_string()
+    ///         .replace("\r\n", "\n"),
+    ///     "\
+    ///     +-------+-------+\n\
+    ///     | value | count |\n\
+    ///     +=======+=======+\n\
+    ///     | 1     | 2     |\n\
+    ///     +-------+-------+\n\
+    ///     | 4     | 1     |\n\
+    ///     +-------+-------+\n\
+    ///     ");
+    /// ```
+    fn from_value_count_map(map: ValueToCountMap) -> Self {
+        let mut outcome_counts = BTreeMap::new();
+        for (value, count) in map {
+            if count != 0 {
+                add_outcome_to_map(
+                    &mut outcome_counts,
+                    ProbabilityOutcome::new_with_empty_constraint_map(value),
+                    count,
+                );
+            }
+        }
+        ProbabilityDistribution { outcome_counts }
+    }
+
+    /// Converts a [ProbabilityDistribution] into a [ValueToCountMap].
+    /// outcomes sharing a value but differing in constraints are counted together.
+    ///
+    /// # Returns
+    ///
+    /// The [ValueToCountMap] of values to their counts.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use std::collections::BTreeMap;
+    /// # use crate::rusted_dice::probability::{FromCountMap, ProbabilityDistribution};
+    /// let two_d2 = ProbabilityDistribution::new_multiple_dice(2, 2);
+    /// assert_eq!(
+    ///     two_d2.to_value_count_map(),
+    ///     BTreeMap::from([(2, 1), (3, 2), (4, 1)])
+    /// );
+    /// ```
+    fn to_value_count_map(&self) -> ValueToCountMap {
+        collapse_outcome_to_count_map(&self.outcome_counts)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use crate::probability::{FromCountMap, ProbabilityDistribution, ToTable};
+    use crate::{CountType, ValueType};
+
+    use proptest::prelude::*;
+
+    #[test]
+    fn test_from_value_count_map_empty() {
+        let probability_distribution =
+            ProbabilityDistribution::from_value_count_map(BTreeMap::new());
+        assert_eq!(probability_distribution.outcome_counts.len(), 0);
+        assert!(probability_distribution.to_value_count_map().is_empty());
+    }
+
+    #[test]
+    fn test_from_value_count_map_skips_zero_counts() {
+        let probability_distribution =
+            ProbabilityDistribution::from_value_count_map(BTreeMap::from([(1, 0), (2, 3)]));
+        assert_eq!(
+            probability_distribution.to_value_count_map(),
+            BTreeMap::from([(2, 3)])
+        );
+    }
+
+    #[test]
+    fn test_from_value_count_map_matches_dice() {
+        let two_d6 = ProbabilityDistribution::new_multiple_dice(2, 6);
+        assert_eq!(
+            ProbabilityDistribution::from_value_count_map(two_d6.to_value_count_map()).to_table(),
+            two_d6.to_table()
+        );
+    }
+
+    #[test]
+    fn test_to_value_count_map_merges_constraints() {
+        let dice_one = ProbabilityDistribution::new_dice(2).add_self_value_constraint(1);
+        let dice_two = ProbabilityDistribution::new_dice(2).add_self_value_constraint(2);
+        assert_eq!(
+            (dice_one + dice_two).to_value_count_map(),
+            BTreeMap::from([(2, 1), (3, 2), (4, 1)])
+        );
+    }
+
+    proptest! {
+        #[test]
+        fn test_value_count_map_round_trip(
+            map in proptest::collection::btree_map(any::<ValueType>(), 1..CountType::MAX, 0..20)
+        ) {
+            let probability_distribution = ProbabilityDistribution::from_value_count_map(map.clone());
+            prop_assert_eq!(probability_distribution.to_value_count_map(), map);
+        }
+    }
+}
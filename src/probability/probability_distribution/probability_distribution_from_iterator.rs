@@ -0,0 +1,141 @@
+use crate::probability::{ProbabilityDistribution, ProbabilityOutcome};
+
+use super::add_outcome_to_map;
+
+impl FromIterator<ProbabilityOutcome> for ProbabilityDistribution {
+    /// Collects [ProbabilityOutcome]s into a [ProbabilityDistribution].
+    /// each [ProbabilityOutcome] is counted once, duplicates increment the count.
+    ///
+    /// # Arguments
+    ///
+    /// * `iter` - The [ProbabilityOutcome]s to collect.
+    ///
+    /// # Returns
+    ///
+    /// The new [ProbabilityDistribution].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use crate::rusted_dice::probability::ProbabilityDistribution;
+    /// # use crate::rusted_dice::probability::ProbabilityOutcome;
+    /// let probability_outcome_one = ProbabilityOutcome::new_with_empty_constraint_map(1);
+    /// let probability_outcome_two = ProbabilityOutcome::new_with_empty_constraint_map(2);
+    /// let probability_distribution: ProbabilityDistribution = vec![
+    ///     probability_outcome_one.clone(),
+    ///     probability_outcome_one.clone(),
+    ///     probability_outcome_two.clone(),
+    /// ]
+    /// .into_iter()
+    /// .collect();
+    /// assert_eq!(probability_distribution.outcome_counts.get(&probability_outcome_one), Some(&2));
+    /// assert_eq!(probability_distribution.outcome_counts.get(&probability_outcome_two), Some(&1));
+    /// ```
+    fn from_iter<T: IntoIterator<Item = ProbabilityOutcome>>(iter: T) -> Self {
+        let mut probability_distribution = ProbabilityDistribution::new_empty_distribution();
+        probability_distribution.extend(iter);
+        probability_distribution
+    }
+}
+
+impl Extend<ProbabilityOutcome> for ProbabilityDistribution {
+    /// Adds [ProbabilityOutcome]s to the [ProbabilityDistribution].
+    /// each [ProbabilityOutcome] increments its count by 1.
+    ///
+    /// # Arguments
+    ///
+    /// * `iter` - The [ProbabilityOutcome]s to add.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use crate::rusted_dice::probability::ProbabilityDistribution;
+    /// # use crate::rusted_dice::probability::ProbabilityOutcome;
+    /// let mut probability_distribution = ProbabilityDistribution::new_dice(2);
+    /// probability_distribution.extend(vec![
+    ///     ProbabilityOutcome::new_with_empty_constraint_map(2),
+    ///     ProbabilityOutcome::new_with_empty_constraint_map(3),
+    /// ]);
+    /// let pairs: Vec<_> = probability_distribution.iter_values().collect();
+    /// assert_eq!(pairs, vec![(1, 1), (2, 2), (3, 1)]);
+    /// ```
+    fn extend<T: IntoIterator<Item = ProbabilityOutcome>>(&mut self, iter: T) {
+        for probability_outcome in iter {
+            add_outcome_to_map(&mut self.outcome_counts, probability_outcome, 1);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::constraint_management::Constraint;
+    use crate::probability::{ProbabilityDistribution, ProbabilityOutcome};
+
+    #[test]
+    fn test_collect_empty() {
+        let probability_distribution: ProbabilityDistribution =
+            Vec::<ProbabilityOutcome>::new().into_iter().collect();
+        assert!(probability_distribution.outcome_counts.is_empty());
+    }
+
+    #[test]
+    fn test_collect_counts_duplicates() {
+        let probability_outcome_one = ProbabilityOutcome::new_with_constraints(
+            4,
+            vec![Constraint::new_single_valid_value_constraint(1, 4)],
+        );
+        let probability_outcome_two = ProbabilityOutcome::new_with_empty_constraint_map(4);
+        let probability_distribution = vec![
+            probability_outcome_one.clone(),
+            probability_outcome_one.clone(),
+            probability_outcome_two.clone(),
+        ]
+        .into_iter()
+        .collect::<ProbabilityDistribution>();
+
+        assert_eq!(probability_distribution.outcome_counts.len(), 2);
+        assert_eq!(
+            probability_distribution
+                .outcome_counts
+                .get(&probability_outcome_one),
+            Some(&2)
+        );
+        assert_eq!(
+            probability_distribution
+                .outcome_counts
+                .get(&probability_outcome_two),
+            Some(&1)
+        );
+    }
+
+    #[test]
+    fn test_collect_matches_new_from_many_probability_outcomes() {
+        let probability_outcomes: Vec<ProbabilityOutcome> = (1..=5)
+            .chain(3..=7)
+            .map(ProbabilityOutcome::new_with_empty_constraint_map)
+            .collect();
+        let collected: ProbabilityDistribution = probability_outcomes.clone().into_iter().collect();
+        assert_eq!(
+            collected.outcome_counts,
+            ProbabilityDistribution::new_from_many_probability_outcomes(probability_outcomes)
+                .outcome_counts
+        );
+    }
+
+    #[test]
+    fn test_extend_increments_existing_counts() {
+        let probability_outcome = ProbabilityOutcome::new_with_empty_constraint_map(1);
+        let mut probability_distribution = ProbabilityDistribution::new_dice(3);
+        probability_distribution.extend(vec![
+            probability_outcome.clone(),
+            probability_outcome.clone(),
+        ]);
+        assert_eq!(
+            probability_distribution
+                .outcome_counts
+                .get(&probability_outcome),
+            Some(&3)
+        );
+        assert_eq!(probability_distribution.outcome_counts.len(), 3);
+    }
+}
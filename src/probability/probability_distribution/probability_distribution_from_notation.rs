@@ -0,0 +1,571 @@
+use std::error::Error;
+use std::fmt;
+
+use crate::probability::pool_aggregator::{KeepHighest, KeepLowest};
+use crate::probability::ProbabilityDistribution;
+use crate::{CountType, ValueType};
+
+/// The maximum number of times each dice can explode when parsing exploding dice notation.
+pub const DEFAULT_EXPLOSION_CAP: u32 = 1;
+
+/// The maximum number of sorted combinations of faces a keep or drop dice pool can have when parsing dice notation.
+pub const MAX_POOL_COMBINATIONS: CountType = 1_000_000;
+
+/// The error returned by [ProbabilityDistribution::from_notation] when the notation can not be parsed.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum NotationError {
+    /// the notation has no terms.
+    Empty,
+    /// a term is not a number or a dice roll.
+    InvalidTerm(String),
+    /// a dice roll ends in a suffix that is not supported.
+    UnknownSuffix(String),
+    /// a keep or drop dice pool is too large to calculate.
+    PoolTooLarge(String),
+}
+
+impl fmt::Display for NotationError {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            NotationError::Empty => write!(formatter, "Dice notation is empty."),
+            NotationError::InvalidTerm(term) => {
+                write!(formatter, "Invalid dice notation term \"{}\".", term)
+            }
+            NotationError::UnknownSuffix(suffix) => {
+                write!(formatter, "Unknown dice notation suffix \"{}\".", suffix)
+            }
+            NotationError::PoolTooLarge(term) => {
+                write!(
+                    formatter,
+                    "Dice pool \"{}\" is too large to calculate.",
+                    term
+                )
+            }
+        }
+    }
+}
+
+impl Error for NotationError {}
+
+fn parse_number<T: std::str::FromStr>(text: &str, term: &str) -> Result<T, NotationError> {
+    text.parse()
+        .map_err(|_| NotationError::InvalidTerm(term.to_string()))
+}
+
+/// a helper function to check if M, N sided dice have more than `limit` sorted combinations of faces,
+/// the number of sorted combinations is `C(m + n - 1, m)`.
+fn pool_combinations_exceed(
+    number_of_dice: u16,
+    number_of_sides: ValueType,
+    limit: CountType,
+) -> bool {
+    let faces = u128::from(number_of_sides.unsigned_abs());
+    if faces == 0 {
+        return false;
+    }
+    let n = u128::from(number_of_dice) + faces - 1;
+    let k = u128::from(number_of_dice).min(faces - 1);
+    let mut combinations: u128 = 1;
+    for i in 0..k {
+        combinations = combinations * (n - i) / (i + 1);
+        if combinations > u128::from(limit) {
+            return true;
+        }
+    }
+    false
+}
+
+fn parse_dice_suffix(
+    number_of_dice: u16,
+    number_of_sides: ValueType,
+    suffix: &str,
+    term: &str,
+) -> Result<ProbabilityDistribution, NotationError> {
+    if suffix.is_empty() {
+        return Ok(ProbabilityDistribution::new_multiple_dice(
+            number_of_dice,
+            number_of_sides,
+        ));
+    }
+
+    let digits_start = suffix
+        .find(|character: char| character.is_ascii_digit())
+        .unwrap_or(suffix.len());
+    let (kind, digits) = suffix.split_at(digits_start);
+    if kind == "!" || kind == "!!" {
+        let lowest_exploding_face: ValueType = if digits.is_empty() {
+            number_of_sides
+        } else {
+            parse_number(digits, term)?
+        };
+        if !(1..=number_of_sides).contains(&lowest_exploding_face) {
+            return Err(NotationError::InvalidTerm(term.to_string()));
+        }
+        let explode_faces: Vec<ValueType> = (lowest_exploding_face..=number_of_sides).collect();
+        let single_dice = ProbabilityDistribution::new_exploding_on(
+            number_of_sides,
+            &explode_faces,
+            DEFAULT_EXPLOSION_CAP,
+        );
+        if number_of_dice == 0 {
+            return Ok(ProbabilityDistribution::new_empty_distribution());
+        }
+        let mut combined_probability_distribution = single_dice.clone();
+        for _ in 1..number_of_dice {
+            combined_probability_distribution += single_dice.clone();
+        }
+        return Ok(combined_probability_distribution);
+    }
+
+    let amount: u16 = if digits.is_empty() {
+        1
+    } else {
+        parse_number(digits, term)?
+    };
+
+    let (keep_highest, keep) = match kind {
+        "kh" => (true, amount),
+        "kl" => (false, amount),
+        "dh" => (false, number_of_dice.saturating_sub(amount)),
+        "dl" => (true, number_of_dice.saturating_sub(amount)),
+        _ => return Err(NotationError::UnknownSuffix(suffix.to_string())),
+    };
+    if keep >= number_of_dice {
+        return Ok(ProbabilityDistribution::new_multiple_dice(
+            number_of_dice,
+            number_of_sides,
+        ));
+    }
+    if CountType::checked_pow(number_of_sides as CountType, number_of_dice.into()).is_none() {
+        return Err(NotationError::PoolTooLarge(term.to_string()));
+    }
+
+    Ok(match (keep_highest, keep) {
+        (true, 1) => ProbabilityDistribution::new_pool_max(number_of_dice, number_of_sides),
+        (false, 1) => ProbabilityDistribution::new_pool_min(number_of_dice, number_of_sides),
+        _ => {
+            if pool_combinations_exceed(number_of_dice, number_of_sides, MAX_POOL_COMBINATIONS) {
+                return Err(NotationError::PoolTooLarge(term.to_string()));
+            }
+            if keep_highest {
+                ProbabilityDistribution::new_pool(
+                    number_of_dice,
+                    number_of_sides,
+                    &KeepHighest(keep),
+                )
+            } else {
+                ProbabilityDistribution::new_pool(
+                    number_of_dice,
+                    number_of_sides,
+                    &KeepLowest(keep),
+                )
+            }
+        }
+    })
+}
+
+fn parse_term(term: &str) -> Result<ProbabilityDistribution, NotationError> {
+    let Some((number_of_dice, rest)) = term.split_once('d') else {
+        return Ok(ProbabilityDistribution::from(parse_number::<ValueType>(
+            term, term,
+        )?));
+    };
+
+    let number_of_dice: u16 = if number_of_dice.is_empty() {
+        1
+    } else {
+        parse_number(number_of_dice, term)?
+    };
+    let sides_end = rest
+        .find(|character: char| !character.is_ascii_digit())
+        .unwrap_or(rest.len());
+    let (number_of_sides, suffix) = rest.split_at(sides_end);
+    if number_of_sides.is_empty() {
+        return Err(NotationError::InvalidTerm(term.to_string()));
+    }
+
+    parse_dice_suffix(
+        number_of_dice,
+        parse_number(number_of_sides, term)?,
+        suffix,
+        term,
+    )
+}
+
+impl ProbabilityDistribution {
+    /// Creates a new [ProbabilityDistribution] from dice notation, for example `"2d6 + 3"`.
+    ///
+    /// the notation is a list of terms joined by `+` or `-`, whitespace is ignored.
+    /// a term is either a whole number, or a dice roll `MdN` of M, N sided dice where M defaults to 1.
+    /// a dice roll can end in one suffix, where X defaults to 1.
+    ///
+    /// * `khX` - keep the highest X dice.
+    /// * `klX` - keep the lowest X dice.
+    /// * `dhX` - drop the highest X dice.
+    /// * `dlX` - drop the lowest X dice.
+    ///
+    /// * `!` - each dice explodes on its highest face.
+    /// * `!X` - each dice explodes on X or higher, X has to be a face of the dice.
+    /// * `!!` - each dice compounds on its highest face.
+    /// * `!!X` - each dice compounds on X or higher, X has to be a face of the dice.
+    ///
+    /// keeping more dice than are rolled keeps every dice.
+    /// keeping or dropping dice fails with [NotationError::PoolTooLarge] when the number of ordered rolls
+    /// does not fit in a [CountType], or when keeping more than one dice and the pool has more than
+    /// [MAX_POOL_COMBINATIONS] sorted combinations of faces.
+    /// exploding and compounding dice explode at most [DEFAULT_EXPLOSION_CAP] times,
+    /// see [ProbabilityDistribution::new_exploding_on].
+    /// a compounding dice adds its explosions into a single dice instead of rolling extra dice,
+    /// as the notation only sums dice it has the same [ProbabilityDistribution] as an exploding dice.
+    ///
+    /// # Arguments
+    ///
+    /// * `notation` - The dice notation to parse.
+    ///
+    /// # Returns
+    ///
+    /// The new [ProbabilityDistribution], or a [NotationError] if the notation can not be parsed.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use crate::rusted_dice::probability::{NotationError, ProbabilityDistribution, ToTable};
+    /// let probability_distribution = ProbabilityDistribution::from_notation("2d4kh1 - 1").unwrap();
+    ///
+    /// assert_eq!(
+    ///     probability_distribution
+    ///         .to_table()
+    ///         .to_string()
+    ///         .replace("\r\n", "\n"),
+    ///     "\
+    ///     +-------+-------+\n\
+    ///     | value | count |\n\
+    ///     +=======+=======+\n\
+    ///     | 0     | 1     |\n\
+    ///     +-------+-------+\n\
+    ///     | 1     | 3     |\n\
+    ///     +-------+-------+\n\
+    ///     | 2     | 5     |\n\
+    ///     +-------+-------+\n\
+    ///     | 3     | 7     |\n\
+    ///     +-------+-------+\n\
+    ///     ");
+    ///
+    /// assert_eq!(
+    ///     ProbabilityDistribution::from_notation("4d6x").unwrap_err(),
+    ///     NotationError::UnknownSuffix("x".to_string())
+    /// );
+    /// ```
+    pub fn from_notation(notation: &str) -> Result<Self, NotationError> {
+        let notation: String = notation
+            .chars()
+            .filter(|character| !character.is_whitespace())
+            .collect();
+        if notation.is_empty() {
+            return Err(NotationError::Empty);
+        }
+
+        let mut result: Option<ProbabilityDistribution> = None;
+        let mut rest = notation.as_str();
+        while !rest.is_empty() {
+            let is_negative = rest.starts_with('-');
+            if rest.starts_with(['+', '-']) {
+                rest = &rest[1..];
+            }
+            let term_end = rest.find(['+', '-']).unwrap_or(rest.len());
+            let (term, remaining) = rest.split_at(term_end);
+            if term.is_empty() {
+                return Err(NotationError::InvalidTerm(notation.clone()));
+            }
+            rest = remaining;
+
+            let term = parse_term(term)?;
+            result = Some(match (result, is_negative) {
+                (None, false) => term,
+                (None, true) => -term,
+                (Some(result), false) => result + term,
+                (Some(result), true) => result - term,
+            });
+        }
+        result.ok_or(NotationError::Empty)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{pool_combinations_exceed, NotationError, DEFAULT_EXPLOSION_CAP};
+    use crate::probability::pool_aggregator::{KeepHighest, KeepLowest};
+    use crate::probability::{ProbabilityDistribution, ToTable};
+    use crate::{CountType, ValueType};
+
+    fn assert_notation(notation: &str, expected: ProbabilityDistribution) {
+        assert_eq!(
+            ProbabilityDistribution::from_notation(notation)
+                .unwrap()
+                .to_table(),
+            expected.to_table(),
+            "{}",
+            notation
+        );
+    }
+
+    #[test]
+    fn test_from_notation_empty() {
+        assert_eq!(
+            ProbabilityDistribution::from_notation("").unwrap_err(),
+            NotationError::Empty
+        );
+        assert_eq!(
+            ProbabilityDistribution::from_notation("  ").unwrap_err(),
+            NotationError::Empty
+        );
+    }
+
+    #[test]
+    fn test_from_notation_constant() {
+        assert_notation("7", ProbabilityDistribution::from(7));
+        assert_notation("-7", ProbabilityDistribution::from(-7));
+    }
+
+    #[test]
+    fn test_from_notation_dice() {
+        assert_notation("d6", ProbabilityDistribution::new_dice(6));
+        assert_notation("1d6", ProbabilityDistribution::new_dice(6));
+        assert_notation("3d6", ProbabilityDistribution::new_multiple_dice(3, 6));
+        assert_notation("0d6", ProbabilityDistribution::new_empty_distribution());
+    }
+
+    #[test]
+    fn test_from_notation_arithmetic() {
+        assert_notation(
+            " 2d6 + 1d4 - 3 ",
+            ProbabilityDistribution::new_multiple_dice(2, 6) + ProbabilityDistribution::new_dice(4)
+                - 3,
+        );
+        assert_notation("-d4+2", -ProbabilityDistribution::new_dice(4) + 2);
+    }
+
+    #[test]
+    fn test_from_notation_keep_highest() {
+        assert_notation(
+            "4d6kh3",
+            ProbabilityDistribution::new_pool(4, 6, &KeepHighest(3)),
+        );
+        assert_notation("2d20kh", ProbabilityDistribution::new_pool_max(2, 20));
+    }
+
+    #[test]
+    fn test_from_notation_keep_lowest() {
+        assert_notation("4d6kl1", ProbabilityDistribution::new_pool_min(4, 6));
+        assert_notation(
+            "4d6kl2",
+            ProbabilityDistribution::new_pool(4, 6, &KeepLowest(2)),
+        );
+    }
+
+    #[test]
+    fn test_from_notation_drop() {
+        assert_notation(
+            "4d6dl1",
+            ProbabilityDistribution::new_pool(4, 6, &KeepHighest(3)),
+        );
+        assert_notation(
+            "4d6dh1",
+            ProbabilityDistribution::new_pool(4, 6, &KeepLowest(3)),
+        );
+        assert_notation(
+            "4d6dl",
+            ProbabilityDistribution::new_pool(4, 6, &KeepHighest(3)),
+        );
+    }
+
+    #[test]
+    fn test_from_notation_keep_more_than_rolled() {
+        assert_notation("4d6kh5", ProbabilityDistribution::new_multiple_dice(4, 6));
+        assert_notation("4d6kl9", ProbabilityDistribution::new_multiple_dice(4, 6));
+    }
+
+    #[test]
+    fn test_from_notation_keep_one_large_pool() {
+        assert_notation("10d20kh1", ProbabilityDistribution::new_pool_max(10, 20));
+        assert_notation("10d20dl9", ProbabilityDistribution::new_pool_max(10, 20));
+        assert_notation("10d20kl1", ProbabilityDistribution::new_pool_min(10, 20));
+        assert_notation("10d20dh9", ProbabilityDistribution::new_pool_min(10, 20));
+    }
+
+    #[test]
+    fn test_from_notation_pool_too_large() {
+        for notation in [
+            "100d100kh1",
+            "100d100kl1",
+            "100d100dl1",
+            "20d20kh2",
+            "1 + 20d20dh1",
+        ] {
+            assert!(
+                matches!(
+                    ProbabilityDistribution::from_notation(notation),
+                    Err(NotationError::PoolTooLarge(_))
+                ),
+                "{}",
+                notation
+            );
+        }
+        assert_eq!(
+            ProbabilityDistribution::from_notation("100d100kh1").unwrap_err(),
+            NotationError::PoolTooLarge("100d100kh1".to_string())
+        );
+    }
+
+    #[test]
+    fn test_pool_combinations_exceed() {
+        assert!(!pool_combinations_exceed(4, 6, 126));
+        assert!(pool_combinations_exceed(4, 6, 125));
+        assert!(!pool_combinations_exceed(0, 6, 0));
+        assert!(!pool_combinations_exceed(4, 0, 0));
+        assert!(!pool_combinations_exceed(3, 1, 1));
+        assert!(pool_combinations_exceed(
+            u16::MAX,
+            ValueType::MAX,
+            CountType::MAX
+        ));
+    }
+
+    #[test]
+    fn test_from_notation_unknown_suffix() {
+        assert_eq!(
+            ProbabilityDistribution::from_notation("4d6kx3").unwrap_err(),
+            NotationError::UnknownSuffix("kx3".to_string())
+        );
+        assert_eq!(
+            ProbabilityDistribution::from_notation("4d6k").unwrap_err(),
+            NotationError::UnknownSuffix("k".to_string())
+        );
+    }
+
+    #[test]
+    fn test_from_notation_invalid_term() {
+        for notation in [
+            "4d", "xd6", "d", "2d6++1", "2d6+", "4d6kh3x", "abc", "70000d6",
+        ] {
+            assert!(
+                matches!(
+                    ProbabilityDistribution::from_notation(notation),
+                    Err(NotationError::InvalidTerm(_))
+                ),
+                "{}",
+                notation
+            );
+        }
+    }
+
+    #[test]
+    fn test_notation_error_display() {
+        assert_eq!(NotationError::Empty.to_string(), "Dice notation is empty.");
+        assert_eq!(
+            NotationError::InvalidTerm("4d".to_string()).to_string(),
+            "Invalid dice notation term \"4d\"."
+        );
+        assert_eq!(
+            NotationError::UnknownSuffix("x".to_string()).to_string(),
+            "Unknown dice notation suffix \"x\"."
+        );
+        assert_eq!(
+            NotationError::PoolTooLarge("100d100kh1".to_string()).to_string(),
+            "Dice pool \"100d100kh1\" is too large to calculate."
+        );
+    }
+
+    #[test]
+    fn test_from_notation_exploding() {
+        assert_notation(
+            "1d6!",
+            ProbabilityDistribution::new_exploding_on(6, &[6], DEFAULT_EXPLOSION_CAP),
+        );
+        assert_notation(
+            "d6!5",
+            ProbabilityDistribution::new_exploding_on(6, &[5, 6], DEFAULT_EXPLOSION_CAP),
+        );
+        assert_notation(
+            "2d4! + 1",
+            ProbabilityDistribution::new_exploding_on(4, &[4], DEFAULT_EXPLOSION_CAP)
+                + ProbabilityDistribution::new_exploding_on(4, &[4], DEFAULT_EXPLOSION_CAP)
+                + 1,
+        );
+        assert_notation("0d6!", ProbabilityDistribution::new_empty_distribution());
+    }
+
+    #[test]
+    fn test_from_notation_compounding() {
+        assert_notation(
+            "1d6!!",
+            ProbabilityDistribution::new_exploding_on(6, &[6], DEFAULT_EXPLOSION_CAP),
+        );
+        assert_notation(
+            "d6!!5",
+            ProbabilityDistribution::new_exploding_on(6, &[5, 6], DEFAULT_EXPLOSION_CAP),
+        );
+        assert_notation(
+            "3d6!!",
+            ProbabilityDistribution::from_notation("3d6!").unwrap(),
+        );
+        assert_notation("0d6!!", ProbabilityDistribution::new_empty_distribution());
+    }
+
+    #[test]
+    fn test_from_notation_exploding_one_level() {
+        let exploding_d6 = ProbabilityDistribution::from_notation("1d6!").unwrap();
+        assert_eq!(
+            exploding_d6.to_table().to_string().replace("\r\n", "\n"),
+            "\
+            +-------+-------+\n\
+            | value | count |\n\
+            +=======+=======+\n\
+            | 1     | 6     |\n\
+            +-------+-------+\n\
+            | 2     | 6     |\n\
+            +-------+-------+\n\
+            | 3     | 6     |\n\
+            +-------+-------+\n\
+            | 4     | 6     |\n\
+            +-------+-------+\n\
+            | 5     | 6     |\n\
+            +-------+-------+\n\
+            | 7     | 1     |\n\
+            +-------+-------+\n\
+            | 8     | 1     |\n\
+            +-------+-------+\n\
+            | 9     | 1     |\n\
+            +-------+-------+\n\
+            | 10    | 1     |\n\
+            +-------+-------+\n\
+            | 11    | 1     |\n\
+            +-------+-------+\n\
+            | 12    | 1     |\n\
+            +-------+-------+\n\
+            "
+        );
+    }
+
+    #[test]
+    fn test_from_notation_exploding_invalid() {
+        for notation in ["1d6!7", "1d6!0", "1d6!5x", "1d6!!7", "1d6!!0"] {
+            assert!(
+                matches!(
+                    ProbabilityDistribution::from_notation(notation),
+                    Err(NotationError::InvalidTerm(_))
+                ),
+                "{}",
+                notation
+            );
+        }
+        assert_eq!(
+            ProbabilityDistribution::from_notation("1d6!!!").unwrap_err(),
+            NotationError::UnknownSuffix("!!!".to_string())
+        );
+        assert_eq!(
+            ProbabilityDistribution::from_notation("1d6!x").unwrap_err(),
+            NotationError::UnknownSuffix("!x".to_string())
+        );
+    }
+}
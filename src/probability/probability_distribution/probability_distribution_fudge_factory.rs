@@ -0,0 +1,89 @@
+use crate::probability::{ProbabilityDistribution, ProbabilityOutcome};
+
+impl ProbabilityDistribution {
+    /// Creates a new [ProbabilityDistribution] of rolling M Fudge dice, also called FATE dice, and summing them.
+    /// each Fudge dice has the faces -1, 0 and 1, each with a count of 1.
+    ///
+    /// for Zero m, the [ProbabilityDistribution] is the constant 0 with a count of 1, the sum of no dice,
+    /// unlike [ProbabilityDistribution::new_multiple_dice] which has no [ProbabilityOutcome]s for Zero m.
+    ///
+    /// # Arguments
+    ///
+    /// * `number_of_dice` - [u16] The the number of dice to roll.
+    ///
+    /// # Returns
+    ///
+    /// The new [ProbabilityDistribution].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use crate::rusted_dice::probability::ProbabilityDistribution;
+    /// let four_df = ProbabilityDistribution::new_fudge_dice(4);
+    /// assert_eq!(four_df.coefficients(), (-4, vec![1, 4, 10, 16, 19, 16, 10, 4, 1]));
+    /// assert_eq!(four_df.total_outcome_count(), 81);
+    ///
+    /// let no_dice = ProbabilityDistribution::new_fudge_dice(0);
+    /// assert_eq!(no_dice.coefficients(), (0, vec![1]));
+    /// ```
+    pub fn new_fudge_dice(number_of_dice: u16) -> ProbabilityDistribution {
+        let single_dice = ProbabilityDistribution::new_from_many_probability_outcomes(
+            (-1..=1).map(ProbabilityOutcome::new_with_empty_constraint_map),
+        );
+        let mut combined_probability_distribution = ProbabilityDistribution::new_constant(0);
+
+        for _ in 0..number_of_dice {
+            combined_probability_distribution += single_dice.clone();
+        }
+
+        combined_probability_distribution
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::probability::ProbabilityDistribution;
+    use crate::ValueType;
+
+    #[test]
+    fn test_new_fudge_dice_zero_dice() {
+        let probability_distribution = ProbabilityDistribution::new_fudge_dice(0);
+        assert_eq!(probability_distribution.len(), 1);
+        assert_eq!(probability_distribution.count_of(0), 1);
+    }
+
+    #[test]
+    fn test_new_fudge_dice_one_dice() {
+        let probability_distribution = ProbabilityDistribution::new_fudge_dice(1);
+        assert_eq!(probability_distribution.coefficients(), (-1, vec![1, 1, 1]));
+    }
+
+    #[test]
+    fn test_new_fudge_dice_four_dice() {
+        let four_df = ProbabilityDistribution::new_fudge_dice(4);
+
+        assert_eq!(four_df.min_value(), Some(-4));
+        assert_eq!(four_df.max_value(), Some(4));
+        assert_eq!(four_df.count_of(-4), 1);
+        assert_eq!(four_df.count_of(-3), 4);
+        assert_eq!(four_df.count_of(-2), 10);
+        assert_eq!(four_df.count_of(-1), 16);
+        assert_eq!(four_df.count_of(0), 19);
+        assert_eq!(four_df.count_of(1), 16);
+        assert_eq!(four_df.count_of(2), 10);
+        assert_eq!(four_df.count_of(3), 4);
+        assert_eq!(four_df.count_of(4), 1);
+        assert_eq!(four_df.total_outcome_count(), 81);
+        assert_eq!(four_df.mean(), 0.0);
+    }
+
+    #[test]
+    fn test_new_fudge_dice_matches_shifted_d3() {
+        for number_of_dice in 1..=6 {
+            let fudge = ProbabilityDistribution::new_fudge_dice(number_of_dice);
+            let shifted = ProbabilityDistribution::new_multiple_dice(number_of_dice, 3)
+                - 2 * ValueType::from(number_of_dice);
+            assert_eq!(fudge.outcome_counts, shifted.outcome_counts);
+        }
+    }
+}
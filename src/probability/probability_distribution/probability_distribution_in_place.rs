@@ -0,0 +1,216 @@
+use crate::probability::{ProbabilityDistribution, ProbabilityOutcome};
+use crate::{CountType, ValueType};
+
+use super::add_outcome_to_map;
+
+impl ProbabilityDistribution {
+    /// Multiplies every count of the [ProbabilityDistribution] by `factor`, without reallocating.
+    /// a `factor` of 0 removes every outcome, leaving an empty [ProbabilityDistribution].
+    ///
+    /// # Arguments
+    ///
+    /// * `factor` - The [CountType] to multiply every count by.
+    ///
+    /// # Panics
+    ///
+    /// Panics in debug builds if a count overflows [CountType].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use crate::rusted_dice::probability::ProbabilityDistribution;
+    /// let mut dice = ProbabilityDistribution::new_dice(6);
+    /// dice.scale_counts_in_place(3);
+    /// assert_eq!(dice.coefficients(), (1, vec![3, 3, 3, 3, 3, 3]));
+    ///
+    /// dice.scale_counts_in_place(0);
+    /// assert_eq!(dice.outcome_counts.len(), 0);
+    /// ```
+    pub fn scale_counts_in_place(&mut self, factor: CountType) {
+        if factor == 0 {
+            self.outcome_counts.clear();
+            return;
+        }
+        for count in self.outcome_counts.values_mut() {
+            *count *= factor;
+        }
+    }
+
+    /// Applies `function` to the value of every outcome of the [ProbabilityDistribution], keeping the constraint maps.
+    /// outcomes whose value is unchanged stay in place, only the changed outcomes are removed and inserted again,
+    /// outcomes which end up equal are merged and their counts added.
+    ///
+    /// # Arguments
+    ///
+    /// * `function` - The function mapping each old [ValueType] to its new [ValueType].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use crate::rusted_dice::probability::ProbabilityDistribution;
+    /// let mut dice = ProbabilityDistribution::new_dice(6);
+    /// dice.map_values_in_place(|value| value.min(4));
+    /// assert_eq!(dice.coefficients(), (1, vec![1, 1, 1, 3]));
+    /// ```
+    pub fn map_values_in_place<F: Fn(ValueType) -> ValueType>(&mut self, function: F) {
+        let changed: Vec<(ProbabilityOutcome, ValueType)> = self
+            .outcome_counts
+            .keys()
+            .filter_map(|outcome| {
+                let new_value = function(outcome.value);
+                (new_value != outcome.value).then(|| (outcome.clone(), new_value))
+            })
+            .collect();
+
+        let mut moved = Vec::with_capacity(changed.len());
+        for (outcome, new_value) in changed {
+            if let Some(count) = self.outcome_counts.remove(&outcome) {
+                moved.push((
+                    ProbabilityOutcome {
+                        value: new_value,
+                        constraint_map: outcome.constraint_map,
+                    },
+                    count,
+                ));
+            }
+        }
+        for (outcome, count) in moved {
+            add_outcome_to_map(&mut self.outcome_counts, outcome, count);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use crate::constraint_management::Constraint;
+    use crate::probability::{ProbabilityDistribution, ProbabilityOutcome};
+
+    fn tracked_two_d4() -> ProbabilityDistribution {
+        ProbabilityDistribution::new_dice(4).add_self_value_constraint(1)
+            + ProbabilityDistribution::new_dice(4).add_self_value_constraint(2)
+    }
+
+    #[test]
+    fn test_scale_counts_in_place_empty() {
+        let mut probability_distribution = ProbabilityDistribution::new_empty_distribution();
+        probability_distribution.scale_counts_in_place(5);
+        assert_eq!(probability_distribution.outcome_counts.len(), 0);
+    }
+
+    #[test]
+    fn test_scale_counts_in_place_one() {
+        let mut probability_distribution = tracked_two_d4();
+        probability_distribution.scale_counts_in_place(1);
+        assert_eq!(
+            probability_distribution.outcome_counts,
+            tracked_two_d4().outcome_counts
+        );
+    }
+
+    #[test]
+    fn test_scale_counts_in_place_zero() {
+        let mut probability_distribution = ProbabilityDistribution::new_multiple_dice(2, 6);
+        probability_distribution.scale_counts_in_place(0);
+        assert_eq!(probability_distribution.outcome_counts.len(), 0);
+    }
+
+    #[test]
+    fn test_scale_counts_in_place_matches_adding_weighted_zero() {
+        for factor in [2, 3, 17] {
+            let mut probability_distribution = tracked_two_d4();
+            probability_distribution.scale_counts_in_place(factor);
+            assert_eq!(
+                probability_distribution.outcome_counts,
+                (tracked_two_d4() + ProbabilityDistribution::from_coefficients(0, &[factor]))
+                    .outcome_counts
+            );
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "attempt to multiply with overflow")]
+    fn test_scale_counts_in_place_overflow() {
+        let mut probability_distribution = ProbabilityDistribution::new_dice(6);
+        probability_distribution.scale_counts_in_place(u64::MAX);
+        probability_distribution.scale_counts_in_place(2);
+    }
+
+    #[test]
+    fn test_map_values_in_place_empty() {
+        let mut probability_distribution = ProbabilityDistribution::new_empty_distribution();
+        probability_distribution.map_values_in_place(|value| value + 1);
+        assert_eq!(probability_distribution.outcome_counts.len(), 0);
+    }
+
+    #[test]
+    fn test_map_values_in_place_identity() {
+        let mut probability_distribution = tracked_two_d4();
+        probability_distribution.map_values_in_place(|value| value);
+        assert_eq!(
+            probability_distribution.outcome_counts,
+            tracked_two_d4().outcome_counts
+        );
+    }
+
+    #[test]
+    fn test_map_values_in_place_matches_arithmetic() {
+        let mut added = tracked_two_d4();
+        added.map_values_in_place(|value| value + 3);
+        assert_eq!(added.outcome_counts, (tracked_two_d4() + 3).outcome_counts);
+
+        let mut multiplied = ProbabilityDistribution::new_multiple_dice(3, 4);
+        multiplied.map_values_in_place(|value| value * -2);
+        assert_eq!(
+            multiplied.outcome_counts,
+            (ProbabilityDistribution::new_multiple_dice(3, 4) * -2).outcome_counts
+        );
+    }
+
+    #[test]
+    fn test_map_values_in_place_matches_relabel() {
+        let mapping = HashMap::from([(1, 2), (2, 1), (3, 8), (8, 2)]);
+        let mut probability_distribution = tracked_two_d4();
+        probability_distribution
+            .map_values_in_place(|value| *mapping.get(&value).unwrap_or(&value));
+        assert_eq!(
+            probability_distribution.outcome_counts,
+            tracked_two_d4().relabel(&mapping).outcome_counts
+        );
+    }
+
+    #[test]
+    fn test_map_values_in_place_collisions() {
+        let constrained = ProbabilityOutcome::new_with_constraints(
+            6,
+            vec![Constraint::new_single_valid_value_constraint(1, 6)],
+        );
+        let mut probability_distribution =
+            ProbabilityDistribution::new_from_many_probability_outcomes(vec![
+                ProbabilityOutcome::new_with_empty_constraint_map(1),
+                ProbabilityOutcome::new_with_empty_constraint_map(5),
+                ProbabilityOutcome::new_with_empty_constraint_map(6),
+                ProbabilityOutcome::new_with_empty_constraint_map(6),
+                constrained,
+            ]);
+        probability_distribution.map_values_in_place(|value| value.min(4));
+
+        assert_eq!(
+            probability_distribution
+                .outcome_counts
+                .get(&ProbabilityOutcome::new_with_empty_constraint_map(4)),
+            Some(&3)
+        );
+        assert_eq!(
+            probability_distribution
+                .outcome_counts
+                .get(&ProbabilityOutcome::new_with_constraints(
+                    4,
+                    vec![Constraint::new_single_valid_value_constraint(1, 6)],
+                )),
+            Some(&1)
+        );
+        assert_eq!(probability_distribution.outcome_counts.len(), 3);
+    }
+}
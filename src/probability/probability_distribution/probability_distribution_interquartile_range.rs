@@ -0,0 +1,85 @@
+use crate::probability::ProbabilityDistribution;
+use crate::ValueType;
+
+impl ProbabilityDistribution {
+    /// Returns the 25th and 75th percentile values of the [ProbabilityDistribution].
+    /// outcomes sharing a value but differing in constraints are counted together.
+    ///
+    /// percentiles use the nearest-rank rule of [ProbabilityDistribution::percentile].
+    ///
+    /// # Arguments
+    ///
+    /// * `self` - The [ProbabilityDistribution] to get the interquartile range from.
+    ///
+    /// # Returns
+    ///
+    /// A tuple of the lower and upper quartile values,
+    /// or `None` if the [ProbabilityDistribution] is empty.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use crate::rusted_dice::probability::ProbabilityDistribution;
+    /// let dice = ProbabilityDistribution::new_dice(8);
+    /// assert_eq!(dice.interquartile_range(), Some((2, 6)));
+    ///
+    /// let empty = ProbabilityDistribution::new_empty_distribution();
+    /// assert_eq!(empty.interquartile_range(), None);
+    /// ```
+    pub fn interquartile_range(&self) -> Option<(ValueType, ValueType)> {
+        Some((self.percentile(0.25)?, self.percentile(0.75)?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::probability::{ProbabilityDistribution, ProbabilityOutcome};
+
+    #[test]
+    fn test_interquartile_range_empty() {
+        let probability_distribution = ProbabilityDistribution::new_empty_distribution();
+        assert_eq!(probability_distribution.interquartile_range(), None);
+    }
+
+    #[test]
+    fn test_interquartile_range_single_outcome() {
+        let probability_distribution = ProbabilityDistribution::new_from_single_probability_outcome(
+            ProbabilityOutcome::new_with_empty_constraint_map(-3),
+        );
+        assert_eq!(
+            probability_distribution.interquartile_range(),
+            Some((-3, -3))
+        );
+    }
+
+    #[test]
+    fn test_interquartile_range_d100() {
+        let (lower, upper) = ProbabilityDistribution::new_dice(100)
+            .interquartile_range()
+            .unwrap();
+        assert_eq!((lower, upper), (25, 75));
+    }
+
+    #[test]
+    fn test_interquartile_range_three_d6() {
+        // 3d6 has 216 outcomes, ranks 54 and 162 fall in 8 and 13
+        let probability_distribution = ProbabilityDistribution::new_multiple_dice(3, 6);
+        assert_eq!(
+            probability_distribution.interquartile_range(),
+            Some((8, 13))
+        );
+    }
+
+    #[test]
+    fn test_interquartile_range_skewed() {
+        let probability_distribution = ProbabilityDistribution::new_from_many_probability_outcomes(
+            [
+                vec![ProbabilityOutcome::new_with_empty_constraint_map(1); 6],
+                vec![ProbabilityOutcome::new_with_empty_constraint_map(2); 1],
+                vec![ProbabilityOutcome::new_with_empty_constraint_map(100); 1],
+            ]
+            .concat(),
+        );
+        assert_eq!(probability_distribution.interquartile_range(), Some((1, 1)));
+    }
+}
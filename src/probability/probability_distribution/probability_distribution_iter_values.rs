@@ -0,0 +1,78 @@
+use crate::probability::ProbabilityDistribution;
+use crate::{CountType, ValueType};
+
+use super::collapse_outcome_to_count_map;
+
+impl ProbabilityDistribution {
+    /// Iterates over the value and count pairs of the [ProbabilityDistribution].
+    /// outcomes sharing a value but differing in constraints are counted together.
+    ///
+    /// # Returns
+    ///
+    /// An [Iterator] of ([ValueType], [CountType]) pairs in ascending value order.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use crate::rusted_dice::probability::ProbabilityDistribution;
+    /// let d3 = ProbabilityDistribution::new_dice(3);
+    /// let d3_constrained = ProbabilityDistribution::new_dice(3).add_self_value_constraint(1);
+    /// let pairs: Vec<_> = (d3 + d3_constrained).iter_values().collect();
+    /// assert_eq!(pairs, vec![(2, 1), (3, 2), (4, 3), (5, 2), (6, 1)]);
+    /// ```
+    pub fn iter_values(&self) -> impl Iterator<Item = (ValueType, CountType)> {
+        collapse_outcome_to_count_map(&self.outcome_counts).into_iter()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use crate::constraint_management::Constraint;
+    use crate::probability::{ProbabilityDistribution, ProbabilityOutcome};
+
+    #[test]
+    fn test_iter_values_empty() {
+        let probability_distribution = ProbabilityDistribution::new_empty_distribution();
+        assert_eq!(probability_distribution.iter_values().count(), 0);
+    }
+
+    #[test]
+    fn test_iter_values_ascending_order() {
+        let probability_distribution = ProbabilityDistribution::new_dice(4) * -1 + 10;
+        let pairs: Vec<_> = probability_distribution.iter_values().collect();
+        assert_eq!(pairs, vec![(6, 1), (7, 1), (8, 1), (9, 1)]);
+    }
+
+    #[test]
+    fn test_iter_values_merges_constrained_duplicates() {
+        let mut outcome_counts = BTreeMap::new();
+        outcome_counts.insert(ProbabilityOutcome::new_with_empty_constraint_map(3), 2);
+        outcome_counts.insert(
+            ProbabilityOutcome::new_with_constraints(
+                3,
+                vec![Constraint::new_single_valid_value_constraint(1, 1)],
+            ),
+            5,
+        );
+        outcome_counts.insert(
+            ProbabilityOutcome::new_with_constraints(
+                3,
+                vec![Constraint::new_single_valid_value_constraint(1, 2)],
+            ),
+            7,
+        );
+        outcome_counts.insert(
+            ProbabilityOutcome::new_with_constraints(
+                -1,
+                vec![Constraint::new_single_valid_value_constraint(2, 4)],
+            ),
+            1,
+        );
+        let probability_distribution = ProbabilityDistribution { outcome_counts };
+
+        let pairs: Vec<_> = probability_distribution.iter_values().collect();
+        assert_eq!(pairs, vec![(-1, 1), (3, 14)]);
+    }
+}
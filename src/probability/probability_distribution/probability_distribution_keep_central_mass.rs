@@ -0,0 +1,186 @@
+use crate::probability::ProbabilityDistribution;
+use crate::{CountType, ValueType};
+
+use super::collapse_outcome_to_count_map;
+
+impl ProbabilityDistribution {
+    /// Keeps the narrowest range of values whose probability is at least `fraction`, dropping the tails.
+    /// useful to show only the typical results of a [ProbabilityDistribution].
+    ///
+    /// the range is the one with the smallest difference between its lowest and highest value,
+    /// if several ranges are equally narrow the one with the most probability is kept,
+    /// then the one with the lowest values.
+    /// counts and constraint maps of the kept outcomes are unchanged.
+    ///
+    /// # Arguments
+    ///
+    /// * `fraction` - The [f64] probability to keep, clamped into `[0.0, 1.0]`.
+    ///
+    /// # Returns
+    ///
+    /// The new [ProbabilityDistribution], empty if `fraction` is 0.0 or `self` is empty.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use crate::rusted_dice::probability::ProbabilityDistribution;
+    /// # use crate::rusted_dice::probability::ToTable;
+    /// let two_d4 = ProbabilityDistribution::new_multiple_dice(2, 4);
+    ///
+    /// assert_eq!(
+    ///     two_d4
+    ///         .keep_central_mass(0.75)
+    ///         .to_table()
+    ///         .to_string()
+    ///         .replace("\r\n", "\n"),
+    ///     "\
+    ///     +-------+-------+\n\
+    ///     | value | count |\n\
+    ///     +=======+=======+\n\
+    ///     | 3     | 2     |\n\
+    ///     +-------+-------+\n\
+    ///     | 4     | 3     |\n\
+    ///     +-------+-------+\n\
+    ///     | 5     | 4     |\n\
+    ///     +-------+-------+\n\
+    ///     | 6     | 3     |\n\
+    ///     +-------+-------+\n\
+    ///     ");
+    /// ```
+    pub fn keep_central_mass(&self, fraction: f64) -> Self {
+        let fraction = fraction.clamp(0.0, 1.0);
+        let value_counts: Vec<(ValueType, CountType)> =
+            collapse_outcome_to_count_map(&self.outcome_counts)
+                .into_iter()
+                .collect();
+        let target = fraction * self.total_outcome_count() as f64;
+        if target == 0.0 {
+            return ProbabilityDistribution::new_empty_distribution();
+        }
+
+        let mut best: Option<(i64, CountType, ValueType, ValueType)> = None;
+        let mut window_count: CountType = 0;
+        let mut right = 0;
+        for left in 0..value_counts.len() {
+            while right < value_counts.len() && (window_count as f64) < target {
+                window_count += value_counts[right].1;
+                right += 1;
+            }
+            if (window_count as f64) < target {
+                break;
+            }
+
+            let (lowest, highest) = (value_counts[left].0, value_counts[right - 1].0);
+            let span = i64::from(highest) - i64::from(lowest);
+            let is_better = match best {
+                None => true,
+                Some((best_span, best_count, _, _)) => {
+                    span < best_span || (span == best_span && window_count > best_count)
+                }
+            };
+            if is_better {
+                best = Some((span, window_count, lowest, highest));
+            }
+            window_count -= value_counts[left].1;
+        }
+
+        let Some((_, _, lowest, highest)) = best else {
+            return ProbabilityDistribution::new_empty_distribution();
+        };
+        ProbabilityDistribution {
+            outcome_counts: self
+                .outcome_counts
+                .iter()
+                .filter(|(outcome, _)| (lowest..=highest).contains(&outcome.value))
+                .map(|(outcome, count)| (outcome.clone(), *count))
+                .collect(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::probability::{ProbabilityDistribution, ProbabilityOutcome, ToTable};
+
+    #[test]
+    fn test_keep_central_mass_empty() {
+        let probability_distribution = ProbabilityDistribution::new_empty_distribution();
+        assert_eq!(
+            probability_distribution
+                .keep_central_mass(0.5)
+                .outcome_counts
+                .len(),
+            0
+        );
+    }
+
+    #[test]
+    fn test_keep_central_mass_zero_and_full() {
+        let three_d6 = ProbabilityDistribution::new_multiple_dice(3, 6);
+        assert_eq!(three_d6.keep_central_mass(0.0).outcome_counts.len(), 0);
+        assert_eq!(three_d6.keep_central_mass(-1.0).outcome_counts.len(), 0);
+        assert_eq!(
+            three_d6.keep_central_mass(1.0).to_table(),
+            three_d6.to_table()
+        );
+        assert_eq!(
+            three_d6.keep_central_mass(2.0).to_table(),
+            three_d6.to_table()
+        );
+    }
+
+    #[test]
+    fn test_keep_central_mass_three_d6() {
+        let three_d6 = ProbabilityDistribution::new_multiple_dice(3, 6);
+        let central = three_d6.keep_central_mass(0.9);
+
+        let values: Vec<i32> = central
+            .outcome_counts
+            .keys()
+            .map(|outcome| outcome.value)
+            .collect();
+        assert_eq!(values, (6..=15).collect::<Vec<i32>>());
+        assert_eq!(central.total_outcome_count(), 196);
+        assert!(central.total_outcome_count() as f64 >= 0.9 * 216.0);
+    }
+
+    #[test]
+    fn test_keep_central_mass_skewed() {
+        let probability_distribution = ProbabilityDistribution::new_from_many_probability_outcomes(
+            [
+                vec![ProbabilityOutcome::new_with_empty_constraint_map(1); 1],
+                vec![ProbabilityOutcome::new_with_empty_constraint_map(2); 6],
+                vec![ProbabilityOutcome::new_with_empty_constraint_map(3); 2],
+                vec![ProbabilityOutcome::new_with_empty_constraint_map(10); 1],
+            ]
+            .concat(),
+        );
+
+        assert_eq!(
+            probability_distribution.keep_central_mass(0.6).to_table(),
+            ProbabilityDistribution::from_coefficients(2, &[6]).to_table()
+        );
+        assert_eq!(
+            probability_distribution.keep_central_mass(0.7).to_table(),
+            ProbabilityDistribution::from_coefficients(2, &[6, 2]).to_table()
+        );
+        assert_eq!(
+            probability_distribution.keep_central_mass(0.9).to_table(),
+            ProbabilityDistribution::from_coefficients(1, &[1, 6, 2]).to_table()
+        );
+    }
+
+    #[test]
+    fn test_keep_central_mass_keeps_constraints() {
+        let dice_one = ProbabilityDistribution::new_dice(4).add_self_value_constraint(1);
+        let dice_two = ProbabilityDistribution::new_dice(4).add_self_value_constraint(2);
+        let two_d4 = dice_one + dice_two;
+        let central = two_d4.keep_central_mass(0.75);
+
+        assert_eq!(central.outcome_counts.len(), 12);
+        assert!(central
+            .outcome_counts
+            .keys()
+            .all(|outcome| two_d4.outcome_counts.contains_key(outcome)));
+    }
+}
@@ -0,0 +1,189 @@
+use crate::probability::pool_aggregator::{KeepHighest, KeepLowest};
+use crate::probability::ProbabilityDistribution;
+use crate::ValueType;
+
+impl ProbabilityDistribution {
+    /// Creates a new [ProbabilityDistribution] by rolling M, N sided dice and summing the highest `keep` dice.
+    /// every roll combination is enumerated by [ProbabilityDistribution::new_pool] with a [KeepHighest] aggregator.
+    ///
+    /// for Zero m or Zero n, the [ProbabilityDistribution] will have no [ProbabilityOutcome](crate::probability::ProbabilityOutcome)s.
+    ///
+    /// for `keep` larger than m, all dice are kept.
+    ///
+    /// # Arguments
+    ///
+    /// * `number_of_dice` - [u16] The the number of dice to roll.
+    /// * `number_of_sides` - [ValueType] The the number of sides the dice has.
+    /// * `keep` - [u16] The number of highest dice to sum.
+    ///
+    /// # Returns
+    ///
+    /// The new [ProbabilityDistribution].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use crate::rusted_dice::probability::ProbabilityDistribution;
+    /// # use crate::rusted_dice::probability::ToTable;
+    /// let advantage = ProbabilityDistribution::new_keep_highest(2, 3, 1);
+    ///
+    /// assert_eq!(
+    ///     advantage
+    ///         .to_table()
+    ///         .to_string()
+    ///         .replace("\r\n", "\n"),
+    ///     "\
+    ///     +-------+-------+\n\
+    ///     | value | count |\n\
+    ///     +=======+=======+\n\
+    ///     | 1     | 1     |\n\
+    ///     +-------+-------+\n\
+    ///     | 2     | 3     |\n\
+    ///     +-------+-------+\n\
+    ///     | 3     | 5     |\n\
+    ///     +-------+-------+\n\
+    ///     ");
+    /// ```
+    pub fn new_keep_highest(
+        number_of_dice: u16,
+        number_of_sides: ValueType,
+        keep: u16,
+    ) -> ProbabilityDistribution {
+        ProbabilityDistribution::new_pool(number_of_dice, number_of_sides, &KeepHighest(keep))
+    }
+
+    /// Creates a new [ProbabilityDistribution] by rolling M, N sided dice and summing the lowest `keep` dice.
+    /// every roll combination is enumerated by [ProbabilityDistribution::new_pool] with a [KeepLowest] aggregator.
+    ///
+    /// for Zero m or Zero n, the [ProbabilityDistribution] will have no [ProbabilityOutcome](crate::probability::ProbabilityOutcome)s.
+    ///
+    /// for `keep` larger than m, all dice are kept.
+    ///
+    /// # Arguments
+    ///
+    /// * `number_of_dice` - [u16] The the number of dice to roll.
+    /// * `number_of_sides` - [ValueType] The the number of sides the dice has.
+    /// * `keep` - [u16] The number of lowest dice to sum.
+    ///
+    /// # Returns
+    ///
+    /// The new [ProbabilityDistribution].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use crate::rusted_dice::probability::ProbabilityDistribution;
+    /// # use crate::rusted_dice::probability::ToTable;
+    /// let disadvantage = ProbabilityDistribution::new_keep_lowest(2, 3, 1);
+    ///
+    /// assert_eq!(
+    ///     disadvantage
+    ///         .to_table()
+    ///         .to_string()
+    ///         .replace("\r\n", "\n"),
+    ///     "\
+    ///     +-------+-------+\n\
+    ///     | value | count |\n\
+    ///     +=======+=======+\n\
+    ///     | 1     | 5     |\n\
+    ///     +-------+-------+\n\
+    ///     | 2     | 3     |\n\
+    ///     +-------+-------+\n\
+    ///     | 3     | 1     |\n\
+    ///     +-------+-------+\n\
+    ///     ");
+    /// ```
+    pub fn new_keep_lowest(
+        number_of_dice: u16,
+        number_of_sides: ValueType,
+        keep: u16,
+    ) -> ProbabilityDistribution {
+        ProbabilityDistribution::new_pool(number_of_dice, number_of_sides, &KeepLowest(keep))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::probability::{ProbabilityDistribution, ToTable};
+
+    #[test]
+    fn test_new_keep_highest_zero_dice() {
+        let probability_distribution = ProbabilityDistribution::new_keep_highest(0, 6, 1);
+        assert_eq!(probability_distribution.outcome_counts.len(), 0);
+    }
+
+    #[test]
+    fn test_new_keep_lowest_zero_sides() {
+        let probability_distribution = ProbabilityDistribution::new_keep_lowest(3, 0, 1);
+        assert_eq!(probability_distribution.outcome_counts.len(), 0);
+    }
+
+    #[test]
+    fn test_new_keep_highest_keep_all() {
+        assert_eq!(
+            ProbabilityDistribution::new_keep_highest(3, 4, 3).to_table(),
+            ProbabilityDistribution::new_multiple_dice(3, 4).to_table()
+        );
+        assert_eq!(
+            ProbabilityDistribution::new_keep_highest(3, 4, 7).to_table(),
+            ProbabilityDistribution::new_multiple_dice(3, 4).to_table()
+        );
+    }
+
+    #[test]
+    fn test_new_keep_lowest_keep_all() {
+        assert_eq!(
+            ProbabilityDistribution::new_keep_lowest(2, 6, 5).to_table(),
+            ProbabilityDistribution::new_multiple_dice(2, 6).to_table()
+        );
+    }
+
+    #[test]
+    fn test_new_keep_highest_four_d6_drop_lowest() {
+        let stat_roll = ProbabilityDistribution::new_keep_highest(4, 6, 3);
+
+        assert_eq!(stat_roll.total_outcome_count(), 1296);
+        assert_eq!(
+            stat_roll.coefficients(),
+            (
+                3,
+                vec![1, 4, 10, 21, 38, 62, 91, 122, 148, 167, 172, 160, 131, 94, 54, 21]
+            )
+        );
+    }
+
+    #[test]
+    fn test_new_keep_lowest_four_d6_drop_highest() {
+        let (offset, mut coefficients) =
+            ProbabilityDistribution::new_keep_lowest(4, 6, 3).coefficients();
+        coefficients.reverse();
+
+        assert_eq!(offset, 3);
+        assert_eq!(
+            coefficients,
+            vec![1, 4, 10, 21, 38, 62, 91, 122, 148, 167, 172, 160, 131, 94, 54, 21]
+        );
+    }
+
+    #[test]
+    fn test_new_keep_highest_one_matches_new_pool_max() {
+        assert_eq!(
+            ProbabilityDistribution::new_keep_highest(3, 6, 1).to_table(),
+            ProbabilityDistribution::new_pool_max(3, 6).to_table()
+        );
+    }
+
+    #[test]
+    fn test_new_keep_lowest_one_matches_new_pool_min() {
+        assert_eq!(
+            ProbabilityDistribution::new_keep_lowest(3, 6, 1).to_table(),
+            ProbabilityDistribution::new_pool_min(3, 6).to_table()
+        );
+    }
+
+    #[test]
+    fn test_new_keep_highest_zero_kept() {
+        let probability_distribution = ProbabilityDistribution::new_keep_highest(2, 6, 0);
+        assert_eq!(probability_distribution.coefficients(), (0, vec![36]));
+    }
+}
@@ -0,0 +1,72 @@
+use crate::probability::ProbabilityDistribution;
+
+impl ProbabilityDistribution {
+    /// Returns the number of distinct outcomes in the [ProbabilityDistribution].
+    /// outcomes sharing a value but differing in constraints are counted separately.
+    ///
+    /// # Returns
+    ///
+    /// The number of distinct [ProbabilityOutcome](crate::probability::ProbabilityOutcome)s.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use crate::rusted_dice::probability::ProbabilityDistribution;
+    /// let two_d6 = ProbabilityDistribution::new_multiple_dice(2, 6);
+    /// assert_eq!(two_d6.len(), 11);
+    /// ```
+    pub fn len(&self) -> usize {
+        self.outcome_counts.len()
+    }
+
+    /// Checks if the [ProbabilityDistribution] has no outcomes.
+    ///
+    /// # Returns
+    ///
+    /// `true` if there are no outcomes, `false` otherwise.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use crate::rusted_dice::probability::ProbabilityDistribution;
+    /// assert!(ProbabilityDistribution::new_empty_distribution().is_empty());
+    /// assert!(!ProbabilityDistribution::new_dice(6).is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.outcome_counts.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::probability::ProbabilityDistribution;
+
+    #[test]
+    fn test_empty() {
+        let probability_distribution = ProbabilityDistribution::new_empty_distribution();
+        assert_eq!(probability_distribution.len(), 0);
+        assert!(probability_distribution.is_empty());
+    }
+
+    #[test]
+    fn test_single_outcome() {
+        let probability_distribution = ProbabilityDistribution::new_constant(4);
+        assert_eq!(probability_distribution.len(), 1);
+        assert!(!probability_distribution.is_empty());
+    }
+
+    #[test]
+    fn test_two_d6() {
+        let two_d6 = ProbabilityDistribution::new_multiple_dice(2, 6);
+        assert_eq!(two_d6.len(), 11);
+        assert!(!two_d6.is_empty());
+    }
+
+    #[test]
+    fn test_counts_constrained_outcomes_separately() {
+        let d4 = ProbabilityDistribution::new_dice(4).add_self_value_constraint(1);
+        let probability_distribution = d4 + ProbabilityDistribution::new_dice(4);
+        assert_eq!(probability_distribution.len(), 16);
+        assert_eq!(probability_distribution.iter_values().count(), 7);
+    }
+}
@@ -0,0 +1,140 @@
+use std::collections::BTreeMap;
+
+use crate::probability::{ProbabilityDistribution, ProbabilityOutcome};
+use crate::ValueType;
+
+use super::add_outcome_to_map;
+
+impl ProbabilityDistribution {
+    /// Applies `function` to the value of every outcome of the [ProbabilityDistribution],
+    /// for example capping a roll at 20.
+    /// constraint maps are kept, outcomes which end up equal are merged and their counts added.
+    ///
+    /// # Arguments
+    ///
+    /// * `function` - The function mapping each old [ValueType] to its new [ValueType].
+    ///
+    /// # Returns
+    ///
+    /// The new [ProbabilityDistribution].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use crate::rusted_dice::probability::ProbabilityDistribution;
+    /// # use crate::rusted_dice::probability::ToTable;
+    /// let dice = ProbabilityDistribution::new_dice(6);
+    ///
+    /// assert_eq!(
+    ///     dice.map_values(|value| value.min(4))
+    ///         .to_table()
+    ///         .to_string()
+    ///         .replace("\r\n", "\n"),
+    ///     "\
+    ///     +-------+-------+\n\
+    ///     | value | count |\n\
+    ///     +=======+=======+\n\
+    ///     | 1     | 1     |\n\
+    ///     +-------+-------+\n\
+    ///     | 2     | 1     |\n\
+    ///     +-------+-------+\n\
+    ///     | 3     | 1     |\n\
+    ///     +-------+-------+\n\
+    ///     | 4     | 3     |\n\
+    ///     +-------+-------+\n\
+    ///     ");
+    /// ```
+    pub fn map_values<F: Fn(ValueType) -> ValueType>(&self, function: F) -> Self {
+        let mut new_outcome_counts = BTreeMap::new();
+        for (outcome, count) in self.outcome_counts.iter() {
+            add_outcome_to_map(
+                &mut new_outcome_counts,
+                ProbabilityOutcome {
+                    value: function(outcome.value),
+                    constraint_map: outcome.constraint_map.clone(),
+                },
+                *count,
+            );
+        }
+        ProbabilityDistribution {
+            outcome_counts: new_outcome_counts,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::constraint_management::Constraint;
+    use crate::probability::{ProbabilityDistribution, ProbabilityOutcome};
+    use crate::ValueType;
+
+    #[test]
+    fn test_map_values_empty() {
+        let probability_distribution =
+            ProbabilityDistribution::new_empty_distribution().map_values(|value| value * 2);
+        assert_eq!(probability_distribution.outcome_counts.len(), 0);
+    }
+
+    #[test]
+    fn test_map_values_identity() {
+        let probability_distribution = ProbabilityDistribution::new_multiple_dice(2, 6);
+        assert_eq!(
+            probability_distribution
+                .map_values(|value| value)
+                .outcome_counts,
+            probability_distribution.outcome_counts
+        );
+    }
+
+    #[test]
+    fn test_map_values_cap() {
+        let probability_distribution =
+            ProbabilityDistribution::new_dice(6).map_values(|value| value.min(4));
+
+        assert_eq!(probability_distribution.outcome_counts.len(), 4);
+        for (value, count) in [(1, 1), (2, 1), (3, 1), (4, 3)] {
+            assert_eq!(
+                probability_distribution
+                    .outcome_counts
+                    .get(&ProbabilityOutcome::new_with_empty_constraint_map(value)),
+                Some(&count)
+            );
+        }
+    }
+
+    #[test]
+    fn test_map_values_keeps_constraints() {
+        let probability_distribution = ProbabilityDistribution::new_dice(3)
+            .add_self_value_constraint(7)
+            .map_values(|value| value % 2);
+
+        for (value, face) in [(1, 1), (0, 2), (1, 3)] {
+            assert_eq!(
+                probability_distribution.outcome_counts.get(
+                    &ProbabilityOutcome::new_with_constraints(
+                        value,
+                        vec![Constraint::new_single_valid_value_constraint(7, face)],
+                    )
+                ),
+                Some(&1)
+            );
+        }
+        assert_eq!(probability_distribution.outcome_counts.len(), 3);
+    }
+
+    #[test]
+    fn test_map_values_matches_map_values_in_place() {
+        let probability_distribution = ProbabilityDistribution::new_dice(4)
+            .add_self_value_constraint(1)
+            + ProbabilityDistribution::new_dice(4).add_self_value_constraint(2);
+        let function = |value: ValueType| (value - 5).abs();
+
+        let mut in_place = probability_distribution.clone();
+        in_place.map_values_in_place(function);
+
+        assert_eq!(
+            probability_distribution.map_values(function).outcome_counts,
+            in_place.outcome_counts
+        );
+    }
+}
@@ -0,0 +1,73 @@
+use crate::probability::ProbabilityDistribution;
+use crate::ValueType;
+
+impl ProbabilityDistribution {
+    /// Returns the mean value of the rolls of the [ProbabilityDistribution] that are at least `threshold`.
+    /// for example the average damage of an attack when it hits.
+    ///
+    /// # Arguments
+    ///
+    /// * `threshold` - The lowest [ValueType] to include.
+    ///
+    /// # Returns
+    ///
+    /// The mean of the values `>= threshold` weighted by their counts,
+    /// or `None` if no outcome is at least `threshold`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use crate::rusted_dice::probability::ProbabilityDistribution;
+    /// let d20 = ProbabilityDistribution::new_dice(20);
+    /// assert_eq!(d20.mean_given_at_least(11), Some(15.5));
+    /// assert_eq!(d20.mean_given_at_least(21), None);
+    /// ```
+    pub fn mean_given_at_least(&self, threshold: ValueType) -> Option<f64> {
+        let (at_or_above, _) = self.partition_at(threshold);
+        let total = at_or_above.total_outcome_count();
+        if total == 0 {
+            return None;
+        }
+        Some(at_or_above.mean())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::probability::ProbabilityDistribution;
+
+    #[test]
+    fn test_mean_given_at_least_empty() {
+        let probability_distribution = ProbabilityDistribution::new_empty_distribution();
+        assert_eq!(probability_distribution.mean_given_at_least(0), None);
+    }
+
+    #[test]
+    fn test_mean_given_at_least_d20() {
+        let d20 = ProbabilityDistribution::new_dice(20);
+        assert_eq!(d20.mean_given_at_least(11), Some(15.5));
+        assert_eq!(d20.mean_given_at_least(20), Some(20.0));
+        assert_eq!(d20.mean_given_at_least(-5), Some(10.5));
+        assert_eq!(d20.mean_given_at_least(21), None);
+    }
+
+    #[test]
+    fn test_mean_given_at_least_weighted() {
+        let two_d6 = ProbabilityDistribution::new_multiple_dice(2, 6);
+        // 10 has a count of 3, 11 of 2 and 12 of 1
+        assert_eq!(
+            two_d6.mean_given_at_least(10),
+            Some((10.0 * 3.0 + 11.0 * 2.0 + 12.0) / 6.0)
+        );
+    }
+
+    #[test]
+    fn test_mean_given_at_least_merges_constraints() {
+        let dice_one = ProbabilityDistribution::new_dice(6).add_self_value_constraint(1);
+        let dice_two = ProbabilityDistribution::new_dice(6).add_self_value_constraint(2);
+        assert_eq!(
+            (dice_one + dice_two).mean_given_at_least(10),
+            ProbabilityDistribution::new_multiple_dice(2, 6).mean_given_at_least(10)
+        );
+    }
+}
@@ -0,0 +1,188 @@
+use crate::probability::ProbabilityDistribution;
+use crate::ValueType;
+
+impl ProbabilityDistribution {
+    /// Combine this instance with another instance keeping the higher value of each pair,
+    /// for example rolling two dice and taking the higher.
+    /// counts are multiplied like [Combine::combine](crate::probability::Combine::combine),
+    /// constraint maps are combined using the ConstraintMap::add function,
+    /// outcomes whose constraint maps are not possible are dropped.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The [ProbabilityDistribution] to combine with.
+    ///
+    /// # Returns
+    ///
+    /// The new [ProbabilityDistribution].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use crate::rusted_dice::probability::ProbabilityDistribution;
+    /// let d4 = ProbabilityDistribution::new_dice(4);
+    /// let d6 = ProbabilityDistribution::new_dice(6);
+    /// assert_eq!(d4.max_combine(&d6).coefficients(), (1, vec![1, 3, 5, 7, 4, 4]));
+    /// ```
+    pub fn max_combine(&self, other: &Self) -> Self {
+        self.combine_map(other, ValueType::max)
+    }
+
+    /// Combine this instance with another instance keeping the lower value of each pair,
+    /// for example rolling two dice and taking the lower.
+    /// counts are multiplied like [Combine::combine](crate::probability::Combine::combine),
+    /// constraint maps are combined using the ConstraintMap::add function,
+    /// outcomes whose constraint maps are not possible are dropped.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The [ProbabilityDistribution] to combine with.
+    ///
+    /// # Returns
+    ///
+    /// The new [ProbabilityDistribution].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use crate::rusted_dice::probability::ProbabilityDistribution;
+    /// let d4 = ProbabilityDistribution::new_dice(4);
+    /// let d6 = ProbabilityDistribution::new_dice(6);
+    /// assert_eq!(d4.min_combine(&d6).coefficients(), (1, vec![9, 7, 5, 3]));
+    /// ```
+    pub fn min_combine(&self, other: &Self) -> Self {
+        self.combine_map(other, ValueType::min)
+    }
+
+    /// Creates a new [ProbabilityDistribution] of rolling two N sided dice and keeping the higher,
+    /// advantage in D&D 5e.
+    ///
+    /// for non-positive n, the [ProbabilityDistribution] will have no [ProbabilityOutcome](crate::probability::ProbabilityOutcome)s.
+    ///
+    /// # Arguments
+    ///
+    /// * `number_of_sides` - [ValueType] The the number of sides the dice has.
+    ///
+    /// # Returns
+    ///
+    /// The new [ProbabilityDistribution].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use crate::rusted_dice::probability::ProbabilityDistribution;
+    /// let advantage = ProbabilityDistribution::new_advantage(20);
+    /// assert_eq!(advantage.count_of(1), 1);
+    /// assert_eq!(advantage.count_of(20), 39);
+    /// ```
+    pub fn new_advantage(number_of_sides: ValueType) -> ProbabilityDistribution {
+        let dice = ProbabilityDistribution::new_positive_dice(number_of_sides);
+        dice.max_combine(&dice)
+    }
+
+    /// Creates a new [ProbabilityDistribution] of rolling two N sided dice and keeping the lower,
+    /// disadvantage in D&D 5e.
+    ///
+    /// for non-positive n, the [ProbabilityDistribution] will have no [ProbabilityOutcome](crate::probability::ProbabilityOutcome)s.
+    ///
+    /// # Arguments
+    ///
+    /// * `number_of_sides` - [ValueType] The the number of sides the dice has.
+    ///
+    /// # Returns
+    ///
+    /// The new [ProbabilityDistribution].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use crate::rusted_dice::probability::ProbabilityDistribution;
+    /// let disadvantage = ProbabilityDistribution::new_disadvantage(20);
+    /// assert_eq!(disadvantage.count_of(1), 39);
+    /// assert_eq!(disadvantage.count_of(20), 1);
+    /// ```
+    pub fn new_disadvantage(number_of_sides: ValueType) -> ProbabilityDistribution {
+        let dice = ProbabilityDistribution::new_positive_dice(number_of_sides);
+        dice.min_combine(&dice)
+    }
+
+    /// a helper function for a single N sided dice, empty for non-positive n.
+    fn new_positive_dice(number_of_sides: ValueType) -> ProbabilityDistribution {
+        if number_of_sides <= 0 {
+            return ProbabilityDistribution::new_empty_distribution();
+        }
+        ProbabilityDistribution::new_dice(number_of_sides)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::constraint_management::Constraint;
+    use crate::probability::{ProbabilityDistribution, ToTable};
+
+    #[test]
+    fn test_min_max_combine_empty() {
+        let empty = ProbabilityDistribution::new_empty_distribution();
+        let dice = ProbabilityDistribution::new_dice(6);
+        assert!(empty.max_combine(&dice).is_empty());
+        assert!(dice.max_combine(&empty).is_empty());
+        assert!(empty.min_combine(&dice).is_empty());
+        assert!(dice.min_combine(&empty).is_empty());
+    }
+
+    #[test]
+    fn test_new_advantage_d20() {
+        let advantage = ProbabilityDistribution::new_advantage(20);
+        assert_eq!(advantage.count_of(1), 1);
+        assert_eq!(advantage.count_of(20), 39);
+        assert_eq!(advantage.total_outcome_count(), 400);
+        for value in 1..=20 {
+            assert_eq!(advantage.count_of(value), (2 * value - 1) as u64);
+        }
+    }
+
+    #[test]
+    fn test_new_disadvantage_d20() {
+        let disadvantage = ProbabilityDistribution::new_disadvantage(20);
+        assert_eq!(disadvantage.count_of(1), 39);
+        assert_eq!(disadvantage.count_of(20), 1);
+        assert_eq!(disadvantage.total_outcome_count(), 400);
+    }
+
+    #[test]
+    fn test_new_advantage_matches_new_pool_max() {
+        for number_of_sides in 1..=12 {
+            assert_eq!(
+                ProbabilityDistribution::new_advantage(number_of_sides).to_table(),
+                ProbabilityDistribution::new_pool_max(2, number_of_sides).to_table()
+            );
+            assert_eq!(
+                ProbabilityDistribution::new_disadvantage(number_of_sides).to_table(),
+                ProbabilityDistribution::new_pool_min(2, number_of_sides).to_table()
+            );
+        }
+    }
+
+    #[test]
+    fn test_new_advantage_non_positive_sides() {
+        assert!(ProbabilityDistribution::new_advantage(0).is_empty());
+        assert!(ProbabilityDistribution::new_disadvantage(-6).is_empty());
+    }
+
+    #[test]
+    fn test_min_max_combine_drops_impossible_constraints() {
+        let dice_one = ProbabilityDistribution::new_dice(4).add_self_value_constraint(1);
+        let dice_two = ProbabilityDistribution::new_dice(4)
+            + Constraint::new_many_item_constraint(1, vec![3, 4]);
+
+        let highest = dice_one.max_combine(&dice_two);
+        assert_eq!(highest.total_outcome_count(), 8);
+        assert_eq!(highest.count_of(3), 3);
+        assert_eq!(highest.count_of(4), 5);
+
+        let lowest = dice_one.min_combine(&dice_two);
+        assert_eq!(lowest.total_outcome_count(), 8);
+        assert_eq!(lowest.count_of(1), 2);
+        assert_eq!(lowest.count_of(4), 1);
+    }
+}
@@ -0,0 +1,382 @@
+use std::collections::BTreeMap;
+
+use crate::probability::ProbabilityDistribution;
+use crate::CountType;
+
+use super::{add_outcome_to_map, greatest_common_divisor, least_common_multiple};
+
+/// the number of parts a total weight of 1.0 is split into when weights are made integral.
+const MIXTURE_WEIGHT_RESOLUTION: f64 = 1_000_000.0;
+
+impl ProbabilityDistribution {
+    /// Creates a new [ProbabilityDistribution] that is a probabilistic mixture of the components.
+    /// each component is rolled with a probability proportional to its weight,
+    /// for example a random encounter table.
+    ///
+    /// weights are divided by their sum, so they do not need to sum to exactly 1.0.
+    /// to keep counts integral every component is first scaled to the least common multiple of the
+    /// component total counts, then weights are rounded to millionths of the total weight,
+    /// finally all counts are divided by their greatest common divisor.
+    ///
+    /// # Arguments
+    ///
+    /// * `components` - Pairs of a [ProbabilityDistribution] and its [f64] weight.
+    ///
+    /// # Returns
+    ///
+    /// The new [ProbabilityDistribution].
+    /// components that are empty or have a weight of 0 are skipped,
+    /// if every component is skipped the [ProbabilityDistribution] will be empty.
+    ///
+    /// # Panics
+    ///
+    /// Panics if any weight is negative or not finite.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use crate::rusted_dice::probability::ProbabilityDistribution;
+    /// # use crate::rusted_dice::probability::ToTable;
+    /// let sword_or_dagger = ProbabilityDistribution::mixture(&[
+    ///     (ProbabilityDistribution::new_dice(4), 0.5),
+    ///     (ProbabilityDistribution::new_dice(2), 0.5),
+    /// ]);
+    ///
+    /// assert_eq!(
+    ///     sword_or_dagger
+    ///         .to_table()
+    ///         .to_string()
+    ///         .replace("\r\n", "\n"),
+    ///     "\
+    ///     +-------+-------+\n\
+    ///     | value | count |\n\
+    ///     +=======+=======+\n\
+    ///     | 1     | 3     |\n\
+    ///     +-------+-------+\n\
+    ///     | 2     | 3     |\n\
+    ///     +-------+-------+\n\
+    ///     | 3     | 1     |\n\
+    ///     +-------+-------+\n\
+    ///     | 4     | 1     |\n\
+    ///     +-------+-------+\n\
+    ///     ");
+    /// ```
+    pub fn mixture(components: &[(ProbabilityDistribution, f64)]) -> Self {
+        if components
+            .iter()
+            .any(|(_, weight)| !weight.is_finite() || *weight < 0.0)
+        {
+            panic!("Mixture weights must be finite and non-negative.");
+        }
+
+        let weight_sum: f64 = components.iter().map(|(_, weight)| weight).sum();
+        if weight_sum == 0.0 {
+            return ProbabilityDistribution::new_empty_distribution();
+        }
+        let weighted_components: Vec<(&ProbabilityDistribution, CountType)> = components
+            .iter()
+            .map(|(probability_distribution, weight)| {
+                (
+                    probability_distribution,
+                    (weight / weight_sum * MIXTURE_WEIGHT_RESOLUTION).round() as CountType,
+                )
+            })
+            .collect();
+        mix_weighted_components(weighted_components)
+    }
+
+    /// Creates a new [ProbabilityDistribution] that is a probabilistic mixture of the components,
+    /// with integer weights so no rounding is needed,
+    /// for example "50% chance to use a sword (1d8), 50% a dagger (1d4)" as weights of 1 and 1.
+    ///
+    /// each component is rolled with a probability of its weight divided by the sum of the weights.
+    /// to keep counts integral every component is first scaled to the least common multiple of the
+    /// component total counts, then by its weight,
+    /// finally all counts are divided by their greatest common divisor.
+    ///
+    /// # Arguments
+    ///
+    /// * `components` - Pairs of a [ProbabilityDistribution] and its [CountType] weight.
+    ///
+    /// # Returns
+    ///
+    /// The new [ProbabilityDistribution].
+    /// components that are empty or have a weight of 0 are skipped,
+    /// if every component is skipped the [ProbabilityDistribution] will be empty.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use crate::rusted_dice::probability::ProbabilityDistribution;
+    /// # use crate::rusted_dice::probability::ProbabilityOutcome;
+    /// let sword_or_dagger = ProbabilityDistribution::mixture_with_counts(&[
+    ///     (ProbabilityDistribution::new_dice(8), 1),
+    ///     (ProbabilityDistribution::new_dice(4), 1),
+    /// ]);
+    ///
+    /// assert_eq!(sword_or_dagger.total_outcome_count(), 16);
+    /// assert_eq!(
+    ///     sword_or_dagger.outcome_counts.get(&ProbabilityOutcome::new_with_empty_constraint_map(1)),
+    ///     Some(&3)
+    /// );
+    /// assert_eq!(
+    ///     sword_or_dagger.outcome_counts.get(&ProbabilityOutcome::new_with_empty_constraint_map(8)),
+    ///     Some(&1)
+    /// );
+    /// ```
+    pub fn mixture_with_counts(components: &[(ProbabilityDistribution, CountType)]) -> Self {
+        mix_weighted_components(
+            components
+                .iter()
+                .map(|(probability_distribution, weight)| (probability_distribution, *weight))
+                .collect(),
+        )
+    }
+}
+
+/// a helper function to mix components with integral weights,
+/// skipping components that are empty or have a weight of 0.
+fn mix_weighted_components(
+    weighted_components: Vec<(&ProbabilityDistribution, CountType)>,
+) -> ProbabilityDistribution {
+    let weighted_components: Vec<(&ProbabilityDistribution, CountType)> = weighted_components
+        .into_iter()
+        .filter(|(probability_distribution, weight)| {
+            *weight != 0 && probability_distribution.total_outcome_count() != 0
+        })
+        .collect();
+
+    let common_total =
+        weighted_components
+            .iter()
+            .fold(1, |common_total, (probability_distribution, _)| {
+                least_common_multiple(common_total, probability_distribution.total_outcome_count())
+            });
+
+    let mut map = BTreeMap::new();
+    for (probability_distribution, weight) in weighted_components {
+        let scale = common_total / probability_distribution.total_outcome_count() * weight;
+        for (outcome, count) in probability_distribution.outcome_counts.iter() {
+            add_outcome_to_map(&mut map, outcome.clone(), count * scale);
+        }
+    }
+
+    let divisor = map.values().copied().fold(0, greatest_common_divisor);
+    for count in map.values_mut() {
+        *count /= divisor;
+    }
+    ProbabilityDistribution {
+        outcome_counts: map,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::constraint_management::Constraint;
+    use crate::probability::{ProbabilityDistribution, ProbabilityOutcome, ToTable};
+
+    #[test]
+    fn test_mixture_no_components() {
+        assert_eq!(
+            ProbabilityDistribution::mixture(&[]).outcome_counts.len(),
+            0
+        );
+    }
+
+    #[test]
+    fn test_mixture_zero_weights_and_empty_components() {
+        let probability_distribution = ProbabilityDistribution::mixture(&[
+            (ProbabilityDistribution::new_dice(6), 0.0),
+            (ProbabilityDistribution::new_empty_distribution(), 1.0),
+        ]);
+        assert_eq!(probability_distribution.outcome_counts.len(), 0);
+
+        let probability_distribution = ProbabilityDistribution::mixture(&[
+            (ProbabilityDistribution::new_dice(6), 0.0),
+            (ProbabilityDistribution::new_dice(4), 0.3),
+        ]);
+        assert_eq!(
+            probability_distribution.to_table(),
+            ProbabilityDistribution::new_dice(4).to_table()
+        );
+    }
+
+    #[test]
+    fn test_mixture_single_component_is_reduced() {
+        let probability_distribution =
+            ProbabilityDistribution::mixture(&[(ProbabilityDistribution::new_dice(6) * 2, 1.0)]);
+        assert_eq!(
+            probability_distribution.to_table(),
+            (ProbabilityDistribution::new_dice(6) * 2).to_table()
+        );
+    }
+
+    #[test]
+    fn test_mixture_even_weights() {
+        let probability_distribution = ProbabilityDistribution::mixture(&[
+            (ProbabilityDistribution::new_dice(2), 0.5),
+            (ProbabilityDistribution::new_dice(2) + 1, 0.5),
+        ]);
+        assert_eq!(
+            probability_distribution.to_table(),
+            ProbabilityDistribution::from_coefficients(1, &[1, 2, 1]).to_table()
+        );
+    }
+
+    #[test]
+    fn test_mixture_uneven_weights() {
+        // 1/4 of d6 and 3/4 of 2d6, in 144ths and reduced to 48ths
+        let probability_distribution = ProbabilityDistribution::mixture(&[
+            (ProbabilityDistribution::new_dice(6), 0.25),
+            (ProbabilityDistribution::new_multiple_dice(2, 6), 0.75),
+        ]);
+        assert_eq!(
+            probability_distribution.to_table(),
+            ProbabilityDistribution::from_coefficients(1, &[2, 3, 4, 5, 6, 7, 6, 5, 4, 3, 2, 1])
+                .to_table()
+        );
+        assert_eq!(probability_distribution.total_outcome_count(), 48);
+    }
+
+    #[test]
+    fn test_mixture_weights_are_normalized() {
+        assert_eq!(
+            ProbabilityDistribution::mixture(&[
+                (ProbabilityDistribution::new_dice(4), 3.0),
+                (ProbabilityDistribution::new_dice(8), 3.0),
+            ])
+            .to_table(),
+            ProbabilityDistribution::mixture(&[
+                (ProbabilityDistribution::new_dice(4), 0.5),
+                (ProbabilityDistribution::new_dice(8), 0.5),
+            ])
+            .to_table()
+        );
+    }
+
+    #[test]
+    fn test_mixture_keeps_constraints() {
+        let probability_distribution = ProbabilityDistribution::mixture(&[
+            (
+                ProbabilityDistribution::new_dice(1).add_self_value_constraint(1),
+                0.5,
+            ),
+            (ProbabilityDistribution::new_dice(1), 0.5),
+        ]);
+        assert_eq!(
+            probability_distribution
+                .outcome_counts
+                .get(&ProbabilityOutcome::new_with_constraints(
+                    1,
+                    vec![Constraint::new_single_valid_value_constraint(1, 1)]
+                )),
+            Some(&1)
+        );
+        assert_eq!(
+            probability_distribution
+                .outcome_counts
+                .get(&ProbabilityOutcome::new_with_empty_constraint_map(1)),
+            Some(&1)
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Mixture weights must be finite and non-negative.")]
+    fn test_mixture_negative_weight() {
+        ProbabilityDistribution::mixture(&[(ProbabilityDistribution::new_dice(4), -0.5)]);
+    }
+
+    #[test]
+    #[should_panic(expected = "Mixture weights must be finite and non-negative.")]
+    fn test_mixture_nan_weight() {
+        ProbabilityDistribution::mixture(&[(ProbabilityDistribution::new_dice(4), f64::NAN)]);
+    }
+
+    #[test]
+    fn test_mixture_with_counts_no_components() {
+        assert_eq!(
+            ProbabilityDistribution::mixture_with_counts(&[])
+                .outcome_counts
+                .len(),
+            0
+        );
+    }
+
+    #[test]
+    fn test_mixture_with_counts_zero_weights_and_empty_components() {
+        let probability_distribution = ProbabilityDistribution::mixture_with_counts(&[
+            (ProbabilityDistribution::new_dice(6), 0),
+            (ProbabilityDistribution::new_empty_distribution(), 3),
+        ]);
+        assert_eq!(probability_distribution.outcome_counts.len(), 0);
+
+        let probability_distribution = ProbabilityDistribution::mixture_with_counts(&[
+            (ProbabilityDistribution::new_dice(6), 0),
+            (ProbabilityDistribution::new_dice(4), 5),
+        ]);
+        assert_eq!(
+            probability_distribution.to_table(),
+            ProbabilityDistribution::new_dice(4).to_table()
+        );
+    }
+
+    #[test]
+    fn test_mixture_with_counts_d4_d8_even_weights() {
+        let probability_distribution = ProbabilityDistribution::mixture_with_counts(&[
+            (ProbabilityDistribution::new_dice(4), 1),
+            (ProbabilityDistribution::new_dice(8), 1),
+        ]);
+
+        assert_eq!(probability_distribution.outcome_counts.len(), 8);
+        for (value, count) in [
+            (1, 3),
+            (2, 3),
+            (3, 3),
+            (4, 3),
+            (5, 1),
+            (6, 1),
+            (7, 1),
+            (8, 1),
+        ] {
+            assert_eq!(
+                probability_distribution
+                    .outcome_counts
+                    .get(&ProbabilityOutcome::new_with_empty_constraint_map(value)),
+                Some(&count)
+            );
+        }
+    }
+
+    #[test]
+    fn test_mixture_with_counts_uneven_weights() {
+        let probability_distribution = ProbabilityDistribution::mixture_with_counts(&[
+            (ProbabilityDistribution::new_constant(0), 3),
+            (ProbabilityDistribution::new_dice(2), 2),
+        ]);
+
+        for (value, count) in [(0, 3), (1, 1), (2, 1)] {
+            assert_eq!(
+                probability_distribution
+                    .outcome_counts
+                    .get(&ProbabilityOutcome::new_with_empty_constraint_map(value)),
+                Some(&count)
+            );
+        }
+    }
+
+    #[test]
+    fn test_mixture_with_counts_matches_mixture() {
+        let components = [
+            (ProbabilityDistribution::new_multiple_dice(2, 6), 1),
+            (ProbabilityDistribution::new_dice(10), 3),
+        ];
+        assert_eq!(
+            ProbabilityDistribution::mixture_with_counts(&components).outcome_counts,
+            ProbabilityDistribution::mixture(&[
+                (components[0].0.clone(), 0.25),
+                (components[1].0.clone(), 0.75),
+            ])
+            .outcome_counts
+        );
+    }
+}
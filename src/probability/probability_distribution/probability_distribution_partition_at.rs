@@ -0,0 +1,140 @@
+use crate::probability::ProbabilityDistribution;
+use crate::ValueType;
+
+impl ProbabilityDistribution {
+    /// Splits the [ProbabilityDistribution] into the outcomes at or above `threshold` and those below it.
+    /// counts and constraint maps are kept, so the two parts can be processed separately,
+    /// for example hits and misses.
+    ///
+    /// # Arguments
+    ///
+    /// * `threshold` - The lowest [ValueType] of the first part.
+    ///
+    /// # Returns
+    ///
+    /// A tuple of the [ProbabilityDistribution] of values `>= threshold`
+    /// and the [ProbabilityDistribution] of values `< threshold`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use crate::rusted_dice::probability::ProbabilityDistribution;
+    /// # use crate::rusted_dice::probability::ToTable;
+    /// let dice = ProbabilityDistribution::new_dice(4);
+    /// let (hits, misses) = dice.partition_at(3);
+    ///
+    /// assert_eq!(
+    ///     hits.to_table().to_string().replace("\r\n", "\n"),
+    ///     "\
+    ///     +-------+-------+\n\
+    ///     | value | count |\n\
+    ///     +=======+=======+\n\
+    ///     | 3     | 1     |\n\
+    ///     +-------+-------+\n\
+    ///     | 4     | 1     |\n\
+    ///     +-------+-------+\n\
+    ///     ");
+    /// assert_eq!(
+    ///     misses.to_table().to_string().replace("\r\n", "\n"),
+    ///     "\
+    ///     +-------+-------+\n\
+    ///     | value | count |\n\
+    ///     +=======+=======+\n\
+    ///     | 1     | 1     |\n\
+    ///     +-------+-------+\n\
+    ///     | 2     | 1     |\n\
+    ///     +-------+-------+\n\
+    ///     ");
+    /// ```
+    pub fn partition_at(
+        &self,
+        threshold: ValueType,
+    ) -> (ProbabilityDistribution, ProbabilityDistribution) {
+        let (at_or_above, below) = self
+            .outcome_counts
+            .iter()
+            .map(|(outcome, count)| (outcome.clone(), *count))
+            .partition(|(outcome, _)| outcome.value >= threshold);
+
+        (
+            ProbabilityDistribution {
+                outcome_counts: at_or_above,
+            },
+            ProbabilityDistribution {
+                outcome_counts: below,
+            },
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::constraint_management::Constraint;
+    use crate::probability::{ProbabilityDistribution, ProbabilityOutcome, ToTable};
+
+    #[test]
+    fn test_partition_at_empty() {
+        let probability_distribution = ProbabilityDistribution::new_empty_distribution();
+        let (at_or_above, below) = probability_distribution.partition_at(0);
+        assert_eq!(at_or_above.outcome_counts.len(), 0);
+        assert_eq!(below.outcome_counts.len(), 0);
+    }
+
+    #[test]
+    fn test_partition_at_outside_range() {
+        let dice = ProbabilityDistribution::new_dice(6);
+
+        let (at_or_above, below) = dice.partition_at(1);
+        assert_eq!(at_or_above.to_table(), dice.to_table());
+        assert_eq!(below.outcome_counts.len(), 0);
+
+        let (at_or_above, below) = dice.partition_at(7);
+        assert_eq!(at_or_above.outcome_counts.len(), 0);
+        assert_eq!(below.to_table(), dice.to_table());
+    }
+
+    #[test]
+    fn test_partition_at_conserves_counts() {
+        let three_d6 = ProbabilityDistribution::new_multiple_dice(3, 6);
+        for threshold in 0..20 {
+            let (at_or_above, below) = three_d6.partition_at(threshold);
+            assert_eq!(
+                at_or_above.total_outcome_count() + below.total_outcome_count(),
+                three_d6.total_outcome_count()
+            );
+            assert!(at_or_above
+                .outcome_counts
+                .keys()
+                .all(|outcome| outcome.value >= threshold));
+            assert!(below
+                .outcome_counts
+                .keys()
+                .all(|outcome| outcome.value < threshold));
+        }
+    }
+
+    #[test]
+    fn test_partition_at_keeps_constraints() {
+        let dice = ProbabilityDistribution::new_dice(2).add_self_value_constraint(4);
+        let (at_or_above, below) = dice.partition_at(2);
+
+        assert_eq!(
+            at_or_above
+                .outcome_counts
+                .get(&ProbabilityOutcome::new_with_constraints(
+                    2,
+                    vec![Constraint::new_single_valid_value_constraint(4, 2)]
+                )),
+            Some(&1)
+        );
+        assert_eq!(
+            below
+                .outcome_counts
+                .get(&ProbabilityOutcome::new_with_constraints(
+                    1,
+                    vec![Constraint::new_single_valid_value_constraint(4, 1)]
+                )),
+            Some(&1)
+        );
+    }
+}
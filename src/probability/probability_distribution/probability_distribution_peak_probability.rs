@@ -0,0 +1,86 @@
+use crate::probability::ProbabilityDistribution;
+
+use super::collapse_outcome_to_count_map;
+
+impl ProbabilityDistribution {
+    /// Returns the probability of the most likely value in the [ProbabilityDistribution].
+    /// outcomes sharing a value but differing in constraints are counted together.
+    ///
+    /// # Arguments
+    ///
+    /// * `self` - The [ProbabilityDistribution] to get the peak probability from.
+    ///
+    /// # Returns
+    ///
+    /// The highest count of any single value divided by the total outcome count,
+    /// or `None` if the [ProbabilityDistribution] is empty.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use crate::rusted_dice::probability::ProbabilityDistribution;
+    /// let two_d6 = ProbabilityDistribution::new_multiple_dice(2, 6);
+    /// assert_eq!(two_d6.peak_probability(), Some(6.0 / 36.0));
+    ///
+    /// let empty = ProbabilityDistribution::new_empty_distribution();
+    /// assert_eq!(empty.peak_probability(), None);
+    /// ```
+    pub fn peak_probability(&self) -> Option<f64> {
+        let peak_count = collapse_outcome_to_count_map(&self.outcome_counts)
+            .into_values()
+            .max()?;
+        Some(peak_count as f64 / self.total_outcome_count() as f64)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::constraint_management::Constraint;
+    use crate::probability::{ProbabilityDistribution, ProbabilityOutcome};
+
+    #[test]
+    fn test_empty() {
+        let probability_distribution = ProbabilityDistribution::new_empty_distribution();
+        assert_eq!(probability_distribution.peak_probability(), None);
+    }
+
+    #[test]
+    fn test_single_outcome() {
+        let probability_distribution = ProbabilityDistribution::new_from_single_probability_outcome(
+            ProbabilityOutcome::new_with_empty_constraint_map(12),
+        );
+        assert_eq!(probability_distribution.peak_probability(), Some(1.0));
+    }
+
+    #[test]
+    fn test_dice() {
+        let probability_distribution = ProbabilityDistribution::new_dice(4);
+        assert_eq!(probability_distribution.peak_probability(), Some(0.25));
+    }
+
+    #[test]
+    fn test_two_d6() {
+        let probability_distribution = ProbabilityDistribution::new_multiple_dice(2, 6);
+        assert_eq!(
+            probability_distribution.peak_probability(),
+            Some(6.0 / 36.0)
+        );
+    }
+
+    #[test]
+    fn test_constrained_outcomes_share_value() {
+        let probability_distribution =
+            ProbabilityDistribution::new_from_many_probability_outcomes(vec![
+                ProbabilityOutcome::new_with_empty_constraint_map(1),
+                ProbabilityOutcome::new_with_constraints(
+                    2,
+                    vec![Constraint::new_single_valid_value_constraint(1, 1)],
+                ),
+                ProbabilityOutcome::new_with_constraints(
+                    2,
+                    vec![Constraint::new_single_valid_value_constraint(1, 2)],
+                ),
+            ]);
+        assert_eq!(probability_distribution.peak_probability(), Some(2.0 / 3.0));
+    }
+}
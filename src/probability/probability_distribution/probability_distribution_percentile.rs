@@ -0,0 +1,200 @@
+use crate::probability::ProbabilityDistribution;
+use crate::ValueType;
+
+use super::collapse_outcome_to_count_map;
+
+/// a helper function to write a fraction as its shortest decimal form `numerator / 10^power`,
+/// for example `0.07` is `(7, 2)`, the fraction has to be in `[0.0, 1.0]`.
+fn decimal_fraction(fraction: f64) -> (u128, u32) {
+    let formatted = format!("{:e}", fraction);
+    let (mantissa, exponent) = formatted
+        .split_once('e')
+        .expect("Formatted f64 has an exponent.");
+    let exponent: i32 = exponent
+        .parse()
+        .expect("Formatted f64 exponent is a number.");
+    let (whole, decimals) = mantissa.split_once('.').unwrap_or((mantissa, ""));
+    let numerator: u128 = format!("{}{}", whole, decimals)
+        .parse()
+        .expect("Formatted f64 mantissa is a number.");
+    let power = decimals.len() as i32 - exponent;
+    (numerator, power.max(0) as u32)
+}
+
+impl ProbabilityDistribution {
+    /// Returns the p-th percentile value of the [ProbabilityDistribution].
+    /// outcomes sharing a value but differing in constraints are counted together.
+    ///
+    /// uses the nearest-rank rule, the p-th percentile is the lowest value whose cumulative count
+    /// is at least the rank `max(ceil(p * total count), 1)`, no interpolation between values is done.
+    /// so the 0.0 percentile is the lowest value and the 1.0 percentile the highest.
+    ///
+    /// the rank is calculated exactly in integers from the shortest decimal form of `p`,
+    /// so `0.07` of a total count of 100 is rank 7.
+    ///
+    /// # Arguments
+    ///
+    /// * `p` - The [f64] fraction of the total count, clamped into `[0.0, 1.0]`.
+    ///
+    /// # Returns
+    ///
+    /// The percentile [ValueType], or `None` if the [ProbabilityDistribution] is empty.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use crate::rusted_dice::probability::ProbabilityDistribution;
+    /// let three_d6 = ProbabilityDistribution::new_multiple_dice(3, 6);
+    /// assert_eq!(three_d6.percentile(0.9), Some(14));
+    /// assert_eq!(three_d6.percentile(0.0), Some(3));
+    /// assert_eq!(three_d6.percentile(1.0), Some(18));
+    ///
+    /// let empty = ProbabilityDistribution::new_empty_distribution();
+    /// assert_eq!(empty.percentile(0.5), None);
+    /// ```
+    pub fn percentile(&self, p: f64) -> Option<ValueType> {
+        let p = if p.is_nan() { 0.0 } else { p.clamp(0.0, 1.0) };
+        let total = self.total_outcome_count();
+        let (numerator, power) = decimal_fraction(p);
+        // the numerator has at most 17 digits, so the product is below 10^37
+        let product = u128::from(total) * numerator;
+        let rank = match 10_u128.checked_pow(power) {
+            Some(denominator) => product.div_ceil(denominator),
+            None => u128::from(product > 0),
+        }
+        .max(1);
+
+        let mut cumulative_count: u128 = 0;
+        for (value, count) in collapse_outcome_to_count_map(&self.outcome_counts) {
+            cumulative_count += u128::from(count);
+            if cumulative_count >= rank {
+                return Some(value);
+            }
+        }
+        None
+    }
+
+    /// Returns the median value of the [ProbabilityDistribution], its 0.5 [ProbabilityDistribution::percentile].
+    /// uses the nearest-rank rule, so the median is always a value of the [ProbabilityDistribution],
+    /// the lower of the two middle values when the total count is even and they differ.
+    ///
+    /// # Returns
+    ///
+    /// The median [ValueType], or `None` if the [ProbabilityDistribution] is empty.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use crate::rusted_dice::probability::ProbabilityDistribution;
+    /// assert_eq!(ProbabilityDistribution::new_multiple_dice(3, 6).median(), Some(10));
+    /// assert_eq!(ProbabilityDistribution::new_dice(6).median(), Some(3));
+    /// ```
+    pub fn median(&self) -> Option<ValueType> {
+        self.percentile(0.5)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::constraint_management::Constraint;
+    use crate::probability::{ProbabilityDistribution, ProbabilityOutcome};
+    use crate::CountType;
+
+    use super::decimal_fraction;
+
+    #[test]
+    fn test_decimal_fraction() {
+        assert_eq!(decimal_fraction(0.0), (0, 0));
+        assert_eq!(decimal_fraction(1.0), (1, 0));
+        assert_eq!(decimal_fraction(0.5), (5, 1));
+        assert_eq!(decimal_fraction(0.07), (7, 2));
+        assert_eq!(decimal_fraction(0.125), (125, 3));
+        assert_eq!(decimal_fraction(1e-300), (1, 300));
+        assert_eq!(decimal_fraction(1.0 / 3.0), (3333333333333333, 16));
+    }
+
+    #[test]
+    fn test_percentile_empty() {
+        let probability_distribution = ProbabilityDistribution::new_empty_distribution();
+        assert_eq!(probability_distribution.percentile(0.0), None);
+        assert_eq!(probability_distribution.percentile(1.0), None);
+        assert_eq!(probability_distribution.median(), None);
+    }
+
+    #[test]
+    fn test_percentile_three_d6() {
+        let three_d6 = ProbabilityDistribution::new_multiple_dice(3, 6);
+        assert_eq!(three_d6.percentile(0.5), Some(10));
+        assert_eq!(three_d6.median(), Some(10));
+        assert_eq!(three_d6.percentile(0.9), Some(14));
+        // the cumulative count of 10 is exactly half of 216
+        assert_eq!(three_d6.percentile(108.0 / 216.0), Some(10));
+        assert_eq!(three_d6.percentile(109.0 / 216.0), Some(11));
+    }
+
+    #[test]
+    fn test_percentile_clamped() {
+        let dice = ProbabilityDistribution::new_dice(20);
+        assert_eq!(dice.percentile(-3.0), Some(1));
+        assert_eq!(dice.percentile(7.0), Some(20));
+        assert_eq!(dice.percentile(f64::NAN), Some(1));
+    }
+
+    #[test]
+    fn test_percentile_single_outcome() {
+        let probability_distribution = ProbabilityDistribution::new_from_single_probability_outcome(
+            ProbabilityOutcome::new_with_empty_constraint_map(-2),
+        );
+        for p in [0.0, 0.3, 0.5, 1.0] {
+            assert_eq!(probability_distribution.percentile(p), Some(-2));
+        }
+    }
+
+    #[test]
+    fn test_percentile_merges_constraints() {
+        let probability_distribution =
+            ProbabilityDistribution::new_from_many_probability_outcomes(vec![
+                ProbabilityOutcome::new_with_empty_constraint_map(1),
+                ProbabilityOutcome::new_with_constraints(
+                    1,
+                    vec![Constraint::new_single_valid_value_constraint(1, 1)],
+                ),
+                ProbabilityOutcome::new_with_empty_constraint_map(2),
+            ]);
+        assert_eq!(probability_distribution.median(), Some(1));
+        assert_eq!(probability_distribution.percentile(0.7), Some(2));
+    }
+
+    #[test]
+    fn test_percentile_exact_decimal_rank() {
+        let dice = ProbabilityDistribution::new_dice(100);
+        assert_eq!(dice.percentile(0.07), Some(7));
+        assert_eq!(dice.percentile(0.28), Some(28));
+        assert_eq!(dice.percentile(0.56), Some(56));
+        assert_eq!(dice.percentile(0.071), Some(8));
+        assert_eq!(dice.percentile(1e-300), Some(1));
+    }
+
+    #[test]
+    fn test_percentile_large_total() {
+        // a total count above 2^53 can not be represented exactly as a f64
+        let lower_count: CountType = (1 << 56) + 1;
+        let upper_count: CountType = 9 * (1 << 56) + 10;
+        let mut probability_distribution = ProbabilityDistribution::new_empty_distribution();
+        probability_distribution.outcome_counts.insert(
+            ProbabilityOutcome::new_with_empty_constraint_map(1),
+            lower_count,
+        );
+        probability_distribution.outcome_counts.insert(
+            ProbabilityOutcome::new_with_empty_constraint_map(2),
+            upper_count,
+        );
+        assert_eq!(probability_distribution.percentile(0.1), Some(2));
+    }
+
+    #[test]
+    fn test_median_even_total() {
+        let probability_distribution = ProbabilityDistribution::new_dice(4);
+        assert_eq!(probability_distribution.median(), Some(2));
+    }
+}
@@ -0,0 +1,672 @@
+use std::collections::BTreeMap;
+
+use crate::probability::ProbabilityDistribution;
+use crate::probability::ProbabilityOutcome;
+use crate::probability::{OutcomeToCountMap, PoolAggregator};
+use crate::{CountType, ValueType};
+
+use super::{add_outcome_to_map, binomial_coefficient};
+
+/// a helper function to walk every sorted combination of faces for a pool of dice.
+/// each combination is aggregated and added to the map with the number of ordered rolls producing it.
+fn add_pool_combinations_to_map(
+    outcome_to_count_map: &mut OutcomeToCountMap,
+    faces: &[ValueType],
+    remaining_dice: u16,
+    sorted_faces: &mut Vec<ValueType>,
+    ordered_roll_count: CountType,
+    aggregator: &dyn PoolAggregator,
+) {
+    match faces.split_first() {
+        None => {
+            if remaining_dice == 0 {
+                add_outcome_to_map(
+                    outcome_to_count_map,
+                    ProbabilityOutcome::new_with_empty_constraint_map(
+                        aggregator.aggregate(sorted_faces),
+                    ),
+                    ordered_roll_count,
+                );
+            }
+        }
+        Some((face, other_faces)) => {
+            for repeats in 0..=remaining_dice {
+                sorted_faces.extend(std::iter::repeat_n(*face, repeats.into()));
+                add_pool_combinations_to_map(
+                    outcome_to_count_map,
+                    other_faces,
+                    remaining_dice - repeats,
+                    sorted_faces,
+                    ordered_roll_count * binomial_coefficient(remaining_dice, repeats),
+                    aggregator,
+                );
+                sorted_faces.truncate(sorted_faces.len() - usize::from(repeats));
+            }
+        }
+    }
+}
+
+#[allow(dead_code)]
+impl ProbabilityDistribution {
+    /// Creates a new [ProbabilityDistribution] by rolling M, N sided dice and reducing the faces with a [PoolAggregator].
+    /// every combination of faces is enumerated once, sorted ascending,
+    /// and counted by the number of ordered rolls that produce it.
+    ///
+    /// for negative n, the dice have vales from -1 to -n.
+    ///
+    /// for Zero n, the [ProbabilityDistribution] will have no [ProbabilityOutcome]s.
+    ///
+    /// for Zero m, the [ProbabilityDistribution] will have no [ProbabilityOutcome]s.
+    ///
+    /// # Arguments
+    ///
+    /// * `number_of_dice` - [u16] The the number of dice to roll.
+    /// * `number_of_sides` - [ValueType] The the number of sides the dice has.
+    /// * `aggregator` - The [PoolAggregator] used to reduce the faces to a single value.
+    ///
+    /// # Returns
+    ///
+    /// The new [ProbabilityDistribution].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use crate::rusted_dice::probability::ProbabilityDistribution;
+    /// # use crate::rusted_dice::probability::ToTable;
+    /// # use crate::rusted_dice::probability::pool_aggregator::KeepHighest;
+    /// let two_d3_keep_highest = ProbabilityDistribution::new_pool(2, 3, &KeepHighest(1));
+    ///
+    /// assert_eq!(
+    ///     two_d3_keep_highest
+    ///         .to_table()
+    ///         .to_string()
+    ///         .replace("\r\n", "\n"),
+    ///     "\
+    ///     +-------+-------+\n\
+    ///     | value | count |\n\
+    ///     +=======+=======+\n\
+    ///     | 1     | 1     |\n\
+    ///     +-------+-------+\n\
+    ///     | 2     | 3     |\n\
+    ///     +-------+-------+\n\
+    ///     | 3     | 5     |\n\
+    ///     +-------+-------+\n\
+    ///     ");
+    /// ```
+    pub fn new_pool(
+        number_of_dice: u16,
+        number_of_sides: ValueType,
+        aggregator: &dyn PoolAggregator,
+    ) -> ProbabilityDistribution {
+        if number_of_dice == 0 || number_of_sides == 0 {
+            return ProbabilityDistribution::new_empty_distribution();
+        }
+
+        let faces: Vec<ValueType> = ProbabilityDistribution::new_dice(number_of_sides)
+            .outcome_counts
+            .keys()
+            .map(|outcome| outcome.value)
+            .collect();
+
+        let mut map = BTreeMap::new();
+        add_pool_combinations_to_map(
+            &mut map,
+            &faces,
+            number_of_dice,
+            &mut Vec::with_capacity(number_of_dice.into()),
+            1,
+            aggregator,
+        );
+
+        ProbabilityDistribution {
+            outcome_counts: map,
+        }
+    }
+
+    /// Creates a new [ProbabilityDistribution] of the highest single dice shown when rolling M, N sided dice.
+    /// the count of each value k is the number of ordered rolls whose highest dice is k,
+    /// using the closed form `P(max <= k) = (k/n)^m`, so the count of k is `k^m - (k-1)^m`.
+    ///
+    /// for non-positive n, the [ProbabilityDistribution] will have no [ProbabilityOutcome]s.
+    ///
+    /// for Zero m, the [ProbabilityDistribution] will have no [ProbabilityOutcome]s.
+    ///
+    /// # Arguments
+    ///
+    /// * `number_of_dice` - [u16] The the number of dice to roll.
+    /// * `number_of_sides` - [ValueType] The the number of sides the dice has.
+    ///
+    /// # Returns
+    ///
+    /// The new [ProbabilityDistribution].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use crate::rusted_dice::probability::ProbabilityDistribution;
+    /// # use crate::rusted_dice::probability::ToTable;
+    /// let two_d4_max = ProbabilityDistribution::new_pool_max(2, 4);
+    ///
+    /// assert_eq!(
+    ///     two_d4_max
+    ///         .to_table()
+    ///         .to_string()
+    ///         .replace("\r\n", "\n"),
+    ///     "\
+    ///     +-------+-------+\n\
+    ///     | value | count |\n\
+    ///     +=======+=======+\n\
+    ///     | 1     | 1     |\n\
+    ///     +-------+-------+\n\
+    ///     | 2     | 3     |\n\
+    ///     +-------+-------+\n\
+    ///     | 3     | 5     |\n\
+    ///     +-------+-------+\n\
+    ///     | 4     | 7     |\n\
+    ///     +-------+-------+\n\
+    ///     ");
+    /// ```
+    pub fn new_pool_max(
+        number_of_dice: u16,
+        number_of_sides: ValueType,
+    ) -> ProbabilityDistribution {
+        if number_of_dice == 0 {
+            return ProbabilityDistribution::new_empty_distribution();
+        }
+
+        let mut map = BTreeMap::new();
+
+        for value in 1..=number_of_sides {
+            let at_most_value = CountType::pow(value as CountType, number_of_dice.into());
+            let below_value = CountType::pow((value - 1) as CountType, number_of_dice.into());
+            add_outcome_to_map(
+                &mut map,
+                ProbabilityOutcome::new_with_empty_constraint_map(value),
+                at_most_value - below_value,
+            );
+        }
+
+        ProbabilityDistribution {
+            outcome_counts: map,
+        }
+    }
+
+    /// Creates a new [ProbabilityDistribution] of the lowest single dice shown when rolling M, N sided dice.
+    /// the count of each value k is the number of ordered rolls whose lowest dice is k,
+    /// using the closed form `P(min >= k) = ((n-k+1)/n)^m`, so the count of k is `(n-k+1)^m - (n-k)^m`.
+    ///
+    /// for non-positive n, the [ProbabilityDistribution] will have no [ProbabilityOutcome]s.
+    ///
+    /// for Zero m, the [ProbabilityDistribution] will have no [ProbabilityOutcome]s.
+    ///
+    /// # Arguments
+    ///
+    /// * `number_of_dice` - [u16] The the number of dice to roll.
+    /// * `number_of_sides` - [ValueType] The the number of sides the dice has.
+    ///
+    /// # Returns
+    ///
+    /// The new [ProbabilityDistribution].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use crate::rusted_dice::probability::ProbabilityDistribution;
+    /// # use crate::rusted_dice::probability::ToTable;
+    /// let two_d4_min = ProbabilityDistribution::new_pool_min(2, 4);
+    ///
+    /// assert_eq!(
+    ///     two_d4_min
+    ///         .to_table()
+    ///         .to_string()
+    ///         .replace("\r\n", "\n"),
+    ///     "\
+    ///     +-------+-------+\n\
+    ///     | value | count |\n\
+    ///     +=======+=======+\n\
+    ///     | 1     | 7     |\n\
+    ///     +-------+-------+\n\
+    ///     | 2     | 5     |\n\
+    ///     +-------+-------+\n\
+    ///     | 3     | 3     |\n\
+    ///     +-------+-------+\n\
+    ///     | 4     | 1     |\n\
+    ///     +-------+-------+\n\
+    ///     ");
+    /// ```
+    pub fn new_pool_min(
+        number_of_dice: u16,
+        number_of_sides: ValueType,
+    ) -> ProbabilityDistribution {
+        if number_of_dice == 0 {
+            return ProbabilityDistribution::new_empty_distribution();
+        }
+
+        let mut map = BTreeMap::new();
+
+        for value in 1..=number_of_sides {
+            let at_least_value = CountType::pow(
+                (number_of_sides - value + 1) as CountType,
+                number_of_dice.into(),
+            );
+            let above_value = CountType::pow(
+                (number_of_sides - value) as CountType,
+                number_of_dice.into(),
+            );
+            add_outcome_to_map(
+                &mut map,
+                ProbabilityOutcome::new_with_empty_constraint_map(value),
+                at_least_value - above_value,
+            );
+        }
+
+        ProbabilityDistribution {
+            outcome_counts: map,
+        }
+    }
+
+    /// Creates a new [ProbabilityDistribution] of the dice at `position` when rolling M, N sided dice
+    /// and sorting them from lowest to highest, position 1 is the lowest dice and position m the highest.
+    ///
+    /// uses the order statistic closed form, the number of ordered rolls where the dice at `position`
+    /// is at most k is the number of rolls where at least `position` dice are at most k,
+    /// `sum over j from position to m of C(m, j) * k^j * (n-k)^(m-j)`,
+    /// the count of k is the difference between that sum for k and for k - 1.
+    ///
+    /// for non-positive n, the [ProbabilityDistribution] will have no [ProbabilityOutcome]s.
+    ///
+    /// for Zero m, a `position` of Zero or a `position` greater than m,
+    /// the [ProbabilityDistribution] will have no [ProbabilityOutcome]s.
+    ///
+    /// # Arguments
+    ///
+    /// * `number_of_dice` - [u16] The the number of dice to roll.
+    /// * `number_of_sides` - [ValueType] The the number of sides the dice has.
+    /// * `position` - [u16] The position of the dice in the sorted roll, starting at 1.
+    ///
+    /// # Returns
+    ///
+    /// The new [ProbabilityDistribution].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use crate::rusted_dice::probability::ProbabilityDistribution;
+    /// # use crate::rusted_dice::probability::ToTable;
+    /// let three_d4_middle = ProbabilityDistribution::order_statistic(3, 4, 2);
+    ///
+    /// assert_eq!(
+    ///     three_d4_middle
+    ///         .to_table()
+    ///         .to_string()
+    ///         .replace("\r\n", "\n"),
+    ///     "\
+    ///     +-------+-------+\n\
+    ///     | value | count |\n\
+    ///     +=======+=======+\n\
+    ///     | 1     | 10    |\n\
+    ///     +-------+-------+\n\
+    ///     | 2     | 22    |\n\
+    ///     +-------+-------+\n\
+    ///     | 3     | 22    |\n\
+    ///     +-------+-------+\n\
+    ///     | 4     | 10    |\n\
+    ///     +-------+-------+\n\
+    ///     ");
+    /// ```
+    pub fn order_statistic(
+        number_of_dice: u16,
+        number_of_sides: ValueType,
+        position: u16,
+    ) -> ProbabilityDistribution {
+        if position == 0 || position > number_of_dice {
+            return ProbabilityDistribution::new_empty_distribution();
+        }
+
+        let at_most_value = |value: ValueType| -> CountType {
+            (position..=number_of_dice)
+                .map(|at_most_count| {
+                    binomial_coefficient(number_of_dice, at_most_count)
+                        * CountType::pow(value as CountType, at_most_count.into())
+                        * CountType::pow(
+                            (number_of_sides - value) as CountType,
+                            (number_of_dice - at_most_count).into(),
+                        )
+                })
+                .sum()
+        };
+
+        let mut map = BTreeMap::new();
+
+        for value in 1..=number_of_sides {
+            add_outcome_to_map(
+                &mut map,
+                ProbabilityOutcome::new_with_empty_constraint_map(value),
+                at_most_value(value) - at_most_value(value - 1),
+            );
+        }
+
+        ProbabilityDistribution {
+            outcome_counts: map,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::probability::pool_aggregator::{CountSuccesses, KeepHighest, KeepLowest, SumAll};
+    use crate::probability::{
+        PoolAggregator, ProbabilityDistribution, ProbabilityOutcome, ToTable,
+    };
+    use crate::ValueType;
+
+    struct KeepMiddle;
+
+    impl PoolAggregator for KeepMiddle {
+        fn aggregate(&self, sorted_faces: &[ValueType]) -> ValueType {
+            sorted_faces[sorted_faces.len() / 2]
+        }
+    }
+
+    #[test]
+    fn test_new_pool_zero_dice() {
+        let probability_distribution = ProbabilityDistribution::new_pool(0, 6, &SumAll);
+        assert_eq!(probability_distribution.outcome_counts.len(), 0);
+    }
+
+    #[test]
+    fn test_new_pool_zero_sides() {
+        let probability_distribution = ProbabilityDistribution::new_pool(3, 0, &SumAll);
+        assert_eq!(probability_distribution.outcome_counts.len(), 0);
+    }
+
+    #[test]
+    fn test_new_pool_sum_all_matches_new_multiple_dice() {
+        for (number_of_dice, number_of_sides) in [(1, 6), (2, 6), (3, 4), (4, 6), (3, -4)] {
+            assert_eq!(
+                ProbabilityDistribution::new_pool(number_of_dice, number_of_sides, &SumAll)
+                    .outcome_counts,
+                ProbabilityDistribution::new_multiple_dice(number_of_dice, number_of_sides)
+                    .outcome_counts
+            );
+        }
+    }
+
+    #[test]
+    fn test_new_pool_keep_highest_one_matches_new_pool_max() {
+        for (number_of_dice, number_of_sides) in [(1, 6), (2, 6), (3, 4), (4, 8)] {
+            assert_eq!(
+                ProbabilityDistribution::new_pool(number_of_dice, number_of_sides, &KeepHighest(1))
+                    .outcome_counts,
+                ProbabilityDistribution::new_pool_max(number_of_dice, number_of_sides)
+                    .outcome_counts
+            );
+        }
+    }
+
+    #[test]
+    fn test_new_pool_keep_lowest_one_matches_new_pool_min() {
+        for (number_of_dice, number_of_sides) in [(1, 6), (2, 6), (3, 4), (4, 8)] {
+            assert_eq!(
+                ProbabilityDistribution::new_pool(number_of_dice, number_of_sides, &KeepLowest(1))
+                    .outcome_counts,
+                ProbabilityDistribution::new_pool_min(number_of_dice, number_of_sides)
+                    .outcome_counts
+            );
+        }
+    }
+
+    #[test]
+    fn test_new_pool_keep_highest_three_of_four_d6() {
+        let probability_distribution = ProbabilityDistribution::new_pool(4, 6, &KeepHighest(3));
+
+        assert_eq!(probability_distribution.total_outcome_count(), 1296);
+        assert_eq!(
+            probability_distribution
+                .outcome_counts
+                .get(&ProbabilityOutcome::new_with_empty_constraint_map(18)),
+            Some(&21)
+        );
+        assert_eq!(
+            probability_distribution
+                .outcome_counts
+                .get(&ProbabilityOutcome::new_with_empty_constraint_map(3)),
+            Some(&1)
+        );
+    }
+
+    #[test]
+    fn test_new_pool_count_successes() {
+        let probability_distribution = ProbabilityDistribution::new_pool(3, 6, &CountSuccesses(5));
+
+        assert_eq!(
+            probability_distribution
+                .to_table()
+                .to_string()
+                .replace("\r\n", "\n"),
+            "\
+            +-------+-------+\n\
+            | value | count |\n\
+            +=======+=======+\n\
+            | 0     | 64    |\n\
+            +-------+-------+\n\
+            | 1     | 96    |\n\
+            +-------+-------+\n\
+            | 2     | 48    |\n\
+            +-------+-------+\n\
+            | 3     | 8     |\n\
+            +-------+-------+\n\
+            "
+        );
+    }
+
+    #[test]
+    fn test_new_pool_max_zero_dice() {
+        let probability_distribution = ProbabilityDistribution::new_pool_max(0, 6);
+        assert_eq!(probability_distribution.outcome_counts.len(), 0);
+    }
+
+    #[test]
+    fn test_new_pool_max_zero_sides() {
+        let probability_distribution = ProbabilityDistribution::new_pool_max(3, 0);
+        assert_eq!(probability_distribution.outcome_counts.len(), 0);
+    }
+
+    #[test]
+    fn test_new_pool_max_negative_sides() {
+        let probability_distribution = ProbabilityDistribution::new_pool_max(3, -6);
+        assert_eq!(probability_distribution.outcome_counts.len(), 0);
+    }
+
+    #[test]
+    fn test_new_pool_max_one_dice() {
+        let probability_distribution = ProbabilityDistribution::new_pool_max(1, 6);
+        assert_eq!(
+            probability_distribution.to_table(),
+            ProbabilityDistribution::new_dice(6).to_table()
+        );
+    }
+
+    #[test]
+    fn test_new_pool_max_two_d6() {
+        let probability_distribution = ProbabilityDistribution::new_pool_max(2, 6);
+
+        assert_eq!(
+            probability_distribution
+                .outcome_counts
+                .get(&ProbabilityOutcome::new_with_empty_constraint_map(6)),
+            Some(&11)
+        );
+        assert_eq!(probability_distribution.total_outcome_count(), 36);
+        assert_eq!(
+            probability_distribution
+                .to_table()
+                .to_string()
+                .replace("\r\n", "\n"),
+            "\
+            +-------+-------+\n\
+            | value | count |\n\
+            +=======+=======+\n\
+            | 1     | 1     |\n\
+            +-------+-------+\n\
+            | 2     | 3     |\n\
+            +-------+-------+\n\
+            | 3     | 5     |\n\
+            +-------+-------+\n\
+            | 4     | 7     |\n\
+            +-------+-------+\n\
+            | 5     | 9     |\n\
+            +-------+-------+\n\
+            | 6     | 11    |\n\
+            +-------+-------+\n\
+            "
+        );
+    }
+
+    #[test]
+    fn test_new_pool_max_three_d4() {
+        let probability_distribution = ProbabilityDistribution::new_pool_max(3, 4);
+
+        assert_eq!(
+            probability_distribution
+                .to_table()
+                .to_string()
+                .replace("\r\n", "\n"),
+            "\
+            +-------+-------+\n\
+            | value | count |\n\
+            +=======+=======+\n\
+            | 1     | 1     |\n\
+            +-------+-------+\n\
+            | 2     | 7     |\n\
+            +-------+-------+\n\
+            | 3     | 19    |\n\
+            +-------+-------+\n\
+            | 4     | 37    |\n\
+            +-------+-------+\n\
+            "
+        );
+    }
+
+    #[test]
+    fn test_new_pool_min_zero_dice() {
+        let probability_distribution = ProbabilityDistribution::new_pool_min(0, 6);
+        assert_eq!(probability_distribution.outcome_counts.len(), 0);
+    }
+
+    #[test]
+    fn test_new_pool_min_zero_sides() {
+        let probability_distribution = ProbabilityDistribution::new_pool_min(3, 0);
+        assert_eq!(probability_distribution.outcome_counts.len(), 0);
+    }
+
+    #[test]
+    fn test_new_pool_min_negative_sides() {
+        let probability_distribution = ProbabilityDistribution::new_pool_min(3, -6);
+        assert_eq!(probability_distribution.outcome_counts.len(), 0);
+    }
+
+    #[test]
+    fn test_new_pool_min_one_dice() {
+        let probability_distribution = ProbabilityDistribution::new_pool_min(1, 6);
+        assert_eq!(
+            probability_distribution.to_table(),
+            ProbabilityDistribution::new_dice(6).to_table()
+        );
+    }
+
+    #[test]
+    fn test_new_pool_min_two_d6() {
+        let probability_distribution = ProbabilityDistribution::new_pool_min(2, 6);
+
+        assert_eq!(
+            probability_distribution
+                .outcome_counts
+                .get(&ProbabilityOutcome::new_with_empty_constraint_map(1)),
+            Some(&11)
+        );
+        assert_eq!(probability_distribution.total_outcome_count(), 36);
+    }
+
+    #[test]
+    fn test_new_pool_min_mirrors_new_pool_max() {
+        let pool_min = ProbabilityDistribution::new_pool_min(3, 8);
+        let pool_max = ProbabilityDistribution::new_pool_max(3, 8);
+
+        for value in 1..=8 {
+            assert_eq!(
+                pool_min
+                    .outcome_counts
+                    .get(&ProbabilityOutcome::new_with_empty_constraint_map(value)),
+                pool_max
+                    .outcome_counts
+                    .get(&ProbabilityOutcome::new_with_empty_constraint_map(
+                        9 - value
+                    ))
+            );
+        }
+    }
+
+    #[test]
+    fn test_order_statistic_invalid_position() {
+        assert_eq!(
+            ProbabilityDistribution::order_statistic(3, 6, 0)
+                .outcome_counts
+                .len(),
+            0
+        );
+        assert_eq!(
+            ProbabilityDistribution::order_statistic(3, 6, 4)
+                .outcome_counts
+                .len(),
+            0
+        );
+        assert_eq!(
+            ProbabilityDistribution::order_statistic(0, 6, 1)
+                .outcome_counts
+                .len(),
+            0
+        );
+    }
+
+    #[test]
+    fn test_order_statistic_non_positive_sides() {
+        assert_eq!(
+            ProbabilityDistribution::order_statistic(3, 0, 1)
+                .outcome_counts
+                .len(),
+            0
+        );
+        assert_eq!(
+            ProbabilityDistribution::order_statistic(3, -6, 1)
+                .outcome_counts
+                .len(),
+            0
+        );
+    }
+
+    #[test]
+    fn test_order_statistic_matches_new_pool_min_and_max() {
+        for number_of_dice in 1..5 {
+            assert_eq!(
+                ProbabilityDistribution::order_statistic(number_of_dice, 6, 1).to_table(),
+                ProbabilityDistribution::new_pool_min(number_of_dice, 6).to_table()
+            );
+            assert_eq!(
+                ProbabilityDistribution::order_statistic(number_of_dice, 6, number_of_dice)
+                    .to_table(),
+                ProbabilityDistribution::new_pool_max(number_of_dice, 6).to_table()
+            );
+        }
+    }
+
+    #[test]
+    fn test_order_statistic_matches_new_pool() {
+        let middle_dice = ProbabilityDistribution::order_statistic(5, 6, 3);
+        assert_eq!(
+            middle_dice.to_table(),
+            ProbabilityDistribution::new_pool(5, 6, &KeepMiddle).to_table()
+        );
+        assert_eq!(middle_dice.total_outcome_count(), 7776);
+    }
+}
@@ -0,0 +1,170 @@
+use crate::probability::ProbabilityDistribution;
+use crate::ValueType;
+
+impl ProbabilityDistribution {
+    /// Returns the probability of rolling exactly `k` successes when rolling M, N sided dice,
+    /// a success being a dice showing at least `target`, as counted by
+    /// [CountSuccesses](crate::probability::pool_aggregator::CountSuccesses).
+    ///
+    /// with s successful faces, the probability is the binomial term `C(m, k) * s^k * (n - s)^(m - k) / n^m`.
+    ///
+    /// for non-positive n, the probability is 0.0.
+    ///
+    /// for `k` greater than m, the probability is 0.0.
+    ///
+    /// # Arguments
+    ///
+    /// * `number_of_dice` - [u16] The the number of dice to roll.
+    /// * `number_of_sides` - [ValueType] The the number of sides the dice has.
+    /// * `target` - The lowest [ValueType] face that counts as a success.
+    /// * `k` - [u16] The exact number of successes.
+    ///
+    /// # Returns
+    ///
+    /// The probability as a [f64].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use crate::rusted_dice::probability::ProbabilityDistribution;
+    /// // exactly two 6s in 5d6
+    /// assert_eq!(
+    ///     ProbabilityDistribution::probability_exactly_successes(5, 6, 6, 2),
+    ///     1250.0 / 7776.0
+    /// );
+    /// ```
+    pub fn probability_exactly_successes(
+        number_of_dice: u16,
+        number_of_sides: ValueType,
+        target: ValueType,
+        k: u16,
+    ) -> f64 {
+        if number_of_sides <= 0 || k > number_of_dice {
+            return 0.0;
+        }
+
+        let sides = i64::from(number_of_sides);
+        let successful_faces = (sides - i64::from(target) + 1).clamp(0, sides) as u128;
+        let failing_faces = sides as u128 - successful_faces;
+        let failures = u32::from(number_of_dice - k);
+
+        let exact = (0..u128::from(k.min(number_of_dice - k)))
+            .try_fold(1_u128, |ways, i| {
+                Some(ways.checked_mul(u128::from(number_of_dice) - i)? / (i + 1))
+            })
+            .and_then(|ways| {
+                Some((
+                    ways.checked_mul(successful_faces.checked_pow(k.into())?)?
+                        .checked_mul(failing_faces.checked_pow(failures)?)?,
+                    (sides as u128).checked_pow(number_of_dice.into())?,
+                ))
+            });
+
+        match exact {
+            Some((rolls, total)) => rolls as f64 / total as f64,
+            None => {
+                let success = successful_faces as f64 / sides as f64;
+                let ways = (0..k.min(number_of_dice - k)).fold(1.0, |ways, i| {
+                    ways * f64::from(number_of_dice - i) / f64::from(i + 1)
+                });
+                ways * success.powi(k.into()) * (1.0 - success).powi(failures as i32)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::probability::pool_aggregator::CountSuccesses;
+    use crate::probability::{ProbabilityDistribution, ProbabilityOutcome};
+    use crate::CountType;
+
+    use super::super::binomial_coefficient;
+
+    #[test]
+    fn test_probability_exactly_successes_impossible() {
+        assert_eq!(
+            ProbabilityDistribution::probability_exactly_successes(3, 0, 1, 1),
+            0.0
+        );
+        assert_eq!(
+            ProbabilityDistribution::probability_exactly_successes(3, -6, 1, 1),
+            0.0
+        );
+        assert_eq!(
+            ProbabilityDistribution::probability_exactly_successes(3, 6, 4, 4),
+            0.0
+        );
+    }
+
+    #[test]
+    fn test_probability_exactly_successes_zero_dice() {
+        assert_eq!(
+            ProbabilityDistribution::probability_exactly_successes(0, 6, 4, 0),
+            1.0
+        );
+    }
+
+    #[test]
+    fn test_probability_exactly_successes_target_outside_faces() {
+        assert_eq!(
+            ProbabilityDistribution::probability_exactly_successes(4, 6, 7, 0),
+            1.0
+        );
+        assert_eq!(
+            ProbabilityDistribution::probability_exactly_successes(4, 6, 1, 4),
+            1.0
+        );
+        assert_eq!(
+            ProbabilityDistribution::probability_exactly_successes(4, 6, -3, 2),
+            0.0
+        );
+    }
+
+    #[test]
+    fn test_probability_exactly_successes_closed_form() {
+        for k in 0..=5 {
+            let expected = binomial_coefficient(5, k) as f64
+                * (1.0_f64 / 3.0).powi(k.into())
+                * (2.0_f64 / 3.0).powi((5 - k).into());
+            assert!(
+                (ProbabilityDistribution::probability_exactly_successes(5, 6, 5, k) - expected)
+                    .abs()
+                    < 1e-12
+            );
+        }
+    }
+
+    #[test]
+    fn test_probability_exactly_successes_sums_to_one() {
+        for (number_of_dice, number_of_sides, target) in [(5, 6, 6), (10, 10, 7), (200, 20, 15)] {
+            let total: f64 = (0..=number_of_dice)
+                .map(|k| {
+                    ProbabilityDistribution::probability_exactly_successes(
+                        number_of_dice,
+                        number_of_sides,
+                        target,
+                        k,
+                    )
+                })
+                .sum();
+            assert!((total - 1.0).abs() < 1e-9);
+        }
+    }
+
+    #[test]
+    fn test_probability_exactly_successes_matches_new_pool() {
+        let pool = ProbabilityDistribution::new_pool(4, 6, &CountSuccesses(4));
+        let total = pool.total_outcome_count() as f64;
+        for k in 0..=4 {
+            let count: CountType = *pool
+                .outcome_counts
+                .get(&ProbabilityOutcome::new_with_empty_constraint_map(k.into()))
+                .unwrap();
+            assert_eq!(
+                ProbabilityDistribution::probability_exactly_successes(4, 6, 4, k),
+                count as f64 / total
+            );
+        }
+    }
+}
@@ -0,0 +1,186 @@
+use crate::probability::ProbabilityDistribution;
+use crate::{CountType, ValueType};
+
+impl ProbabilityDistribution {
+    /// Returns the probability that a roll of the [ProbabilityDistribution] is at least `target`.
+    /// for example the chance to hit an armour class of `target`.
+    ///
+    /// # Arguments
+    ///
+    /// * `target` - The [ValueType] the roll has to meet.
+    ///
+    /// # Returns
+    ///
+    /// The probability as a [f64], an empty [ProbabilityDistribution] returns 0.0.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use crate::rusted_dice::probability::ProbabilityDistribution;
+    /// let d20 = ProbabilityDistribution::new_dice(20);
+    /// assert_eq!(d20.probability_meets(15), 0.3);
+    /// ```
+    pub fn probability_meets(&self, target: ValueType) -> f64 {
+        self.probability_of(|value| value >= target)
+    }
+
+    /// Returns the probability that a roll of the [ProbabilityDistribution] is strictly greater than `target`.
+    ///
+    /// # Arguments
+    ///
+    /// * `target` - The [ValueType] the roll has to beat.
+    ///
+    /// # Returns
+    ///
+    /// The probability as a [f64], an empty [ProbabilityDistribution] returns 0.0.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use crate::rusted_dice::probability::ProbabilityDistribution;
+    /// let d20 = ProbabilityDistribution::new_dice(20);
+    /// assert_eq!(d20.probability_beats(15), 0.25);
+    /// ```
+    pub fn probability_beats(&self, target: ValueType) -> f64 {
+        self.probability_of(|value| value > target)
+    }
+
+    /// Returns the probability that a roll of the [ProbabilityDistribution] is at least `threshold`.
+    /// the same as [ProbabilityDistribution::probability_meets],
+    /// a `threshold` below every value returns 1.0 and above every value returns 0.0.
+    ///
+    /// # Arguments
+    ///
+    /// * `threshold` - The lowest [ValueType] counted.
+    ///
+    /// # Returns
+    ///
+    /// The probability as a [f64], an empty [ProbabilityDistribution] returns 0.0.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use crate::rusted_dice::probability::ProbabilityDistribution;
+    /// let d6 = ProbabilityDistribution::new_dice(6);
+    /// assert_eq!(d6.probability_at_least(4), 0.5);
+    /// assert_eq!(d6.probability_at_least(-10), 1.0);
+    /// ```
+    pub fn probability_at_least(&self, threshold: ValueType) -> f64 {
+        self.probability_of(|value| value >= threshold)
+    }
+
+    /// Returns the probability that a roll of the [ProbabilityDistribution] is at most `threshold`.
+    /// a `threshold` above every value returns 1.0 and below every value returns 0.0.
+    ///
+    /// # Arguments
+    ///
+    /// * `threshold` - The highest [ValueType] counted.
+    ///
+    /// # Returns
+    ///
+    /// The probability as a [f64], an empty [ProbabilityDistribution] returns 0.0.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use crate::rusted_dice::probability::ProbabilityDistribution;
+    /// let d6 = ProbabilityDistribution::new_dice(6);
+    /// assert_eq!(d6.probability_at_most(2), 2.0 / 6.0);
+    /// assert_eq!(d6.probability_at_most(0), 0.0);
+    /// ```
+    pub fn probability_at_most(&self, threshold: ValueType) -> f64 {
+        self.probability_of(|value| value <= threshold)
+    }
+
+    fn probability_of(&self, predicate: impl Fn(ValueType) -> bool) -> f64 {
+        let total = self.total_outcome_count();
+        if total == 0 {
+            return 0.0;
+        }
+        let matching: CountType = self
+            .outcome_counts
+            .iter()
+            .filter(|(outcome, _)| predicate(outcome.value))
+            .map(|(_, count)| count)
+            .sum();
+        matching as f64 / total as f64
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::probability::ProbabilityDistribution;
+
+    #[test]
+    fn test_probability_meets_empty() {
+        let probability_distribution = ProbabilityDistribution::new_empty_distribution();
+        assert_eq!(probability_distribution.probability_meets(1), 0.0);
+        assert_eq!(probability_distribution.probability_beats(1), 0.0);
+    }
+
+    #[test]
+    fn test_probability_meets_d20() {
+        let d20 = ProbabilityDistribution::new_dice(20);
+        assert_eq!(d20.probability_meets(15), 6.0 / 20.0);
+        assert_eq!(d20.probability_meets(1), 1.0);
+        assert_eq!(d20.probability_meets(20), 1.0 / 20.0);
+        assert_eq!(d20.probability_meets(21), 0.0);
+    }
+
+    #[test]
+    fn test_probability_beats_d20() {
+        let d20 = ProbabilityDistribution::new_dice(20);
+        assert_eq!(d20.probability_beats(15), 5.0 / 20.0);
+        assert_eq!(d20.probability_beats(0), 1.0);
+        assert_eq!(d20.probability_beats(20), 0.0);
+    }
+
+    #[test]
+    fn test_probability_meets_merges_constraints() {
+        let dice_one = ProbabilityDistribution::new_dice(6).add_self_value_constraint(1);
+        let dice_two = ProbabilityDistribution::new_dice(6).add_self_value_constraint(2);
+        let two_d6 = dice_one + dice_two;
+
+        assert_eq!(two_d6.probability_meets(10), 6.0 / 36.0);
+        assert_eq!(two_d6.probability_beats(10), 3.0 / 36.0);
+    }
+
+    #[test]
+    fn test_probability_at_least_d6() {
+        let d6 = ProbabilityDistribution::new_dice(6);
+        assert_eq!(d6.probability_at_least(4), 0.5);
+        assert_eq!(d6.probability_at_least(1), 1.0);
+        assert_eq!(d6.probability_at_least(0), 1.0);
+        assert_eq!(d6.probability_at_least(7), 0.0);
+    }
+
+    #[test]
+    fn test_probability_at_most_d6() {
+        let d6 = ProbabilityDistribution::new_dice(6);
+        assert_eq!(d6.probability_at_most(3), 0.5);
+        assert_eq!(d6.probability_at_most(6), 1.0);
+        assert_eq!(d6.probability_at_most(100), 1.0);
+        assert_eq!(d6.probability_at_most(0), 0.0);
+    }
+
+    #[test]
+    fn test_probability_at_least_and_at_most_empty() {
+        let probability_distribution = ProbabilityDistribution::new_empty_distribution();
+        assert_eq!(probability_distribution.probability_at_least(0), 0.0);
+        assert_eq!(probability_distribution.probability_at_most(0), 0.0);
+    }
+
+    #[test]
+    fn test_probability_at_least_and_at_most_complement() {
+        let three_d6 = ProbabilityDistribution::new_multiple_dice(3, 6);
+        for threshold in 2..=19 {
+            assert!(
+                (three_d6.probability_at_least(threshold)
+                    + three_d6.probability_at_most(threshold - 1)
+                    - 1.0)
+                    .abs()
+                    < 1e-12
+            );
+        }
+    }
+}
@@ -0,0 +1,228 @@
+use crate::probability::ProbabilityDistribution;
+use crate::ValueType;
+
+/// a helper function to count the rolls of M, N sided dice where no face is shown `n` or more times.
+/// faces are added one at a time, placing j of the dice rolled so far on the new face in `C(k + j, j)` ways.
+/// returns `None` if the count does not fit in a [u128].
+fn count_rolls_without_match(number_of_dice: u16, number_of_sides: u32, n: u16) -> Option<u128> {
+    let number_of_dice = usize::from(number_of_dice);
+    let mut ways = vec![0_u128; number_of_dice + 1];
+    ways[0] = 1;
+
+    for _ in 0..number_of_sides {
+        let mut new_ways = vec![0_u128; number_of_dice + 1];
+        for (placed, count) in ways.iter().enumerate().filter(|(_, count)| **count != 0) {
+            let mut choose: u128 = 1;
+            for repeats in 0..usize::from(n).min(number_of_dice - placed + 1) {
+                if repeats > 0 {
+                    choose = choose.checked_mul((placed + repeats) as u128)? / repeats as u128;
+                }
+                new_ways[placed + repeats] =
+                    new_ways[placed + repeats].checked_add(count.checked_mul(choose)?)?;
+            }
+        }
+        ways = new_ways;
+    }
+    Some(ways[number_of_dice])
+}
+
+/// a helper function to calculate the probability that no face is shown `n` or more times, without overflowing.
+/// faces are handled one at a time, the number of the remaining dice showing the next face
+/// follows a binomial distribution over the faces that are left.
+fn probability_without_match(number_of_dice: u16, number_of_sides: u32, n: u16) -> f64 {
+    let number_of_dice = usize::from(number_of_dice);
+    let n = usize::from(n);
+    let mut probabilities = vec![0.0; number_of_dice + 1];
+    probabilities[number_of_dice] = 1.0;
+
+    for faces_left in (2..=number_of_sides).rev() {
+        let on_face = 1.0 / f64::from(faces_left);
+        let odds = on_face / (1.0 - on_face);
+        let mut new_probabilities = vec![0.0; number_of_dice + 1];
+        for (remaining, probability) in probabilities.iter().enumerate() {
+            let mut binomial_term = probability * (1.0 - on_face).powi(remaining as i32);
+            for repeats in 0..n.min(remaining + 1) {
+                new_probabilities[remaining - repeats] += binomial_term;
+                binomial_term *= (remaining - repeats) as f64 / (repeats + 1) as f64 * odds;
+            }
+        }
+        probabilities = new_probabilities;
+    }
+
+    // every remaining dice shows the last face
+    probabilities.iter().take(n).sum()
+}
+
+impl ProbabilityDistribution {
+    /// Returns the probability that at least `n` of M, N sided dice show the same face,
+    /// for example doubles with `n` of 2 and triples with `n` of 3.
+    ///
+    /// the rolls where no face is shown `n` or more times are counted exactly,
+    /// adding the faces one at a time, and the probability is one minus their share.
+    /// when the counts do not fit in a [u128] the probability is calculated with [f64]s instead.
+    ///
+    /// for a non-positive number of sides or `n` larger than the number of dice, the probability is 0.0.
+    ///
+    /// for `n` of 0 or 1, the probability is 1.0.
+    ///
+    /// # Arguments
+    ///
+    /// * `number_of_dice` - [u16] The the number of dice to roll.
+    /// * `number_of_sides` - [ValueType] The the number of sides the dice has.
+    /// * `n` - [u16] The number of dice that have to match.
+    ///
+    /// # Returns
+    ///
+    /// The probability as a [f64].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use crate::rusted_dice::probability::ProbabilityDistribution;
+    /// assert_eq!(ProbabilityDistribution::probability_n_matching(2, 6, 2), 6.0 / 36.0);
+    /// assert_eq!(ProbabilityDistribution::probability_n_matching(3, 6, 3), 6.0 / 216.0);
+    /// ```
+    pub fn probability_n_matching(number_of_dice: u16, number_of_sides: ValueType, n: u16) -> f64 {
+        if number_of_sides <= 0 || n > number_of_dice {
+            return 0.0;
+        }
+        if n <= 1 {
+            return 1.0;
+        }
+
+        let sides = number_of_sides.unsigned_abs();
+        let total = u128::from(sides).checked_pow(number_of_dice.into());
+        match (total, count_rolls_without_match(number_of_dice, sides, n)) {
+            (Some(total), Some(without_match)) => (total - without_match) as f64 / total as f64,
+            _ => 1.0 - probability_without_match(number_of_dice, sides, n),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::probability::{PoolAggregator, ProbabilityDistribution};
+    use crate::ValueType;
+
+    use super::probability_without_match;
+
+    struct LongestMatch;
+
+    impl PoolAggregator for LongestMatch {
+        fn aggregate(&self, sorted_faces: &[ValueType]) -> ValueType {
+            sorted_faces
+                .chunk_by(|face_one, face_two| face_one == face_two)
+                .map(|chunk| chunk.len() as ValueType)
+                .max()
+                .unwrap_or(0)
+        }
+    }
+
+    #[test]
+    fn test_probability_n_matching_impossible() {
+        assert_eq!(
+            ProbabilityDistribution::probability_n_matching(3, 0, 2),
+            0.0
+        );
+        assert_eq!(
+            ProbabilityDistribution::probability_n_matching(3, -6, 2),
+            0.0
+        );
+        assert_eq!(
+            ProbabilityDistribution::probability_n_matching(3, 6, 4),
+            0.0
+        );
+        assert_eq!(
+            ProbabilityDistribution::probability_n_matching(7, 6, 8),
+            0.0
+        );
+    }
+
+    #[test]
+    fn test_probability_n_matching_trivial() {
+        assert_eq!(
+            ProbabilityDistribution::probability_n_matching(3, 6, 1),
+            1.0
+        );
+        assert_eq!(
+            ProbabilityDistribution::probability_n_matching(3, 6, 0),
+            1.0
+        );
+        assert_eq!(
+            ProbabilityDistribution::probability_n_matching(1, 1, 1),
+            1.0
+        );
+    }
+
+    #[test]
+    fn test_probability_n_matching_doubles() {
+        assert_eq!(
+            ProbabilityDistribution::probability_n_matching(2, 6, 2),
+            6.0 / 36.0
+        );
+        // 1 - (6 * 5 * 4) / 216
+        assert_eq!(
+            ProbabilityDistribution::probability_n_matching(3, 6, 2),
+            96.0 / 216.0
+        );
+        // pigeonhole, seven dice with six faces always match
+        assert_eq!(
+            ProbabilityDistribution::probability_n_matching(7, 6, 2),
+            1.0
+        );
+    }
+
+    #[test]
+    fn test_probability_n_matching_matches_new_pool() {
+        for (number_of_dice, number_of_sides) in [(4, 6), (5, 4), (6, 3)] {
+            let longest_match =
+                ProbabilityDistribution::new_pool(number_of_dice, number_of_sides, &LongestMatch);
+            let total = longest_match.total_outcome_count() as f64;
+            for n in 2..=number_of_dice {
+                let matching: u64 = longest_match
+                    .outcome_counts
+                    .iter()
+                    .filter(|(outcome, _)| outcome.value >= ValueType::from(n))
+                    .map(|(_, count)| count)
+                    .sum();
+                assert_eq!(
+                    ProbabilityDistribution::probability_n_matching(
+                        number_of_dice,
+                        number_of_sides,
+                        n
+                    ),
+                    matching as f64 / total
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_probability_without_match_matches_exact() {
+        for (number_of_dice, number_of_sides, n) in
+            [(2, 6, 2), (3, 6, 2), (5, 4, 3), (6, 3, 2), (8, 5, 3)]
+        {
+            let exact = 1.0
+                - ProbabilityDistribution::probability_n_matching(
+                    number_of_dice,
+                    number_of_sides,
+                    n,
+                );
+            assert!(
+                (probability_without_match(number_of_dice, number_of_sides as u32, n) - exact)
+                    .abs()
+                    < 1e-12
+            );
+        }
+    }
+
+    #[test]
+    fn test_probability_n_matching_large_pool() {
+        // birthday problem, 23 people and 365 days
+        let probability = ProbabilityDistribution::probability_n_matching(23, 365, 2);
+        assert!((probability - 0.5072972343239857).abs() < 1e-12);
+
+        let probability = ProbabilityDistribution::probability_n_matching(100, 365, 2);
+        assert!((probability - 0.9999996927510721).abs() < 1e-9);
+    }
+}
@@ -0,0 +1,93 @@
+use crate::probability::ProbabilityDistribution;
+use crate::ValueType;
+
+impl ProbabilityDistribution {
+    /// Returns the probability of at least one dice showing its highest face when rolling M, N sided dice,
+    /// the closed form `1 - ((n - 1) / n)^m`.
+    ///
+    /// for a single dice this is `1 / n`, the chance of rolling a natural N.
+    ///
+    /// for non-positive n, or no dice, the probability is 0.0.
+    ///
+    /// # Arguments
+    ///
+    /// * `number_of_dice` - [u16] The the number of dice to roll.
+    /// * `number_of_sides` - [ValueType] The the number of sides the dice has.
+    ///
+    /// # Returns
+    ///
+    /// The probability as a [f64].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use crate::rusted_dice::probability::ProbabilityDistribution;
+    /// // a natural 20 on a single d20
+    /// assert!((ProbabilityDistribution::probability_natural_max(1, 20) - 1.0 / 20.0).abs() < 1e-12);
+    ///
+    /// // a natural 20 on either of 2d20
+    /// assert_eq!(
+    ///     ProbabilityDistribution::probability_natural_max(2, 20),
+    ///     1.0 - (19.0_f64 / 20.0).powi(2)
+    /// );
+    /// ```
+    pub fn probability_natural_max(number_of_dice: u16, number_of_sides: ValueType) -> f64 {
+        if number_of_sides <= 0 {
+            return 0.0;
+        }
+
+        let sides = f64::from(number_of_sides);
+        1.0 - ((sides - 1.0) / sides).powi(number_of_dice.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::probability::ProbabilityDistribution;
+
+    #[test]
+    fn test_probability_natural_max_impossible() {
+        assert_eq!(ProbabilityDistribution::probability_natural_max(3, 0), 0.0);
+        assert_eq!(ProbabilityDistribution::probability_natural_max(3, -6), 0.0);
+        assert_eq!(ProbabilityDistribution::probability_natural_max(0, 6), 0.0);
+    }
+
+    #[test]
+    fn test_probability_natural_max_single_dice() {
+        for number_of_sides in 1..=100 {
+            assert!(
+                (ProbabilityDistribution::probability_natural_max(1, number_of_sides)
+                    - 1.0 / f64::from(number_of_sides))
+                .abs()
+                    < 1e-12
+            );
+        }
+    }
+
+    #[test]
+    fn test_probability_natural_max_one_sided() {
+        assert_eq!(ProbabilityDistribution::probability_natural_max(5, 1), 1.0);
+    }
+
+    #[test]
+    fn test_probability_natural_max_two_d20() {
+        assert_eq!(
+            ProbabilityDistribution::probability_natural_max(2, 20),
+            1.0 - (19.0_f64 / 20.0).powi(2)
+        );
+    }
+
+    #[test]
+    fn test_probability_natural_max_matches_pool_max() {
+        for (number_of_dice, number_of_sides) in [(1, 6), (3, 6), (4, 8), (2, 20)] {
+            let pool_max = ProbabilityDistribution::new_pool_max(number_of_dice, number_of_sides);
+            let expected = pool_max.probability_at_least(number_of_sides);
+            assert!(
+                (ProbabilityDistribution::probability_natural_max(number_of_dice, number_of_sides)
+                    - expected)
+                    .abs()
+                    < 1e-12
+            );
+        }
+    }
+}
@@ -0,0 +1,133 @@
+use crate::probability::ProbabilityDistribution;
+use crate::{CountType, ValueType};
+
+impl ProbabilityDistribution {
+    /// Returns the probability that the sum of rolling M, N sided dice is at least `threshold`.
+    /// the same as `new_multiple_dice(m, n).probability_meets(threshold)`,
+    /// but the convolution only keeps a dense list of counts, no [ProbabilityDistribution] is built.
+    ///
+    /// for negative n, the dice have values from -1 to -n.
+    /// for Zero n or Zero m, no outcomes can be rolled and the probability is 0.0.
+    ///
+    /// # Arguments
+    ///
+    /// * `number_of_dice` - [u16] The the number of dice to roll.
+    /// * `number_of_sides` - [ValueType] The the number of sides the dice has.
+    /// * `threshold` - The [ValueType] the sum has to meet.
+    ///
+    /// # Returns
+    ///
+    /// The probability as a [f64].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use crate::rusted_dice::probability::ProbabilityDistribution;
+    /// assert_eq!(
+    ///     ProbabilityDistribution::probability_pool_sum_at_least(2, 6, 10),
+    ///     6.0 / 36.0
+    /// );
+    /// ```
+    pub fn probability_pool_sum_at_least(
+        number_of_dice: u16,
+        number_of_sides: ValueType,
+        threshold: ValueType,
+    ) -> f64 {
+        if number_of_dice == 0 || number_of_sides == 0 {
+            return 0.0;
+        }
+
+        let width = number_of_sides.unsigned_abs() as usize;
+        let lowest_face: i64 = if number_of_sides.is_positive() {
+            1
+        } else {
+            -i64::from(number_of_sides.unsigned_abs())
+        };
+
+        let mut counts: Vec<CountType> = vec![1];
+        for _ in 0..number_of_dice {
+            let mut new_counts: Vec<CountType> = Vec::with_capacity(counts.len() + width - 1);
+            let mut window_sum: CountType = 0;
+            for index in 0..counts.len() + width - 1 {
+                if let Some(count) = counts.get(index) {
+                    window_sum += count;
+                }
+                if index >= width {
+                    window_sum -= counts[index - width];
+                }
+                new_counts.push(window_sum);
+            }
+            counts = new_counts;
+        }
+
+        let lowest_sum = lowest_face * i64::from(number_of_dice);
+        let total: CountType = counts.iter().sum();
+        let skipped = (i64::from(threshold) - lowest_sum).clamp(0, counts.len() as i64) as usize;
+        let meeting: CountType = counts[skipped..].iter().sum();
+        meeting as f64 / total as f64
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::probability::ProbabilityDistribution;
+    use crate::ValueType;
+
+    #[test]
+    fn test_probability_pool_sum_at_least_empty() {
+        assert_eq!(
+            ProbabilityDistribution::probability_pool_sum_at_least(0, 6, 1),
+            0.0
+        );
+        assert_eq!(
+            ProbabilityDistribution::probability_pool_sum_at_least(3, 0, 1),
+            0.0
+        );
+    }
+
+    #[test]
+    fn test_probability_pool_sum_at_least_bounds() {
+        assert_eq!(
+            ProbabilityDistribution::probability_pool_sum_at_least(3, 6, ValueType::MIN),
+            1.0
+        );
+        assert_eq!(
+            ProbabilityDistribution::probability_pool_sum_at_least(3, 6, 3),
+            1.0
+        );
+        assert_eq!(
+            ProbabilityDistribution::probability_pool_sum_at_least(3, 6, 18),
+            1.0 / 216.0
+        );
+        assert_eq!(
+            ProbabilityDistribution::probability_pool_sum_at_least(3, 6, 19),
+            0.0
+        );
+        assert_eq!(
+            ProbabilityDistribution::probability_pool_sum_at_least(3, 6, ValueType::MAX),
+            0.0
+        );
+    }
+
+    #[test]
+    fn test_probability_pool_sum_at_least_matches_full_distribution() {
+        for (number_of_dice, number_of_sides) in [(1, 20), (2, 6), (3, 6), (4, 8), (5, -4)] {
+            let probability_distribution =
+                ProbabilityDistribution::new_multiple_dice(number_of_dice, number_of_sides);
+            for threshold in -25..35 {
+                assert_eq!(
+                    ProbabilityDistribution::probability_pool_sum_at_least(
+                        number_of_dice,
+                        number_of_sides,
+                        threshold
+                    ),
+                    probability_distribution.probability_meets(threshold),
+                    "{}d{} >= {}",
+                    number_of_dice,
+                    number_of_sides,
+                    threshold
+                );
+            }
+        }
+    }
+}
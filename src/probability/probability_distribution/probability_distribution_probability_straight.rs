@@ -0,0 +1,116 @@
+use crate::probability::ProbabilityDistribution;
+use crate::ValueType;
+
+impl ProbabilityDistribution {
+    /// Returns the probability that rolling M, N sided dice shows a straight,
+    /// M distinct faces that form a run of consecutive values, in any order.
+    ///
+    /// there are `n - m + 1` runs of length m and each can be rolled in `m!` orders,
+    /// so the probability is `(n - m + 1) * m! / n^m`.
+    ///
+    /// for non-positive n, Zero m or m greater than n, no straight can be rolled and the probability is 0.0.
+    ///
+    /// # Arguments
+    ///
+    /// * `number_of_dice` - [u16] The the number of dice to roll.
+    /// * `number_of_sides` - [ValueType] The the number of sides the dice has.
+    ///
+    /// # Returns
+    ///
+    /// The probability as a [f64].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use crate::rusted_dice::probability::ProbabilityDistribution;
+    /// assert_eq!(ProbabilityDistribution::probability_straight(2, 6), 10.0 / 36.0);
+    /// assert_eq!(ProbabilityDistribution::probability_straight(5, 6), 240.0 / 7776.0);
+    /// ```
+    pub fn probability_straight(number_of_dice: u16, number_of_sides: ValueType) -> f64 {
+        if number_of_dice == 0
+            || number_of_sides <= 0
+            || ValueType::from(number_of_dice) > number_of_sides
+        {
+            return 0.0;
+        }
+
+        let number_of_runs =
+            u128::from(number_of_sides.unsigned_abs() - u32::from(number_of_dice) + 1);
+        let sides = u128::from(number_of_sides.unsigned_abs());
+        let exact = (1..=u128::from(number_of_dice)).try_fold(
+            (number_of_runs, 1_u128),
+            |(straights, total), dice| {
+                Some((straights.checked_mul(dice)?, total.checked_mul(sides)?))
+            },
+        );
+
+        match exact {
+            Some((straights, total)) => straights as f64 / total as f64,
+            None => (1..=number_of_dice).fold(number_of_runs as f64, |probability, dice| {
+                probability * f64::from(dice) / sides as f64
+            }),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::probability::{PoolAggregator, ProbabilityDistribution};
+    use crate::ValueType;
+
+    struct IsStraight;
+
+    impl PoolAggregator for IsStraight {
+        fn aggregate(&self, sorted_faces: &[ValueType]) -> ValueType {
+            sorted_faces
+                .windows(2)
+                .all(|pair| pair[1] == pair[0] + 1)
+                .into()
+        }
+    }
+
+    #[test]
+    fn test_probability_straight_impossible() {
+        assert_eq!(ProbabilityDistribution::probability_straight(0, 6), 0.0);
+        assert_eq!(ProbabilityDistribution::probability_straight(3, 0), 0.0);
+        assert_eq!(ProbabilityDistribution::probability_straight(3, -6), 0.0);
+        assert_eq!(ProbabilityDistribution::probability_straight(7, 6), 0.0);
+    }
+
+    #[test]
+    fn test_probability_straight_single_dice() {
+        assert_eq!(ProbabilityDistribution::probability_straight(1, 6), 1.0);
+    }
+
+    #[test]
+    fn test_probability_straight_three_d6() {
+        // runs 123, 234, 345 and 456, each in 3! = 6 orders
+        assert_eq!(
+            ProbabilityDistribution::probability_straight(3, 6),
+            24.0 / 216.0
+        );
+    }
+
+    #[test]
+    fn test_probability_straight_matches_new_pool() {
+        for (number_of_dice, number_of_sides) in [(2, 4), (3, 6), (4, 6), (5, 6), (3, 10)] {
+            let pool =
+                ProbabilityDistribution::new_pool(number_of_dice, number_of_sides, &IsStraight);
+            assert_eq!(
+                ProbabilityDistribution::probability_straight(number_of_dice, number_of_sides),
+                pool.probability_meets(1),
+                "{}d{}",
+                number_of_dice,
+                number_of_sides
+            );
+        }
+    }
+
+    #[test]
+    fn test_probability_straight_large_pool() {
+        let probability = ProbabilityDistribution::probability_straight(40, 100);
+        // 61 runs * 40! / 100^40
+        let expected = 4.977083227812176e-31;
+        assert!((probability - expected).abs() / expected < 1e-12);
+    }
+}
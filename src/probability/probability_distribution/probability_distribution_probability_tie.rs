@@ -0,0 +1,83 @@
+use crate::probability::ProbabilityDistribution;
+
+use super::collapse_outcome_to_count_map;
+
+impl ProbabilityDistribution {
+    /// Returns the probability that independent rolls of two [ProbabilityDistribution]s have equal values.
+    /// the tie part of an opposed roll.
+    /// outcomes sharing a value but differing in constraints are counted together.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The [ProbabilityDistribution] rolled against `self`.
+    ///
+    /// # Returns
+    ///
+    /// The probability as a [f64], 0.0 if either [ProbabilityDistribution] is empty.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use crate::rusted_dice::probability::ProbabilityDistribution;
+    /// let d6 = ProbabilityDistribution::new_dice(6);
+    /// let d4 = ProbabilityDistribution::new_dice(4);
+    /// assert_eq!(d6.probability_tie(&d4), 4.0 / 24.0);
+    /// ```
+    pub fn probability_tie(&self, other: &Self) -> f64 {
+        let total =
+            u128::from(self.total_outcome_count()) * u128::from(other.total_outcome_count());
+        if total == 0 {
+            return 0.0;
+        }
+
+        let other_value_to_count_map = collapse_outcome_to_count_map(&other.outcome_counts);
+        let tied: u128 = collapse_outcome_to_count_map(&self.outcome_counts)
+            .into_iter()
+            .filter_map(|(value, count)| {
+                other_value_to_count_map
+                    .get(&value)
+                    .map(|other_count| u128::from(count) * u128::from(*other_count))
+            })
+            .sum();
+        tied as f64 / total as f64
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::probability::ProbabilityDistribution;
+
+    #[test]
+    fn test_probability_tie_empty() {
+        let empty = ProbabilityDistribution::new_empty_distribution();
+        let dice = ProbabilityDistribution::new_dice(6);
+        assert_eq!(empty.probability_tie(&dice), 0.0);
+        assert_eq!(dice.probability_tie(&empty), 0.0);
+    }
+
+    #[test]
+    fn test_probability_tie_two_d6() {
+        let dice = ProbabilityDistribution::new_dice(6);
+        assert_eq!(dice.probability_tie(&dice), 6.0 / 36.0);
+    }
+
+    #[test]
+    fn test_probability_tie_disjoint() {
+        let dice = ProbabilityDistribution::new_dice(6);
+        assert_eq!(dice.probability_tie(&(dice.clone() + 6)), 0.0);
+    }
+
+    #[test]
+    fn test_probability_tie_weighted() {
+        let two_d6 = ProbabilityDistribution::new_multiple_dice(2, 6);
+        // 1 + 4 + 9 + 16 + 25 + 36 + 25 + 16 + 9 + 4 + 1
+        assert_eq!(two_d6.probability_tie(&two_d6), 146.0 / 1296.0);
+    }
+
+    #[test]
+    fn test_probability_tie_merges_constraints() {
+        let dice_one = ProbabilityDistribution::new_dice(6).add_self_value_constraint(1);
+        let dice_two = ProbabilityDistribution::new_dice(6).add_self_value_constraint(2);
+        assert_eq!(dice_one.probability_tie(&dice_two), 6.0 / 36.0);
+    }
+}
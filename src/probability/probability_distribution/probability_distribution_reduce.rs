@@ -0,0 +1,117 @@
+use crate::probability::ProbabilityDistribution;
+
+use super::greatest_common_divisor;
+
+impl ProbabilityDistribution {
+    /// Divides every count of the [ProbabilityDistribution] by the greatest common divisor of all counts.
+    /// relative probabilities are unchanged while counts shrink, delaying overflow of [CountType](crate::CountType)
+    /// in long chains of combinations.
+    ///
+    /// empty and single outcome [ProbabilityDistribution]s are returned unchanged.
+    ///
+    /// # Returns
+    ///
+    /// The new [ProbabilityDistribution].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use crate::rusted_dice::probability::ProbabilityDistribution;
+    /// let mut probability_distribution = ProbabilityDistribution::new_multiple_dice(2, 2);
+    /// probability_distribution.scale_counts_in_place(6);
+    /// assert_eq!(probability_distribution.coefficients(), (2, vec![6, 12, 6]));
+    ///
+    /// assert_eq!(probability_distribution.reduce().coefficients(), (2, vec![1, 2, 1]));
+    /// ```
+    pub fn reduce(&self) -> Self {
+        if self.outcome_counts.len() <= 1 {
+            return self.clone();
+        }
+
+        let divisor = self
+            .outcome_counts
+            .values()
+            .copied()
+            .fold(0, greatest_common_divisor);
+        ProbabilityDistribution {
+            outcome_counts: self
+                .outcome_counts
+                .iter()
+                .map(|(outcome, count)| (outcome.clone(), count / divisor))
+                .collect(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::constraint_management::Constraint;
+    use crate::probability::{ProbabilityDistribution, ProbabilityOutcome};
+
+    #[test]
+    fn test_reduce_empty() {
+        let probability_distribution = ProbabilityDistribution::new_empty_distribution().reduce();
+        assert_eq!(probability_distribution.outcome_counts.len(), 0);
+    }
+
+    #[test]
+    fn test_reduce_single_outcome_unchanged() {
+        let mut probability_distribution = ProbabilityDistribution::new_constant(5);
+        probability_distribution.scale_counts_in_place(12);
+        assert_eq!(
+            probability_distribution.reduce().outcome_counts,
+            probability_distribution.outcome_counts
+        );
+    }
+
+    #[test]
+    fn test_reduce_already_reduced() {
+        let probability_distribution = ProbabilityDistribution::new_multiple_dice(3, 6);
+        assert_eq!(
+            probability_distribution.reduce().outcome_counts,
+            probability_distribution.outcome_counts
+        );
+    }
+
+    #[test]
+    fn test_reduce_two_four_six() {
+        let probability_distribution = ProbabilityDistribution::from_coefficients(1, &[2, 4, 6]);
+        assert_eq!(
+            probability_distribution.reduce().outcome_counts,
+            ProbabilityDistribution::from_coefficients(1, &[1, 2, 3]).outcome_counts
+        );
+    }
+
+    #[test]
+    fn test_reduce_keeps_constraints() {
+        let mut probability_distribution =
+            ProbabilityDistribution::new_dice(3).add_self_value_constraint(1);
+        probability_distribution.scale_counts_in_place(9);
+        let reduced = probability_distribution.reduce();
+
+        for face in 1..=3 {
+            assert_eq!(
+                reduced
+                    .outcome_counts
+                    .get(&ProbabilityOutcome::new_with_constraints(
+                        face,
+                        vec![Constraint::new_single_valid_value_constraint(1, face)],
+                    )),
+                Some(&1)
+            );
+        }
+    }
+
+    #[test]
+    fn test_reduce_preserves_probabilities() {
+        let mut probability_distribution = ProbabilityDistribution::new_multiple_dice(2, 6);
+        probability_distribution.scale_counts_in_place(15);
+        let reduced = probability_distribution.reduce();
+
+        assert_eq!(reduced.total_outcome_count(), 36);
+        assert_eq!(
+            reduced.to_probabilities(),
+            probability_distribution.to_probabilities()
+        );
+    }
+}
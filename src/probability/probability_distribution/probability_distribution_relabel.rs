@@ -0,0 +1,148 @@
+use std::collections::{BTreeMap, HashMap};
+
+use crate::probability::{ProbabilityDistribution, ProbabilityOutcome};
+use crate::ValueType;
+
+use super::add_outcome_to_map;
+
+impl ProbabilityDistribution {
+    /// Replaces each value of the [ProbabilityDistribution] using a lookup table,
+    /// for example mapping the faces of a dice to symbols.
+    /// values missing from `mapping` are kept, constraint maps are kept,
+    /// outcomes which end up equal are merged and their counts added.
+    ///
+    /// # Arguments
+    ///
+    /// * `mapping` - The [HashMap] from each old [ValueType] to its new [ValueType].
+    ///
+    /// # Returns
+    ///
+    /// The new [ProbabilityDistribution].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use std::collections::HashMap;
+    /// # use crate::rusted_dice::probability::ProbabilityDistribution;
+    /// # use crate::rusted_dice::probability::ToTable;
+    /// let dice = ProbabilityDistribution::new_dice(4);
+    /// let mapping = HashMap::from([(1, 0), (4, 0), (3, 10)]);
+    ///
+    /// assert_eq!(
+    ///     dice.relabel(&mapping)
+    ///         .to_table()
+    ///         .to_string()
+    ///         .replace("\r\n", "\n"),
+    ///     "\
+    ///     +-------+-------+\n\
+    ///     | value | count |\n\
+    ///     +=======+=======+\n\
+    ///     | 0     | 2     |\n\
+    ///     +-------+-------+\n\
+    ///     | 2     | 1     |\n\
+    ///     +-------+-------+\n\
+    ///     | 10    | 1     |\n\
+    ///     +-------+-------+\n\
+    ///     ");
+    /// ```
+    pub fn relabel(&self, mapping: &HashMap<ValueType, ValueType>) -> Self {
+        let mut new_outcome_counts = BTreeMap::new();
+        for (outcome, count) in self.outcome_counts.iter() {
+            add_outcome_to_map(
+                &mut new_outcome_counts,
+                ProbabilityOutcome {
+                    value: *mapping.get(&outcome.value).unwrap_or(&outcome.value),
+                    constraint_map: outcome.constraint_map.clone(),
+                },
+                *count,
+            );
+        }
+        ProbabilityDistribution {
+            outcome_counts: new_outcome_counts,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use crate::constraint_management::Constraint;
+    use crate::probability::{ProbabilityDistribution, ProbabilityOutcome, ToTable};
+
+    #[test]
+    fn test_relabel_empty() {
+        let probability_distribution = ProbabilityDistribution::new_empty_distribution();
+        assert_eq!(
+            probability_distribution
+                .relabel(&HashMap::from([(1, 2)]))
+                .outcome_counts
+                .len(),
+            0
+        );
+    }
+
+    #[test]
+    fn test_relabel_empty_mapping() {
+        let two_d6 = ProbabilityDistribution::new_multiple_dice(2, 6);
+        assert_eq!(
+            two_d6.relabel(&HashMap::new()).to_table(),
+            two_d6.to_table()
+        );
+    }
+
+    #[test]
+    fn test_relabel_merges_collisions() {
+        let dice = ProbabilityDistribution::new_dice(6);
+        let relabeled = dice.relabel(&HashMap::from([(1, 0), (6, 0)]));
+
+        assert_eq!(
+            relabeled
+                .outcome_counts
+                .get(&ProbabilityOutcome::new_with_empty_constraint_map(0)),
+            Some(&2)
+        );
+        for value in 2..=5 {
+            assert_eq!(
+                relabeled
+                    .outcome_counts
+                    .get(&ProbabilityOutcome::new_with_empty_constraint_map(value)),
+                Some(&1)
+            );
+        }
+        assert_eq!(relabeled.outcome_counts.len(), 5);
+        assert_eq!(relabeled.total_outcome_count(), 6);
+    }
+
+    #[test]
+    fn test_relabel_swap() {
+        let dice = ProbabilityDistribution::new_dice(2) + ProbabilityDistribution::new_dice(1);
+        let relabeled = dice.relabel(&HashMap::from([(2, 3), (3, 2)]));
+        assert_eq!(relabeled.to_table(), dice.to_table());
+    }
+
+    #[test]
+    fn test_relabel_keeps_constraints() {
+        let dice = ProbabilityDistribution::new_dice(2).add_self_value_constraint(5);
+        let relabeled = dice.relabel(&HashMap::from([(1, 2)]));
+
+        assert_eq!(
+            relabeled
+                .outcome_counts
+                .get(&ProbabilityOutcome::new_with_constraints(
+                    2,
+                    vec![Constraint::new_single_valid_value_constraint(5, 1)]
+                )),
+            Some(&1)
+        );
+        assert_eq!(
+            relabeled
+                .outcome_counts
+                .get(&ProbabilityOutcome::new_with_constraints(
+                    2,
+                    vec![Constraint::new_single_valid_value_constraint(5, 2)]
+                )),
+            Some(&1)
+        );
+    }
+}
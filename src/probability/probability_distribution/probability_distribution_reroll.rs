@@ -0,0 +1,276 @@
+use std::collections::{BTreeMap, HashSet};
+
+use crate::probability::ProbabilityDistribution;
+use crate::{CountType, ValueType};
+
+use super::add_outcome_to_map;
+
+impl ProbabilityDistribution {
+    /// Rerolls any outcome whose value is in `values`, up to `max_rerolls` times.
+    /// the [ProbabilityDistribution] is treated as a single dice that is rolled again,
+    /// the result of the final permitted reroll is kept even if it is in `values`.
+    ///
+    /// counts are scaled so that they stay integral,
+    /// the total count of the result is the total count of `self` to the power of `max_rerolls + 1`.
+    ///
+    /// # Arguments
+    ///
+    /// * `values` - The [ValueType]s that trigger a reroll.
+    /// * `max_rerolls` - The maximum number of times the dice can be rerolled.
+    ///
+    /// # Returns
+    ///
+    /// The new [ProbabilityDistribution].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use crate::rusted_dice::probability::ProbabilityDistribution;
+    /// # use crate::rusted_dice::probability::ToTable;
+    /// let dice = ProbabilityDistribution::new_dice(4);
+    ///
+    /// assert_eq!(
+    ///     dice.reroll_until(&[1], 1)
+    ///         .to_table()
+    ///         .to_string()
+    ///         .replace("\r\n", "\n"),
+    ///     "\
+    ///     +-------+-------+\n\
+    ///     | value | count |\n\
+    ///     +=======+=======+\n\
+    ///     | 1     | 1     |\n\
+    ///     +-------+-------+\n\
+    ///     | 2     | 5     |\n\
+    ///     +-------+-------+\n\
+    ///     | 3     | 5     |\n\
+    ///     +-------+-------+\n\
+    ///     | 4     | 5     |\n\
+    ///     +-------+-------+\n\
+    ///     ");
+    /// ```
+    pub fn reroll_until(&self, values: &[ValueType], max_rerolls: u32) -> Self {
+        let reroll_count: CountType = self
+            .outcome_counts
+            .iter()
+            .filter(|(outcome, _)| values.contains(&outcome.value))
+            .map(|(_, count)| count)
+            .sum();
+
+        let mut result = self.clone();
+        for _ in 0..max_rerolls {
+            let result_total = result.total_outcome_count();
+            let mut new_outcome_counts = BTreeMap::new();
+
+            for (outcome, count) in self.outcome_counts.iter() {
+                if !values.contains(&outcome.value) {
+                    add_outcome_to_map(
+                        &mut new_outcome_counts,
+                        outcome.clone(),
+                        count * result_total,
+                    );
+                }
+            }
+            for (outcome, count) in result.outcome_counts.iter() {
+                add_outcome_to_map(
+                    &mut new_outcome_counts,
+                    outcome.clone(),
+                    count * reroll_count,
+                );
+            }
+
+            result = ProbabilityDistribution {
+                outcome_counts: new_outcome_counts,
+            };
+        }
+        result
+    }
+
+    /// Rerolls any outcome whose value is in `reroll_set` once, keeping the second roll even if it is in `reroll_set`,
+    /// for example Great Weapon Fighting rerolling 1s and 2s.
+    /// this is [ProbabilityDistribution::reroll_until] with a single reroll,
+    /// the total count of the result is the square of the total count of `self`.
+    ///
+    /// # Arguments
+    ///
+    /// * `reroll_set` - The [ValueType]s that trigger a reroll.
+    ///
+    /// # Returns
+    ///
+    /// The new [ProbabilityDistribution].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use std::collections::HashSet;
+    /// # use crate::rusted_dice::probability::ProbabilityDistribution;
+    /// # use crate::rusted_dice::probability::ProbabilityOutcome;
+    /// let dice = ProbabilityDistribution::new_dice(6);
+    /// let rerolled = dice.reroll_once(&HashSet::from([1, 2]));
+    ///
+    /// assert_eq!(rerolled.total_outcome_count(), 36);
+    /// assert_eq!(
+    ///     rerolled.outcome_counts.get(&ProbabilityOutcome::new_with_empty_constraint_map(1)),
+    ///     Some(&2)
+    /// );
+    /// assert_eq!(
+    ///     rerolled.outcome_counts.get(&ProbabilityOutcome::new_with_empty_constraint_map(6)),
+    ///     Some(&8)
+    /// );
+    /// ```
+    pub fn reroll_once(&self, reroll_set: &HashSet<ValueType>) -> Self {
+        let values: Vec<ValueType> = reroll_set.iter().copied().collect();
+        self.reroll_until(&values, 1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use crate::constraint_management::Constraint;
+    use crate::probability::{ProbabilityDistribution, ProbabilityOutcome, ToTable};
+
+    #[test]
+    fn test_reroll_until_empty() {
+        let probability_distribution = ProbabilityDistribution::new_empty_distribution();
+        assert_eq!(
+            probability_distribution
+                .reroll_until(&[1], 3)
+                .outcome_counts
+                .len(),
+            0
+        );
+    }
+
+    #[test]
+    fn test_reroll_until_zero_rerolls() {
+        let dice = ProbabilityDistribution::new_dice(6);
+        assert_eq!(dice.reroll_until(&[1, 2], 0).to_table(), dice.to_table());
+    }
+
+    #[test]
+    fn test_reroll_until_no_matching_values() {
+        let dice = ProbabilityDistribution::new_dice(4);
+        let rerolled = dice.reroll_until(&[9], 2);
+
+        for value in 1..=4 {
+            assert_eq!(
+                rerolled
+                    .outcome_counts
+                    .get(&ProbabilityOutcome::new_with_empty_constraint_map(value)),
+                Some(&16)
+            );
+        }
+        assert_eq!(rerolled.total_outcome_count(), 64);
+    }
+
+    #[test]
+    fn test_reroll_until_d4_ones_cap_two() {
+        let dice = ProbabilityDistribution::new_dice(4);
+        let rerolled = dice.reroll_until(&[1], 2);
+
+        assert_eq!(
+            rerolled.to_table().to_string().replace("\r\n", "\n"),
+            "\
+            +-------+-------+\n\
+            | value | count |\n\
+            +=======+=======+\n\
+            | 1     | 1     |\n\
+            +-------+-------+\n\
+            | 2     | 21    |\n\
+            +-------+-------+\n\
+            | 3     | 21    |\n\
+            +-------+-------+\n\
+            | 4     | 21    |\n\
+            +-------+-------+\n\
+            "
+        );
+    }
+
+    #[test]
+    fn test_reroll_until_conserves_mass() {
+        let dice = ProbabilityDistribution::new_dice(6);
+        for max_rerolls in 0..5 {
+            let rerolled = dice.reroll_until(&[1, 2], max_rerolls);
+            assert_eq!(rerolled.total_outcome_count(), 6_u64.pow(max_rerolls + 1));
+        }
+    }
+
+    #[test]
+    fn test_reroll_until_keeps_constraints() {
+        let dice = ProbabilityDistribution::new_dice(2).add_self_value_constraint(7);
+        let rerolled = dice.reroll_until(&[1], 1);
+
+        assert_eq!(
+            rerolled
+                .outcome_counts
+                .get(&ProbabilityOutcome::new_with_constraints(
+                    1,
+                    vec![Constraint::new_single_valid_value_constraint(7, 1)]
+                )),
+            Some(&1)
+        );
+        assert_eq!(
+            rerolled
+                .outcome_counts
+                .get(&ProbabilityOutcome::new_with_constraints(
+                    2,
+                    vec![Constraint::new_single_valid_value_constraint(7, 2)]
+                )),
+            Some(&3)
+        );
+    }
+
+    #[test]
+    fn test_reroll_once_empty() {
+        let probability_distribution = ProbabilityDistribution::new_empty_distribution();
+        assert_eq!(
+            probability_distribution
+                .reroll_once(&HashSet::from([1]))
+                .outcome_counts
+                .len(),
+            0
+        );
+    }
+
+    #[test]
+    fn test_reroll_once_empty_set() {
+        let dice = ProbabilityDistribution::new_dice(6);
+        let rerolled = dice.reroll_once(&HashSet::new());
+
+        assert_eq!(rerolled.total_outcome_count(), 36);
+        for value in 1..=6 {
+            assert_eq!(
+                rerolled
+                    .outcome_counts
+                    .get(&ProbabilityOutcome::new_with_empty_constraint_map(value)),
+                Some(&6)
+            );
+        }
+    }
+
+    #[test]
+    fn test_reroll_once_great_weapon_fighting() {
+        let rerolled = ProbabilityDistribution::new_dice(6).reroll_once(&HashSet::from([1, 2]));
+
+        for (value, count) in [(1, 2), (2, 2), (3, 8), (4, 8), (5, 8), (6, 8)] {
+            assert_eq!(
+                rerolled
+                    .outcome_counts
+                    .get(&ProbabilityOutcome::new_with_empty_constraint_map(value)),
+                Some(&count)
+            );
+        }
+        assert_eq!(rerolled.outcome_counts.len(), 6);
+        assert_eq!(rerolled.mean(), 150.0 / 36.0);
+    }
+
+    #[test]
+    fn test_reroll_once_matches_reroll_until() {
+        let dice = ProbabilityDistribution::new_multiple_dice(2, 4);
+        assert_eq!(
+            dice.reroll_once(&HashSet::from([2, 3, 8])).outcome_counts,
+            dice.reroll_until(&[2, 3, 8], 1).outcome_counts
+        );
+    }
+}
@@ -0,0 +1,88 @@
+use crate::constraint_management::IsTheoreticallyPossible;
+use crate::probability::ProbabilityDistribution;
+
+impl ProbabilityDistribution {
+    /// Removes every outcome of the [ProbabilityDistribution] whose constraint map is not possible,
+    /// such as one holding a constraint without any valid values. the remaining counts are unchanged.
+    ///
+    /// the operators already drop impossible outcomes while combining,
+    /// this is useful to clean up a [ProbabilityDistribution] built by hand.
+    ///
+    /// # Returns
+    ///
+    /// The new [ProbabilityDistribution].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use crate::rusted_dice::probability::{ProbabilityDistribution, ProbabilityOutcome};
+    /// # use crate::rusted_dice::constraint_management::Constraint;
+    /// let probability_distribution = ProbabilityDistribution::new_from_many_probability_outcomes(vec![
+    ///     ProbabilityOutcome::new_with_empty_constraint_map(1),
+    ///     ProbabilityOutcome::new_with_constraints(2, vec![Constraint::new_empty_constraint(1)]),
+    /// ]);
+    ///
+    /// let possible = probability_distribution.retain_possible();
+    /// assert_eq!(possible.len(), 1);
+    /// assert!(possible.contains_value(1));
+    /// ```
+    pub fn retain_possible(&self) -> Self {
+        self.filter(|outcome| outcome.constraint_map.is_theoretically_possible())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::constraint_management::Constraint;
+    use crate::probability::{ProbabilityDistribution, ProbabilityOutcome};
+
+    #[test]
+    fn test_retain_possible_empty() {
+        let probability_distribution = ProbabilityDistribution::new_empty_distribution();
+        assert!(probability_distribution.retain_possible().is_empty());
+    }
+
+    #[test]
+    fn test_retain_possible_keeps_possible_outcomes() {
+        let probability_distribution =
+            ProbabilityDistribution::new_multiple_dice(2, 4).add_self_value_constraint(1);
+        assert_eq!(
+            probability_distribution.retain_possible().outcome_counts,
+            probability_distribution.outcome_counts
+        );
+    }
+
+    #[test]
+    fn test_retain_possible_removes_empty_valued_constraint() {
+        let impossible_outcome = ProbabilityOutcome::new_with_constraints(
+            3,
+            vec![
+                Constraint::new_single_valid_value_constraint(1, 3),
+                Constraint::new_many_item_constraint(2, vec![]),
+            ],
+        );
+        let constrained_outcome = ProbabilityOutcome::new_with_constraints(
+            3,
+            vec![Constraint::new_single_valid_value_constraint(1, 3)],
+        );
+        let unconstrained_outcome = ProbabilityOutcome::new_with_empty_constraint_map(4);
+        let probability_distribution =
+            ProbabilityDistribution::new_from_many_probability_outcomes(vec![
+                impossible_outcome.clone(),
+                impossible_outcome.clone(),
+                constrained_outcome.clone(),
+                unconstrained_outcome.clone(),
+                unconstrained_outcome.clone(),
+            ]);
+
+        let possible = probability_distribution.retain_possible();
+        assert_eq!(possible.len(), 2);
+        assert_eq!(possible.outcome_counts.get(&impossible_outcome), None);
+        assert_eq!(possible.outcome_counts.get(&constrained_outcome), Some(&1));
+        assert_eq!(
+            possible.outcome_counts.get(&unconstrained_outcome),
+            Some(&2)
+        );
+        assert_eq!(possible.total_outcome_count(), 3);
+    }
+}
@@ -0,0 +1,215 @@
+use rand::{Rng, RngExt};
+
+use crate::probability::ProbabilityDistribution;
+use crate::ValueType;
+
+impl ProbabilityDistribution {
+    /// Draws a single weighted random value from the [ProbabilityDistribution].
+    /// each outcome is drawn with probability equal to its count divided by the total outcome count.
+    ///
+    /// # Arguments
+    ///
+    /// * `rng` - The random number generator to draw with.
+    ///
+    /// # Returns
+    ///
+    /// The drawn [ValueType], or `None` if the [ProbabilityDistribution] is empty.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rand::SeedableRng;
+    /// # use rand::rngs::StdRng;
+    /// # use crate::rusted_dice::probability::ProbabilityDistribution;
+    /// let mut rng = StdRng::seed_from_u64(42);
+    /// let dice = ProbabilityDistribution::new_dice(6);
+    ///
+    /// let value = dice.sample(&mut rng).unwrap();
+    /// assert!((1..=6).contains(&value));
+    ///
+    /// assert_eq!(ProbabilityDistribution::new_empty_distribution().sample(&mut rng), None);
+    /// ```
+    pub fn sample<R: Rng>(&self, rng: &mut R) -> Option<ValueType> {
+        let total = self.total_outcome_count();
+        if total == 0 {
+            return None;
+        }
+
+        let mut target = rng.random_range(0..total);
+        for (outcome, count) in self.outcome_counts.iter() {
+            if target < *count {
+                return Some(outcome.value);
+            }
+            target -= count;
+        }
+        None
+    }
+
+    /// Draws `n` independent weighted random values from the [ProbabilityDistribution].
+    ///
+    /// # Arguments
+    ///
+    /// * `rng` - The random number generator to draw with.
+    /// * `n` - The number of values to draw.
+    ///
+    /// # Returns
+    ///
+    /// The [Vec] of drawn [ValueType]s, empty if the [ProbabilityDistribution] is empty.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rand::SeedableRng;
+    /// # use rand::rngs::StdRng;
+    /// # use crate::rusted_dice::probability::ProbabilityDistribution;
+    /// let mut rng = StdRng::seed_from_u64(7);
+    /// let dice = ProbabilityDistribution::new_dice(20);
+    ///
+    /// let samples = dice.sample_n(&mut rng, 25);
+    /// assert_eq!(samples.len(), 25);
+    /// assert!(samples.iter().all(|value| (1..=20).contains(value)));
+    ///
+    /// assert!(ProbabilityDistribution::new_empty_distribution().sample_n(&mut rng, 25).is_empty());
+    /// ```
+    pub fn sample_n<R: Rng>(&self, rng: &mut R, n: usize) -> Vec<ValueType> {
+        if self.outcome_counts.is_empty() {
+            return Vec::new();
+        }
+        (0..n).filter_map(|_| self.sample(rng)).collect()
+    }
+
+    /// Draws `n` independent weighted random values from the [ProbabilityDistribution] in the order drawn.
+    ///
+    /// # Arguments
+    ///
+    /// * `rng` - The random number generator to draw with.
+    /// * `n` - The number of values to draw.
+    ///
+    /// # Returns
+    ///
+    /// The [Vec] of drawn [ValueType]s, empty if the [ProbabilityDistribution] is empty.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rand::SeedableRng;
+    /// # use rand::rngs::StdRng;
+    /// # use crate::rusted_dice::probability::ProbabilityDistribution;
+    /// let mut rng = StdRng::seed_from_u64(42);
+    /// let two_d6 = ProbabilityDistribution::new_multiple_dice(2, 6);
+    ///
+    /// let rolls = two_d6.roll_sequence(&mut rng, 10);
+    /// assert_eq!(rolls.len(), 10);
+    /// assert!(rolls.iter().all(|value| (2..=12).contains(value)));
+    /// ```
+    pub fn roll_sequence<R: Rng>(&self, rng: &mut R, n: usize) -> Vec<ValueType> {
+        self.sample_n(rng, n)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    use crate::probability::{ProbabilityDistribution, ProbabilityOutcome};
+
+    #[test]
+    fn test_sample_empty() {
+        let mut rng = StdRng::seed_from_u64(1);
+        let probability_distribution = ProbabilityDistribution::new_empty_distribution();
+        assert_eq!(probability_distribution.sample(&mut rng), None);
+    }
+
+    #[test]
+    fn test_sample_single_outcome() {
+        let mut rng = StdRng::seed_from_u64(1);
+        let probability_distribution = ProbabilityDistribution::new_from_single_probability_outcome(
+            ProbabilityOutcome::new_with_empty_constraint_map(-12),
+        );
+        for _ in 0..100 {
+            assert_eq!(probability_distribution.sample(&mut rng), Some(-12));
+        }
+    }
+
+    #[test]
+    fn test_sample_n_empty() {
+        let mut rng = StdRng::seed_from_u64(1);
+        let probability_distribution = ProbabilityDistribution::new_empty_distribution();
+        assert!(probability_distribution.sample_n(&mut rng, 100).is_empty());
+    }
+
+    #[test]
+    fn test_sample_n_zero() {
+        let mut rng = StdRng::seed_from_u64(1);
+        let probability_distribution = ProbabilityDistribution::new_dice(6);
+        assert!(probability_distribution.sample_n(&mut rng, 0).is_empty());
+    }
+
+    #[test]
+    fn test_sample_n_respects_weights() {
+        let mut rng = StdRng::seed_from_u64(5);
+        let probability_distribution =
+            ProbabilityDistribution::new_from_many_probability_outcomes(vec![
+                ProbabilityOutcome::new_with_empty_constraint_map(0),
+                ProbabilityOutcome::new_with_empty_constraint_map(1),
+                ProbabilityOutcome::new_with_empty_constraint_map(1),
+                ProbabilityOutcome::new_with_empty_constraint_map(1),
+            ]);
+
+        let samples = probability_distribution.sample_n(&mut rng, 40_000);
+        let ones = samples.iter().filter(|value| **value == 1).count() as f64;
+
+        assert!((ones / samples.len() as f64 - 0.75).abs() < 0.01);
+    }
+
+    #[test]
+    fn test_sample_n_d6_roughly_uniform() {
+        let mut rng = StdRng::seed_from_u64(2024);
+        let probability_distribution = ProbabilityDistribution::new_dice(6);
+
+        let samples = probability_distribution.sample_n(&mut rng, 60_000);
+        for face in 1..=6 {
+            let frequency = samples.iter().filter(|value| **value == face).count();
+            assert!(
+                (9_500..=10_500).contains(&frequency),
+                "face {face} drawn {frequency} times"
+            );
+        }
+    }
+
+    #[test]
+    fn test_roll_sequence_empty() {
+        let mut rng = StdRng::seed_from_u64(1);
+        let probability_distribution = ProbabilityDistribution::new_empty_distribution();
+        assert!(probability_distribution
+            .roll_sequence(&mut rng, 100)
+            .is_empty());
+    }
+
+    #[test]
+    fn test_roll_sequence_deterministic_with_seed() {
+        let probability_distribution = ProbabilityDistribution::new_multiple_dice(3, 6);
+
+        let mut rng_one = StdRng::seed_from_u64(1234);
+        let mut rng_two = StdRng::seed_from_u64(1234);
+
+        let rolls_one = probability_distribution.roll_sequence(&mut rng_one, 500);
+        let rolls_two = probability_distribution.roll_sequence(&mut rng_two, 500);
+
+        assert_eq!(rolls_one.len(), 500);
+        assert_eq!(rolls_one, rolls_two);
+    }
+
+    #[test]
+    fn test_roll_sequence_mean_approaches_exact_mean() {
+        let mut rng = StdRng::seed_from_u64(99);
+        let probability_distribution = ProbabilityDistribution::new_multiple_dice(2, 6);
+
+        let rolls = probability_distribution.roll_sequence(&mut rng, 100_000);
+        let empirical_mean =
+            rolls.iter().map(|value| *value as f64).sum::<f64>() / rolls.len() as f64;
+
+        assert!((empirical_mean - 7.0).abs() < 0.05);
+    }
+}
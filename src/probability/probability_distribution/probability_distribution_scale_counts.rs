@@ -0,0 +1,128 @@
+use std::collections::BTreeMap;
+
+use crate::probability::ProbabilityDistribution;
+use crate::CountType;
+
+impl ProbabilityDistribution {
+    /// Multiplies every count of the [ProbabilityDistribution] by `factor`.
+    /// a `factor` of 0 removes every outcome, giving an empty [ProbabilityDistribution].
+    /// the probabilities are unchanged, useful when building mixtures by hand.
+    ///
+    /// # Arguments
+    ///
+    /// * `factor` - The [CountType] to multiply every count by.
+    ///
+    /// # Returns
+    ///
+    /// The new [ProbabilityDistribution].
+    ///
+    /// # Panics
+    ///
+    /// Panics in debug builds if a count overflows [CountType],
+    /// see [ProbabilityDistribution::checked_scale_counts].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use crate::rusted_dice::probability::ProbabilityDistribution;
+    /// let dice = ProbabilityDistribution::new_dice(6);
+    /// let scaled = dice.scale_counts(3);
+    /// assert_eq!(scaled.coefficients(), (1, vec![3, 3, 3, 3, 3, 3]));
+    /// assert_eq!(scaled.total_outcome_count(), 18);
+    ///
+    /// assert_eq!(dice.scale_counts(0).outcome_counts.len(), 0);
+    /// ```
+    pub fn scale_counts(&self, factor: CountType) -> ProbabilityDistribution {
+        let mut probability_distribution = self.clone();
+        probability_distribution.scale_counts_in_place(factor);
+        probability_distribution
+    }
+
+    /// Multiplies every count of the [ProbabilityDistribution] by `factor`, checking for overflow.
+    /// a `factor` of 0 removes every outcome, giving an empty [ProbabilityDistribution].
+    ///
+    /// # Arguments
+    ///
+    /// * `factor` - The [CountType] to multiply every count by.
+    ///
+    /// # Returns
+    ///
+    /// The new [ProbabilityDistribution], or `None` if any count overflows [CountType].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use crate::rusted_dice::probability::ProbabilityDistribution;
+    /// let dice = ProbabilityDistribution::new_dice(6);
+    /// assert_eq!(dice.checked_scale_counts(3).unwrap().total_outcome_count(), 18);
+    /// assert!(dice.checked_scale_counts(u64::MAX).is_some());
+    /// assert!(dice.scale_counts(2).checked_scale_counts(u64::MAX).is_none());
+    /// ```
+    pub fn checked_scale_counts(&self, factor: CountType) -> Option<ProbabilityDistribution> {
+        if factor == 0 {
+            return Some(ProbabilityDistribution::new_empty_distribution());
+        }
+        let outcome_counts = self
+            .outcome_counts
+            .iter()
+            .map(|(outcome, count)| Some((outcome.clone(), count.checked_mul(factor)?)))
+            .collect::<Option<BTreeMap<_, _>>>()?;
+        Some(ProbabilityDistribution { outcome_counts })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::probability::{ProbabilityDistribution, ToTable};
+    use crate::CountType;
+
+    #[test]
+    fn test_scale_counts_empty() {
+        let empty = ProbabilityDistribution::new_empty_distribution();
+        assert_eq!(empty.scale_counts(5).outcome_counts.len(), 0);
+        assert_eq!(
+            empty.checked_scale_counts(5).unwrap().outcome_counts.len(),
+            0
+        );
+    }
+
+    #[test]
+    fn test_scale_counts_d6() {
+        let dice = ProbabilityDistribution::new_dice(6);
+        let scaled = dice.scale_counts(3);
+        assert!(scaled.outcome_counts.values().all(|count| *count == 3));
+        assert_eq!(scaled.total_outcome_count(), 18);
+        assert_eq!(dice.total_outcome_count(), 6);
+    }
+
+    #[test]
+    fn test_scale_counts_zero() {
+        let dice = ProbabilityDistribution::new_multiple_dice(2, 6).add_self_value_constraint(1);
+        assert_eq!(dice.scale_counts(0).outcome_counts.len(), 0);
+        assert_eq!(
+            dice.checked_scale_counts(0).unwrap().outcome_counts.len(),
+            0
+        );
+    }
+
+    #[test]
+    fn test_scale_counts_keeps_constraints() {
+        let dice = ProbabilityDistribution::new_multiple_dice(2, 4).add_self_value_constraint(1);
+        let scaled = dice.scale_counts(7);
+        assert_eq!(
+            scaled.outcome_counts.keys().collect::<Vec<_>>(),
+            dice.outcome_counts.keys().collect::<Vec<_>>()
+        );
+        assert_eq!(
+            scaled.to_table().to_string(),
+            dice.checked_scale_counts(7).unwrap().to_table().to_string()
+        );
+    }
+
+    #[test]
+    fn test_checked_scale_counts_overflow() {
+        let dice = ProbabilityDistribution::new_multiple_dice(2, 6);
+        assert!(dice.checked_scale_counts(CountType::MAX / 6).is_some());
+        assert!(dice.checked_scale_counts(CountType::MAX / 6 + 1).is_none());
+    }
+}
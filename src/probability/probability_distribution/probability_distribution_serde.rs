@@ -0,0 +1,220 @@
+use std::collections::BTreeMap;
+
+use serde::de::Error;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::probability::{ProbabilityDistribution, ProbabilityOutcome};
+use crate::CountType;
+
+use super::checked_add_outcome_to_map;
+
+/// a single serialized entry of a [ProbabilityDistribution], borrowing the [ProbabilityOutcome].
+#[derive(Serialize)]
+struct OutcomeCountRef<'a> {
+    outcome: &'a ProbabilityOutcome,
+    count: CountType,
+}
+
+/// a single deserialized entry of a [ProbabilityDistribution].
+#[derive(Deserialize)]
+struct OutcomeCount {
+    outcome: ProbabilityOutcome,
+    count: CountType,
+}
+
+impl Serialize for ProbabilityDistribution {
+    /// Serializes the [ProbabilityDistribution] as a list of `{outcome, count}` pairs,
+    /// in the order of the outcome counts map.
+    ///
+    /// # Arguments
+    ///
+    /// * `serializer` - The [Serializer] to write to.
+    ///
+    /// # Returns
+    ///
+    /// The result of the [Serializer].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use crate::rusted_dice::probability::ProbabilityDistribution;
+    /// let dice = ProbabilityDistribution::new_dice(2);
+    /// assert_eq!(
+    ///     serde_json::to_string(&dice).unwrap(),
+    ///     "[\
+    ///     {\"outcome\":{\"value\":1,\"constraint_map\":{\"map\":{}}},\"count\":1},\
+    ///     {\"outcome\":{\"value\":2,\"constraint_map\":{\"map\":{}}},\"count\":1}\
+    ///     ]"
+    /// );
+    /// ```
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(
+            self.outcome_counts
+                .iter()
+                .map(|(outcome, count)| OutcomeCountRef {
+                    outcome,
+                    count: *count,
+                }),
+        )
+    }
+}
+
+impl<'de> Deserialize<'de> for ProbabilityDistribution {
+    /// Deserializes a [ProbabilityDistribution] from a list of `{outcome, count}` pairs.
+    /// the outcome counts map is rebuilt with [checked_add_outcome_to_map],
+    /// so the counts of duplicate outcomes are added together.
+    /// the input is rejected if any count is 0 or the counts of an outcome do not fit in [CountType].
+    ///
+    /// # Arguments
+    ///
+    /// * `deserializer` - The [Deserializer] to read from.
+    ///
+    /// # Returns
+    ///
+    /// The new [ProbabilityDistribution] or the error of the [Deserializer].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use crate::rusted_dice::probability::ProbabilityDistribution;
+    /// # use crate::rusted_dice::probability::ProbabilityOutcome;
+    /// let probability_distribution: ProbabilityDistribution = serde_json::from_str(
+    ///     "[\
+    ///     {\"outcome\":{\"value\":3,\"constraint_map\":{\"map\":{}}},\"count\":2},\
+    ///     {\"outcome\":{\"value\":3,\"constraint_map\":{\"map\":{}}},\"count\":5}\
+    ///     ]",
+    /// )
+    /// .unwrap();
+    /// assert_eq!(
+    ///     probability_distribution
+    ///         .outcome_counts
+    ///         .get(&ProbabilityOutcome::new_with_empty_constraint_map(3)),
+    ///     Some(&7)
+    /// );
+    /// ```
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let outcome_counts = Vec::<OutcomeCount>::deserialize(deserializer)?;
+
+        let mut map = BTreeMap::new();
+        for outcome_count in outcome_counts {
+            if outcome_count.count == 0 {
+                return Err(D::Error::custom("Outcome has a count of zero."));
+            }
+            checked_add_outcome_to_map(&mut map, outcome_count.outcome, outcome_count.count)
+                .ok_or_else(|| D::Error::custom("Outcome counts overflowed CountType."))?;
+        }
+        Ok(ProbabilityDistribution {
+            outcome_counts: map,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::probability::{ProbabilityDistribution, ProbabilityOutcome};
+
+    #[test]
+    fn test_serde_empty() {
+        let probability_distribution = ProbabilityDistribution::new_empty_distribution();
+        let json = serde_json::to_string(&probability_distribution).unwrap();
+        assert_eq!(json, "[]");
+
+        let round_trip: ProbabilityDistribution = serde_json::from_str(&json).unwrap();
+        assert_eq!(round_trip.outcome_counts.len(), 0);
+    }
+
+    #[test]
+    fn test_serde_round_trip_two_d6() {
+        let two_d6 = ProbabilityDistribution::new_multiple_dice(2, 6);
+        let json = serde_json::to_string(&two_d6).unwrap();
+        let round_trip: ProbabilityDistribution = serde_json::from_str(&json).unwrap();
+        assert_eq!(round_trip.outcome_counts, two_d6.outcome_counts);
+    }
+
+    #[test]
+    fn test_serde_round_trip_constraints() {
+        let dice_one = ProbabilityDistribution::new_dice(4).add_self_value_constraint(1);
+        let dice_two = ProbabilityDistribution::new_dice(4).add_self_value_constraint(2);
+        let probability_distribution = (dice_one + dice_two) * 2;
+
+        let json = serde_json::to_string(&probability_distribution).unwrap();
+        let round_trip: ProbabilityDistribution = serde_json::from_str(&json).unwrap();
+        assert_eq!(
+            round_trip.outcome_counts,
+            probability_distribution.outcome_counts
+        );
+    }
+
+    #[test]
+    fn test_serde_keeps_map_order() {
+        let probability_distribution = ProbabilityDistribution::new_multiple_dice(3, 4);
+        let values: Vec<i64> = serde_json::from_str::<serde_json::Value>(
+            &serde_json::to_string(&probability_distribution).unwrap(),
+        )
+        .unwrap()
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|entry| entry["outcome"]["value"].as_i64().unwrap())
+        .collect();
+        assert_eq!(values, (3..=12).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn test_deserialize_accumulates_duplicates() {
+        let json = "[\
+            {\"outcome\":{\"value\":1,\"constraint_map\":{\"map\":{}}},\"count\":2},\
+            {\"outcome\":{\"value\":2,\"constraint_map\":{\"map\":{}}},\"count\":1},\
+            {\"outcome\":{\"value\":1,\"constraint_map\":{\"map\":{}}},\"count\":3}\
+            ]";
+        let probability_distribution: ProbabilityDistribution = serde_json::from_str(json).unwrap();
+
+        assert_eq!(
+            probability_distribution
+                .outcome_counts
+                .get(&ProbabilityOutcome::new_with_empty_constraint_map(1)),
+            Some(&5)
+        );
+        assert_eq!(
+            probability_distribution
+                .outcome_counts
+                .get(&ProbabilityOutcome::new_with_empty_constraint_map(2)),
+            Some(&1)
+        );
+        assert_eq!(probability_distribution.outcome_counts.len(), 2);
+    }
+
+    #[test]
+    fn test_deserialize_count_overflow() {
+        let json = "[\
+            {\"outcome\":{\"value\":1,\"constraint_map\":{\"map\":{}}},\"count\":18446744073709551615},\
+            {\"outcome\":{\"value\":1,\"constraint_map\":{\"map\":{}}},\"count\":1}\
+            ]";
+        let error = serde_json::from_str::<ProbabilityDistribution>(json).unwrap_err();
+        assert!(error
+            .to_string()
+            .starts_with("Outcome counts overflowed CountType."));
+    }
+
+    #[test]
+    fn test_deserialize_zero_count() {
+        let json = "[{\"outcome\":{\"value\":1,\"constraint_map\":{\"map\":{}}},\"count\":0}]";
+        let error = serde_json::from_str::<ProbabilityDistribution>(json).unwrap_err();
+        assert!(error
+            .to_string()
+            .starts_with("Outcome has a count of zero."));
+    }
+
+    #[test]
+    fn test_deserialize_mismatched_constraint_key() {
+        let json = "[{\"outcome\":{\"value\":1,\"constraint_map\":\
+            {\"map\":{\"1\":{\"id\":2,\"valid_values\":[1]}}}},\"count\":1}]";
+        assert!(serde_json::from_str::<ProbabilityDistribution>(json).is_err());
+    }
+
+    #[test]
+    fn test_deserialize_invalid() {
+        assert!(serde_json::from_str::<ProbabilityDistribution>("{\"count\":1}").is_err());
+        assert!(serde_json::from_str::<ProbabilityDistribution>("[{\"count\":1}]").is_err());
+    }
+}
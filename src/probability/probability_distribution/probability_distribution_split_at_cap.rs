@@ -0,0 +1,154 @@
+use crate::probability::{Combine, ProbabilityDistribution};
+use crate::ValueType;
+
+fn _capped(value: ValueType, cap: ValueType) -> ValueType {
+    value.min(cap)
+}
+
+fn _overflow(value: ValueType, cap: ValueType) -> ValueType {
+    if value > cap {
+        value - cap
+    } else {
+        0
+    }
+}
+
+impl ProbabilityDistribution {
+    /// Splits every value of the [ProbabilityDistribution] into the part up to `cap` and the part above it.
+    /// useful to model damage dealt up to the health left and the overkill carried past it.
+    ///
+    /// both parts keep the counts and constraint maps of the outcome they came from,
+    /// so for every outcome the capped value plus the overflow is the original value.
+    ///
+    /// # Arguments
+    ///
+    /// * `cap` - The [ValueType] to split at.
+    ///
+    /// # Returns
+    ///
+    /// A tuple of the [ProbabilityDistribution] of `min(value, cap)`
+    /// and the [ProbabilityDistribution] of `max(0, value - cap)`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `value - cap` overflows a [ValueType].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use crate::rusted_dice::probability::ProbabilityDistribution;
+    /// # use crate::rusted_dice::probability::ToTable;
+    /// let (damage, overkill) = ProbabilityDistribution::new_dice(4).split_at_cap(3);
+    ///
+    /// assert_eq!(
+    ///     damage.to_table().to_string().replace("\r\n", "\n"),
+    ///     "\
+    ///     +-------+-------+\n\
+    ///     | value | count |\n\
+    ///     +=======+=======+\n\
+    ///     | 1     | 1     |\n\
+    ///     +-------+-------+\n\
+    ///     | 2     | 1     |\n\
+    ///     +-------+-------+\n\
+    ///     | 3     | 2     |\n\
+    ///     +-------+-------+\n\
+    ///     ");
+    /// assert_eq!(
+    ///     overkill.to_table().to_string().replace("\r\n", "\n"),
+    ///     "\
+    ///     +-------+-------+\n\
+    ///     | value | count |\n\
+    ///     +=======+=======+\n\
+    ///     | 0     | 3     |\n\
+    ///     +-------+-------+\n\
+    ///     | 1     | 1     |\n\
+    ///     +-------+-------+\n\
+    ///     ");
+    /// ```
+    pub fn split_at_cap(&self, cap: ValueType) -> (Self, Self) {
+        (
+            self.combine_value_type(cap, _capped),
+            self.combine_value_type(cap, _overflow),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::probability::ProbabilityDistribution;
+    use crate::ValueType;
+
+    #[test]
+    fn test_split_at_cap_empty() {
+        let (capped, overflow) = ProbabilityDistribution::new_empty_distribution().split_at_cap(3);
+        assert_eq!(capped.outcome_counts.len(), 0);
+        assert_eq!(overflow.outcome_counts.len(), 0);
+    }
+
+    #[test]
+    fn test_split_at_cap_parts_sum_to_value() {
+        let two_d6 = ProbabilityDistribution::new_multiple_dice(2, 6) - 4;
+        for outcome in two_d6.outcome_counts.keys() {
+            let (capped, overflow) = ProbabilityDistribution::from(outcome.clone()).split_at_cap(5);
+            let capped_value = capped.outcome_counts.keys().next().unwrap().value;
+            let overflow_value = overflow.outcome_counts.keys().next().unwrap().value;
+
+            assert_eq!(capped_value + overflow_value, outcome.value);
+            assert!(capped_value <= 5);
+            assert!(overflow_value >= 0);
+        }
+    }
+
+    #[test]
+    fn test_split_at_cap_preserves_total_and_mean() {
+        let three_d6 = ProbabilityDistribution::new_multiple_dice(3, 6);
+        let (capped, overflow) = three_d6.split_at_cap(12);
+
+        assert_eq!(capped.total_outcome_count(), 216);
+        assert_eq!(overflow.total_outcome_count(), 216);
+        assert!((capped.mean() + overflow.mean() - three_d6.mean()).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_split_at_cap_below_every_value() {
+        let dice = ProbabilityDistribution::new_dice(6);
+        let (capped, overflow) = dice.split_at_cap(0);
+
+        assert_eq!(capped.coefficients(), (0, vec![6]));
+        assert_eq!(overflow.coefficients(), dice.coefficients());
+    }
+
+    #[test]
+    fn test_split_at_cap_above_every_value() {
+        let dice = ProbabilityDistribution::new_dice(6);
+        let (capped, overflow) = dice.split_at_cap(10);
+
+        assert_eq!(capped.coefficients(), dice.coefficients());
+        assert_eq!(overflow.coefficients(), (0, vec![6]));
+    }
+
+    #[test]
+    fn test_split_at_cap_keeps_constraints() {
+        let dice = ProbabilityDistribution::new_dice(4).add_self_value_constraint(1);
+        let (capped, overflow) = dice.split_at_cap(2);
+
+        assert_eq!(capped.outcome_counts.len(), 4);
+        assert_eq!(overflow.outcome_counts.len(), 4);
+        for (capped_outcome, overflow_outcome) in capped
+            .outcome_counts
+            .keys()
+            .zip(overflow.outcome_counts.keys())
+        {
+            assert_eq!(
+                capped_outcome.constraint_map,
+                overflow_outcome.constraint_map
+            );
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "attempt to subtract with overflow")]
+    fn test_split_at_cap_overflow() {
+        ProbabilityDistribution::from(ValueType::MAX).split_at_cap(-1);
+    }
+}
@@ -0,0 +1,128 @@
+use crate::probability::ProbabilityDistribution;
+
+impl ProbabilityDistribution {
+    /// Calculates the mean value of the [ProbabilityDistribution].
+    /// each value is weighted by its count, outcomes differing only in constraints are counted together.
+    ///
+    /// # Returns
+    ///
+    /// The mean as a [f64], [f64::NAN] for an empty [ProbabilityDistribution].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use crate::rusted_dice::probability::ProbabilityDistribution;
+    /// assert_eq!(ProbabilityDistribution::new_dice(6).mean(), 3.5);
+    /// assert_eq!(ProbabilityDistribution::new_multiple_dice(2, 6).mean(), 7.0);
+    /// assert!(ProbabilityDistribution::new_empty_distribution().mean().is_nan());
+    /// ```
+    pub fn mean(&self) -> f64 {
+        let weighted_sum: f64 = self
+            .outcome_counts
+            .iter()
+            .map(|(outcome, count)| outcome.value as f64 * *count as f64)
+            .sum();
+        weighted_sum / self.total_outcome_count() as f64
+    }
+
+    /// Calculates the variance of the values of the [ProbabilityDistribution].
+    /// the count weighted mean of the squared distance of each value from the [ProbabilityDistribution::mean].
+    ///
+    /// # Returns
+    ///
+    /// The variance as a [f64], [f64::NAN] for an empty [ProbabilityDistribution].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use crate::rusted_dice::probability::ProbabilityDistribution;
+    /// assert_eq!(ProbabilityDistribution::new_dice(3).variance(), 2.0 / 3.0);
+    /// assert_eq!(ProbabilityDistribution::from(5).variance(), 0.0);
+    /// assert!(ProbabilityDistribution::new_empty_distribution().variance().is_nan());
+    /// ```
+    pub fn variance(&self) -> f64 {
+        let mean = self.mean();
+        let weighted_sum: f64 = self
+            .outcome_counts
+            .iter()
+            .map(|(outcome, count)| (outcome.value as f64 - mean).powi(2) * *count as f64)
+            .sum();
+        weighted_sum / self.total_outcome_count() as f64
+    }
+
+    /// Calculates the standard deviation of the values of the [ProbabilityDistribution].
+    /// the square root of the [ProbabilityDistribution::variance].
+    ///
+    /// # Returns
+    ///
+    /// The standard deviation as a [f64], [f64::NAN] for an empty [ProbabilityDistribution].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use crate::rusted_dice::probability::ProbabilityDistribution;
+    /// assert_eq!(
+    ///     ProbabilityDistribution::new_dice(3).standard_deviation(),
+    ///     (2.0_f64 / 3.0).sqrt()
+    /// );
+    /// ```
+    pub fn standard_deviation(&self) -> f64 {
+        self.variance().sqrt()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::constraint_management::Constraint;
+    use crate::probability::{ProbabilityDistribution, ProbabilityOutcome};
+
+    #[test]
+    fn test_stats_empty() {
+        let probability_distribution = ProbabilityDistribution::new_empty_distribution();
+        assert!(probability_distribution.mean().is_nan());
+        assert!(probability_distribution.variance().is_nan());
+        assert!(probability_distribution.standard_deviation().is_nan());
+    }
+
+    #[test]
+    fn test_stats_d6() {
+        let d6 = ProbabilityDistribution::new_dice(6);
+        assert_eq!(d6.mean(), 3.5);
+        assert!((d6.variance() - 35.0 / 12.0).abs() < 1e-12);
+        assert!((d6.variance() - 2.9167).abs() < 1e-4);
+        assert!((d6.standard_deviation() - (35.0_f64 / 12.0).sqrt()).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_stats_single_outcome() {
+        let probability_distribution = ProbabilityDistribution::new_from_single_probability_outcome(
+            ProbabilityOutcome::new_with_empty_constraint_map(-9),
+        );
+        assert_eq!(probability_distribution.mean(), -9.0);
+        assert_eq!(probability_distribution.variance(), 0.0);
+        assert_eq!(probability_distribution.standard_deviation(), 0.0);
+    }
+
+    #[test]
+    fn test_stats_weighted_by_count() {
+        let probability_distribution =
+            ProbabilityDistribution::new_from_many_probability_outcomes(vec![
+                ProbabilityOutcome::new_with_empty_constraint_map(0),
+                ProbabilityOutcome::new_with_empty_constraint_map(4),
+                ProbabilityOutcome::new_with_empty_constraint_map(4),
+                ProbabilityOutcome::new_with_constraints(
+                    4,
+                    vec![Constraint::new_single_valid_value_constraint(1, 1)],
+                ),
+            ]);
+        assert_eq!(probability_distribution.mean(), 3.0);
+        assert_eq!(probability_distribution.variance(), 3.0);
+    }
+
+    #[test]
+    fn test_stats_sum_of_dice() {
+        let three_d6 = ProbabilityDistribution::new_multiple_dice(3, 6);
+        assert_eq!(three_d6.mean(), 10.5);
+        assert!((three_d6.variance() - 3.0 * 35.0 / 12.0).abs() < 1e-9);
+    }
+}
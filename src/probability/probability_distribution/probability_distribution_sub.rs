@@ -155,6 +155,45 @@ impl Sub<ProbabilityDistribution> for ValueType {
     }
 }
 
+impl ProbabilityDistribution {
+    /// Subtracts the [ProbabilityDistribution] from a [ValueType], computing `lhs - self`.
+    /// the named form of the [ValueType] - [ProbabilityDistribution] operator for use where operator syntax is awkward.
+    /// constraint map is taken from the [ProbabilityDistribution].
+    ///
+    /// # Arguments
+    ///
+    /// * `lhs` - The [ValueType] to subtract from.
+    ///
+    /// # Returns
+    ///
+    /// The resulting [ProbabilityDistribution] after the subtraction operation.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use crate::rusted_dice::probability::ProbabilityDistribution;
+    /// # use crate::rusted_dice::probability::ToTable;
+    /// let dice = ProbabilityDistribution::new_dice(3);
+    ///
+    /// assert_eq!(
+    ///     dice.rsub(10).to_table().to_string().replace("\r\n", "\n"),
+    ///     "\
+    ///     +-------+-------+\n\
+    ///     | value | count |\n\
+    ///     +=======+=======+\n\
+    ///     | 7     | 1     |\n\
+    ///     +-------+-------+\n\
+    ///     | 8     | 1     |\n\
+    ///     +-------+-------+\n\
+    ///     | 9     | 1     |\n\
+    ///     +-------+-------+\n\
+    ///     ");
+    /// ```
+    pub fn rsub(&self, lhs: ValueType) -> Self {
+        self.value_type_combine(lhs, _sub)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::probability::{ProbabilityDistribution, ProbabilityOutcome, ToTable};
@@ -304,4 +343,33 @@ mod tests {
             ProbabilityDistribution::new_from_single_probability_outcome(probability_outcome);
         let _ = ValueType::MIN - probability_distribution;
     }
+
+    #[test]
+    fn test_rsub() {
+        let dice = ProbabilityDistribution::new_dice(6);
+        assert_eq!(dice.rsub(10).to_table(), (10 - dice).to_table());
+    }
+
+    #[test]
+    fn test_rsub_keeps_constraints() {
+        let dice = ProbabilityDistribution::new_dice(4).add_self_value_constraint(1)
+            + ProbabilityDistribution::new_dice(4);
+        assert_eq!(dice.rsub(-3).to_table(), (-3 - dice.clone()).to_table());
+    }
+
+    #[test]
+    fn test_rsub_empty() {
+        let probability_distribution = ProbabilityDistribution::new_empty_distribution();
+        assert_eq!(probability_distribution.rsub(5).outcome_counts.len(), 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "attempt to subtract with overflow")]
+    fn test_rsub_underflow() {
+        let probability_outcome = ProbabilityOutcome::new_with_empty_constraint_map(1);
+
+        let probability_distribution =
+            ProbabilityDistribution::new_from_single_probability_outcome(probability_outcome);
+        let _ = probability_distribution.rsub(ValueType::MIN);
+    }
 }
@@ -0,0 +1,132 @@
+use crate::probability::ProbabilityDistribution;
+
+use super::{ordered_column_names, ToHashMap};
+
+/// a helper function to quote a CSV field if it holds a comma, quote or line break.
+fn escape_csv_field(field: &str) -> String {
+    if field.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+impl ProbabilityDistribution {
+    /// converts a [ProbabilityDistribution] into comma separated values, for sharing with spreadsheets.
+    /// the columns are the same as [ToTable::to_table](crate::probability::ToTable::to_table),
+    /// a header row followed by one row per outcome separated by line breaks.
+    /// fields holding commas, such as constraints with many valid values, are quoted.
+    ///
+    /// # Returns
+    /// * the CSV as a [String]
+    ///
+    /// # Example
+    /// ```
+    /// # use crate::rusted_dice::probability::ProbabilityDistribution;
+    /// let dice = ProbabilityDistribution::new_dice(2).add_self_value_constraint(1);
+    /// assert_eq!(dice.to_csv(), "value,count,1\n1,1,1\n2,1,2");
+    ///
+    /// assert_eq!(ProbabilityDistribution::new_empty_distribution().to_csv(), "value,count");
+    /// ```
+    pub fn to_csv(&self) -> String {
+        let hash_map = self.to_hash_map();
+        let column_names = ordered_column_names(&hash_map);
+        let columns: Vec<&Vec<Option<String>>> = column_names
+            .iter()
+            .map(|column_name| hash_map.get(column_name).unwrap())
+            .collect();
+
+        let mut lines = vec![column_names
+            .iter()
+            .map(|column_name| escape_csv_field(column_name))
+            .collect::<Vec<String>>()
+            .join(",")];
+        for i in 0..self.outcome_counts.len() {
+            lines.push(
+                columns
+                    .iter()
+                    .map(|column| escape_csv_field(column[i].as_deref().unwrap_or_default()))
+                    .collect::<Vec<String>>()
+                    .join(","),
+            );
+        }
+        lines.join("\n")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use crate::constraint_management::Constraint;
+    use crate::probability::{ProbabilityDistribution, ProbabilityOutcome};
+
+    use super::escape_csv_field;
+
+    #[test]
+    fn test_escape_csv_field() {
+        assert_eq!(escape_csv_field("12"), "12");
+        assert_eq!(escape_csv_field(""), "");
+        assert_eq!(escape_csv_field("1, 2, 3"), "\"1, 2, 3\"");
+        assert_eq!(escape_csv_field("a \"b\""), "\"a \"\"b\"\"\"");
+        assert_eq!(escape_csv_field("a\nb"), "\"a\nb\"");
+    }
+
+    #[test]
+    fn test_to_csv_empty() {
+        assert_eq!(
+            ProbabilityDistribution::new_empty_distribution().to_csv(),
+            "value,count"
+        );
+    }
+
+    #[test]
+    fn test_to_csv_no_constraints() {
+        assert_eq!(
+            ProbabilityDistribution::new_multiple_dice(2, 2).to_csv(),
+            "value,count\n2,1\n3,2\n4,1"
+        );
+    }
+
+    #[test]
+    fn test_to_csv_many_constraints() {
+        let mut b_tree_map = BTreeMap::new();
+        b_tree_map.insert(
+            ProbabilityOutcome::new_with_constraints(
+                12345,
+                vec![Constraint::new_single_valid_value_constraint(1, 3)],
+            ),
+            67890,
+        );
+        b_tree_map.insert(
+            ProbabilityOutcome::new_with_constraints(
+                55555,
+                vec![Constraint::new_single_valid_value_constraint(9, 4)],
+            ),
+            66666,
+        );
+        b_tree_map.insert(ProbabilityOutcome::new_with_empty_constraint_map(98766), 1);
+        b_tree_map.insert(
+            ProbabilityOutcome::new_with_constraints(
+                12354,
+                vec![
+                    Constraint::new_many_item_constraint(8, vec![3, 2, 1]),
+                    Constraint::new_many_item_constraint(1, vec![3, 5, 4]),
+                ],
+            ),
+            2,
+        );
+
+        let csv = ProbabilityDistribution {
+            outcome_counts: b_tree_map,
+        }
+        .to_csv();
+        let out = "\
+        value,count,1,8,9\n\
+        12345,67890,3,,\n\
+        12354,2,\"3, 4, 5\",\"1, 2, 3\",\n\
+        55555,66666,,,4\n\
+        98766,1,,,";
+        assert_eq!(csv, out);
+    }
+}
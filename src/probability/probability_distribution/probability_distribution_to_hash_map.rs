@@ -157,13 +157,47 @@ impl ToHashMap for ProbabilityDistribution {
     }
 }
 
+/// Returns the column names of a [ToHashMap::to_hash_map] result in display order,
+/// value then count then the constraint ids sorted as strings.
+///
+/// # Arguments
+/// * `hash_map` - the result of [ToHashMap::to_hash_map]
+///
+/// # Example
+/// ```
+/// # use crate::rusted_dice::probability::ProbabilityDistribution;
+/// # use crate::rusted_dice::probability::probability_distribution::{ordered_column_names, ToHashMap};
+/// let probability_distribution = ProbabilityDistribution::new_dice(2).add_self_value_constraint(12)
+///     + ProbabilityDistribution::new_dice(2).add_self_value_constraint(3);
+///
+/// assert_eq!(
+///     ordered_column_names(&probability_distribution.to_hash_map()),
+///     vec!["value", "count", "12", "3"]
+/// );
+/// ```
+pub fn ordered_column_names(hash_map: &HashMap<String, Vec<Option<String>>>) -> Vec<String> {
+    let mut constraint_names: Vec<String> = hash_map
+        .keys()
+        .filter(|column_name| *column_name != "value" && *column_name != "count")
+        .cloned()
+        .collect();
+    constraint_names.sort();
+
+    ["value".to_string(), "count".to_string()]
+        .into_iter()
+        .chain(constraint_names)
+        .collect()
+}
+
 #[cfg(test)]
 mod tests {
     use std::collections::BTreeMap;
     use std::collections::HashMap;
 
     use crate::constraint_management::Constraint;
-    use crate::probability::probability_distribution::probability_distribution_to_hash_map::ToHashMap;
+    use crate::probability::probability_distribution::probability_distribution_to_hash_map::{
+        ordered_column_names, ToHashMap,
+    };
     use crate::probability::{ProbabilityDistribution, ProbabilityOutcome};
 
     #[test]
@@ -430,4 +464,43 @@ mod tests {
 
         assert_eq!(result, table);
     }
+
+    #[test]
+    fn ordered_column_names_empty() {
+        assert_eq!(
+            ordered_column_names(&ProbabilityDistribution::new_empty_distribution().to_hash_map()),
+            vec!["value", "count"]
+        );
+    }
+
+    #[test]
+    fn ordered_column_names_many_constraints() {
+        let mut b_tree_map = BTreeMap::new();
+        b_tree_map.insert(
+            ProbabilityOutcome::new_with_constraints(
+                1,
+                vec![
+                    Constraint::new_single_valid_value_constraint(9, 1),
+                    Constraint::new_single_valid_value_constraint(10, 1),
+                ],
+            ),
+            1,
+        );
+        b_tree_map.insert(
+            ProbabilityOutcome::new_with_constraints(
+                2,
+                vec![Constraint::new_single_valid_value_constraint(2, 1)],
+            ),
+            1,
+        );
+
+        let hash_map = ProbabilityDistribution {
+            outcome_counts: b_tree_map,
+        }
+        .to_hash_map();
+        assert_eq!(
+            ordered_column_names(&hash_map),
+            vec!["value", "count", "10", "2", "9"]
+        );
+    }
 }
@@ -0,0 +1,162 @@
+use crate::probability::ProbabilityDistribution;
+
+use super::{ordered_column_names, ToHashMap};
+
+/// a helper function to write a [str] as a quoted JSON string.
+fn escape_json_string(string: &str) -> String {
+    let mut escaped = String::with_capacity(string.len() + 2);
+    escaped.push('"');
+    for character in string.chars() {
+        match character {
+            '"' => escaped.push_str("\\\""),
+            '\\' => escaped.push_str("\\\\"),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => escaped.push_str("\\r"),
+            '\t' => escaped.push_str("\\t"),
+            character if character.is_control() => {
+                escaped.push_str(&format!("\\u{:04x}", character as u32))
+            }
+            character => escaped.push(character),
+        }
+    }
+    escaped.push('"');
+    escaped
+}
+
+impl ProbabilityDistribution {
+    /// converts a [ProbabilityDistribution] into a JSON array with one object per outcome.
+    /// each object is keyed by the column names of [ToTable::to_table](crate::probability::ToTable::to_table),
+    /// value and count are JSON numbers, constraints are JSON strings of their sorted valid values,
+    /// constraints an outcome does not have are JSON `null`.
+    ///
+    /// # Returns
+    /// * the JSON as a [String]
+    ///
+    /// # Example
+    /// ```
+    /// # use crate::rusted_dice::probability::ProbabilityDistribution;
+    /// let dice = ProbabilityDistribution::new_dice(2).add_self_value_constraint(1);
+    /// assert_eq!(
+    ///     dice.to_json(),
+    ///     r#"[{"value":1,"count":1,"1":"1"},{"value":2,"count":1,"1":"2"}]"#
+    /// );
+    ///
+    /// assert_eq!(ProbabilityDistribution::new_empty_distribution().to_json(), "[]");
+    /// ```
+    pub fn to_json(&self) -> String {
+        let hash_map = self.to_hash_map();
+        let column_names = ordered_column_names(&hash_map);
+        let columns: Vec<&Vec<Option<String>>> = column_names
+            .iter()
+            .map(|column_name| hash_map.get(column_name).unwrap())
+            .collect();
+
+        let rows: Vec<String> = (0..self.outcome_counts.len())
+            .map(|i| {
+                let fields: Vec<String> = column_names
+                    .iter()
+                    .zip(columns.iter())
+                    .enumerate()
+                    .map(|(column_index, (column_name, column))| {
+                        let cell = match &column[i] {
+                            Some(cell) if column_index < 2 => cell.clone(),
+                            Some(cell) => escape_json_string(cell),
+                            None => "null".to_string(),
+                        };
+                        format!("{}:{}", escape_json_string(column_name), cell)
+                    })
+                    .collect();
+                format!("{{{}}}", fields.join(","))
+            })
+            .collect();
+        format!("[{}]", rows.join(","))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use crate::constraint_management::Constraint;
+    use crate::probability::{ProbabilityDistribution, ProbabilityOutcome};
+
+    use super::escape_json_string;
+
+    #[test]
+    fn test_escape_json_string() {
+        assert_eq!(escape_json_string(""), "\"\"");
+        assert_eq!(escape_json_string("1, 2, 3"), "\"1, 2, 3\"");
+        assert_eq!(escape_json_string("a\"b\\c"), "\"a\\\"b\\\\c\"");
+        assert_eq!(escape_json_string("a\nb\tc"), "\"a\\nb\\tc\"");
+        assert_eq!(escape_json_string("\u{1}"), "\"\\u0001\"");
+    }
+
+    #[test]
+    fn test_to_json_empty() {
+        assert_eq!(
+            ProbabilityDistribution::new_empty_distribution().to_json(),
+            "[]"
+        );
+    }
+
+    #[test]
+    fn test_to_json_no_constraints() {
+        assert_eq!(
+            (ProbabilityDistribution::new_dice(2) - 2).to_json(),
+            r#"[{"value":-1,"count":1},{"value":0,"count":1}]"#
+        );
+    }
+
+    #[test]
+    fn test_to_json_two_constraints() {
+        let mut b_tree_map = BTreeMap::new();
+        b_tree_map.insert(
+            ProbabilityOutcome::new_with_constraints(
+                12345,
+                vec![Constraint::new_single_valid_value_constraint(1, 3)],
+            ),
+            67890,
+        );
+        b_tree_map.insert(ProbabilityOutcome::new_with_empty_constraint_map(98766), 1);
+        b_tree_map.insert(
+            ProbabilityOutcome::new_with_constraints(
+                12354,
+                vec![
+                    Constraint::new_many_item_constraint(8, vec![3, 2, 1]),
+                    Constraint::new_many_item_constraint(1, vec![3, 5, 4]),
+                ],
+            ),
+            2,
+        );
+
+        let json = ProbabilityDistribution {
+            outcome_counts: b_tree_map,
+        }
+        .to_json();
+        let out = concat!(
+            "[",
+            r#"{"value":12345,"count":67890,"1":"3","8":null},"#,
+            r#"{"value":12354,"count":2,"1":"3, 4, 5","8":"1, 2, 3"},"#,
+            r#"{"value":98766,"count":1,"1":null,"8":null}"#,
+            "]"
+        );
+        assert_eq!(json, out);
+    }
+
+    #[test]
+    fn test_to_json_is_valid_json() {
+        let probability_distribution = ProbabilityDistribution::new_dice(3)
+            .add_self_value_constraint(1)
+            + ProbabilityDistribution::new_dice(3).add_self_value_constraint(2)
+            + ProbabilityDistribution::new_dice(2);
+        let parsed: serde_json::Value =
+            serde_json::from_str(&probability_distribution.to_json()).unwrap();
+
+        let rows = parsed.as_array().unwrap();
+        assert_eq!(rows.len(), probability_distribution.outcome_counts.len());
+        assert_eq!(rows[0]["value"], 3);
+        assert_eq!(rows[0]["count"], 1);
+        assert_eq!(rows[0]["1"], "1");
+        assert_eq!(rows[0]["2"], "1");
+    }
+}
@@ -0,0 +1,195 @@
+use std::collections::BTreeMap;
+
+use prettytable::{Cell, Table};
+
+use crate::probability::{ProbabilityDistribution, ProbabilityOutcome};
+
+use super::{ordered_column_names, ToHashMap, ToTable};
+
+impl ProbabilityDistribution {
+    /// Returns the probability of every outcome of the [ProbabilityDistribution],
+    /// each count divided by the [total_outcome_count](ProbabilityDistribution::total_outcome_count).
+    /// an empty [ProbabilityDistribution] gives an empty [BTreeMap].
+    ///
+    /// # Returns
+    ///
+    /// A [BTreeMap] from each [ProbabilityOutcome] to its probability as a [f64].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use crate::rusted_dice::probability::ProbabilityDistribution;
+    /// # use crate::rusted_dice::probability::ProbabilityOutcome;
+    /// let coin = ProbabilityDistribution::new_dice(2);
+    /// let probabilities = coin.to_probabilities();
+    /// assert_eq!(
+    ///     probabilities.get(&ProbabilityOutcome::new_with_empty_constraint_map(1)),
+    ///     Some(&0.5)
+    /// );
+    /// assert_eq!(probabilities.values().sum::<f64>(), 1.0);
+    /// ```
+    pub fn to_probabilities(&self) -> BTreeMap<ProbabilityOutcome, f64> {
+        let total = self.total_outcome_count() as f64;
+        self.outcome_counts
+            .iter()
+            .map(|(outcome, count)| (outcome.clone(), *count as f64 / total))
+            .collect()
+    }
+
+    /// converts a [ProbabilityDistribution] into a Table (from the prettytable crate)
+    /// with a probability column following the count column.
+    /// the other columns and rows are the same as [ToTable::to_table].
+    ///
+    /// # Arguments
+    /// * `decimals` - the number of decimal places to format each probability to
+    ///
+    /// # Returns
+    /// * the Table with the probability column added
+    ///
+    /// # Example
+    /// ```
+    /// # extern crate prettytable;
+    /// # use crate::rusted_dice::probability::ProbabilityDistribution;
+    /// let dice = ProbabilityDistribution::new_dice(3);
+    /// let table = dice.to_probability_table(3);
+    /// let out = "\
+    /// +-------+-------+-------------+\n\
+    /// | value | count | probability |\n\
+    /// +=======+=======+=============+\n\
+    /// | 1     | 1     | 0.333       |\n\
+    /// +-------+-------+-------------+\n\
+    /// | 2     | 1     | 0.333       |\n\
+    /// +-------+-------+-------------+\n\
+    /// | 3     | 1     | 0.333       |\n\
+    /// +-------+-------+-------------+\n\
+    /// ";
+    /// assert_eq!(table.to_string().replace("\r\n", "\n"), out);
+    /// ```
+    pub fn to_probability_table(&self, decimals: usize) -> Table {
+        let mut column_names = ordered_column_names(&self.to_hash_map());
+        column_names.insert(2, "probability".to_string());
+
+        let mut table = self.to_table();
+        table.set_titles(column_names.into());
+
+        let total = self.total_outcome_count() as f64;
+        for (row, count) in table.row_iter_mut().zip(self.outcome_counts.values()) {
+            row.insert_cell(
+                2,
+                Cell::new(&format!("{:.*}", decimals, *count as f64 / total)),
+            );
+        }
+        table
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::probability::{ProbabilityDistribution, ProbabilityOutcome};
+
+    #[test]
+    fn test_to_probabilities_empty() {
+        assert!(ProbabilityDistribution::new_empty_distribution()
+            .to_probabilities()
+            .is_empty());
+    }
+
+    #[test]
+    fn test_to_probabilities_d6() {
+        let probabilities = ProbabilityDistribution::new_dice(6).to_probabilities();
+
+        assert_eq!(probabilities.len(), 6);
+        for value in 1..=6 {
+            let probability = probabilities
+                .get(&ProbabilityOutcome::new_with_empty_constraint_map(value))
+                .unwrap();
+            assert!((probability - 1.0 / 6.0).abs() < 1e-12);
+        }
+        assert!((probabilities.values().sum::<f64>() - 1.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_to_probabilities_keeps_constraints() {
+        let probability_distribution = ProbabilityDistribution::new_dice(4)
+            .add_self_value_constraint(1)
+            + ProbabilityDistribution::new_dice(4).add_self_value_constraint(2);
+        let probabilities = probability_distribution.to_probabilities();
+
+        assert_eq!(probabilities.len(), 16);
+        for (outcome, probability) in probabilities.iter() {
+            assert!(probability_distribution
+                .outcome_counts
+                .contains_key(outcome));
+            assert!((probability - 1.0 / 16.0).abs() < 1e-12);
+        }
+    }
+
+    #[test]
+    fn test_to_probability_table_empty() {
+        let table = ProbabilityDistribution::new_empty_distribution().to_probability_table(2);
+
+        let out = "\
+        +-------+-------+-------------+\n\
+        | value | count | probability |\n\
+        +=======+=======+=============+\n\
+        +-------+-------+-------------+\n\
+        ";
+        assert_eq!(table.to_string().replace("\r\n", "\n"), out);
+    }
+
+    #[test]
+    fn test_to_probability_table_decimals() {
+        let table = ProbabilityDistribution::new_multiple_dice(2, 2).to_probability_table(0);
+
+        let out = "\
+        +-------+-------+-------------+\n\
+        | value | count | probability |\n\
+        +=======+=======+=============+\n\
+        | 2     | 1     | 0           |\n\
+        +-------+-------+-------------+\n\
+        | 3     | 2     | 0           |\n\
+        +-------+-------+-------------+\n\
+        | 4     | 1     | 0           |\n\
+        +-------+-------+-------------+\n\
+        ";
+        assert_eq!(table.to_string().replace("\r\n", "\n"), out);
+
+        let table = ProbabilityDistribution::new_multiple_dice(2, 2).to_probability_table(4);
+
+        let out = "\
+        +-------+-------+-------------+\n\
+        | value | count | probability |\n\
+        +=======+=======+=============+\n\
+        | 2     | 1     | 0.2500      |\n\
+        +-------+-------+-------------+\n\
+        | 3     | 2     | 0.5000      |\n\
+        +-------+-------+-------------+\n\
+        | 4     | 1     | 0.2500      |\n\
+        +-------+-------+-------------+\n\
+        ";
+        assert_eq!(table.to_string().replace("\r\n", "\n"), out);
+    }
+
+    #[test]
+    fn test_to_probability_table_with_constraints() {
+        let probability_distribution = ProbabilityDistribution::new_dice(2)
+            .add_self_value_constraint(12)
+            + ProbabilityDistribution::new_dice(2).add_self_value_constraint(3);
+        let table = probability_distribution.to_probability_table(2);
+
+        let out = "\
+        +-------+-------+-------------+----+---+\n\
+        | value | count | probability | 12 | 3 |\n\
+        +=======+=======+=============+====+===+\n\
+        | 2     | 1     | 0.25        | 1  | 1 |\n\
+        +-------+-------+-------------+----+---+\n\
+        | 3     | 1     | 0.25        | 2  | 1 |\n\
+        +-------+-------+-------------+----+---+\n\
+        | 3     | 1     | 0.25        | 1  | 2 |\n\
+        +-------+-------+-------------+----+---+\n\
+        | 4     | 1     | 0.25        | 2  | 2 |\n\
+        +-------+-------+-------------+----+---+\n\
+        ";
+        assert_eq!(table.to_string().replace("\r\n", "\n"), out);
+    }
+}
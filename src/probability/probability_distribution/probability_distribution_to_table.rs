@@ -1,10 +1,8 @@
-use std::cmp::Ordering;
-
 use prettytable::Table;
 
 use crate::probability::ProbabilityDistribution;
 
-use super::ToHashMap;
+use super::{ordered_column_names, ToHashMap};
 
 /// A trait for probability distributions to be turned into a Table
 pub trait ToTable {
@@ -81,33 +79,13 @@ impl ToTable for ProbabilityDistribution {
         let hash_map = self.to_hash_map();
 
         let mut table = Table::new();
-        let mut column_names = hash_map.keys().collect::<Vec<&String>>();
-
-        column_names.sort_by(|a, b| {
-            if a == &"value" {
-                Ordering::Less
-            } else if b == &"value" {
-                Ordering::Greater
-            } else if a == &"count" {
-                Ordering::Less
-            } else if b == &"count" {
-                Ordering::Greater
-            } else {
-                a.cmp(b)
-            }
-        });
+        let column_names = ordered_column_names(&hash_map);
 
-        table.set_titles(
-            column_names
-                .clone()
-                .into_iter()
-                .map(|x| x.to_string())
-                .collect(),
-        );
+        table.set_titles(column_names.clone().into());
 
         let columns: Vec<Vec<Option<String>>> = column_names
             .iter()
-            .map(|column_name| hash_map.get(*column_name).unwrap().clone())
+            .map(|column_name| hash_map.get(column_name).unwrap().clone())
             .collect();
 
         for i in 0..columns[0].len() {
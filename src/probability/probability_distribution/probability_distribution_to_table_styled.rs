@@ -0,0 +1,85 @@
+use prettytable::format::TableFormat;
+use prettytable::Table;
+
+use crate::probability::ProbabilityDistribution;
+
+use super::ToTable;
+
+impl ProbabilityDistribution {
+    /// converts a [ProbabilityDistribution] into a Table (from the prettytable crate) using the given format.
+    /// the columns and rows are the same as [ToTable::to_table], only the borders and separators change.
+    ///
+    /// # Arguments
+    /// * `format` - the TableFormat (from the prettytable crate) to render the Table with
+    ///
+    /// # Returns
+    /// * the Table with `format` applied
+    ///
+    /// # Example
+    /// ```
+    /// # extern crate prettytable;
+    /// # use prettytable::format::consts::FORMAT_NO_LINESEP_WITH_TITLE;
+    /// # use crate::rusted_dice::probability::ProbabilityDistribution;
+    /// let dice = ProbabilityDistribution::new_dice(3);
+    /// let table = dice.to_table_styled(*FORMAT_NO_LINESEP_WITH_TITLE);
+    /// let out = "\
+    /// +-------+-------+\n\
+    /// | value | count |\n\
+    /// +-------+-------+\n\
+    /// | 1     | 1     |\n\
+    /// | 2     | 1     |\n\
+    /// | 3     | 1     |\n\
+    /// +-------+-------+\n\
+    /// ";
+    /// assert_eq!(table.to_string().replace("\r\n", "\n"), out);
+    /// ```
+    pub fn to_table_styled(&self, format: TableFormat) -> Table {
+        let mut table = self.to_table();
+        table.set_format(format);
+        table
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use prettytable::format::consts::{FORMAT_CLEAN, FORMAT_DEFAULT};
+
+    use crate::probability::{ProbabilityDistribution, ToTable};
+
+    #[test]
+    fn test_to_table_styled_default_format() {
+        let dice_one = ProbabilityDistribution::new_dice(3).add_self_value_constraint(1);
+        let dice_two = ProbabilityDistribution::new_dice(3).add_self_value_constraint(2);
+        let probability_distribution = dice_one + dice_two;
+
+        assert_eq!(
+            probability_distribution
+                .to_table_styled(*FORMAT_DEFAULT)
+                .to_string(),
+            probability_distribution.to_table().to_string()
+        );
+    }
+
+    #[test]
+    fn test_to_table_styled_changes_separators() {
+        let dice = ProbabilityDistribution::new_dice(2).add_self_value_constraint(1);
+        let mut table = dice.to_table_styled(*FORMAT_CLEAN);
+
+        let out = "\
+        \x20value  count  1 \n\
+        \x201      1      1 \n\
+        \x202      1      2 \n\
+        ";
+        assert_eq!(table.to_string().replace("\r\n", "\n"), out);
+
+        table.set_format(*FORMAT_DEFAULT);
+        assert_eq!(table.to_string(), dice.to_table().to_string());
+    }
+
+    #[test]
+    fn test_to_table_styled_empty() {
+        let table =
+            ProbabilityDistribution::new_empty_distribution().to_table_styled(*FORMAT_CLEAN);
+        assert_eq!(table.to_string().replace("\r\n", "\n"), " value  count \n");
+    }
+}
@@ -28,10 +28,39 @@ impl ProbabilityDistribution {
     ///     ].concat()
     /// );
     /// assert_eq!(probability_distribution.total_outcome_count(), 123 + 567 + 111);
+    ///
+    /// assert_eq!(ProbabilityDistribution::new_dice(6).total_outcome_count(), 6);
+    /// assert_eq!(ProbabilityDistribution::new_multiple_dice(2, 6).total_outcome_count(), 36);
     /// ```
     pub fn total_outcome_count(&self) -> CountType {
         self.outcome_counts.values().sum()
     }
+
+    /// Returns the total number of outcomes in the [ProbabilityDistribution],
+    /// returning `None` instead of panicking if the total overflows a [CountType].
+    ///
+    /// # Returns
+    ///
+    /// The total number of outcomes as a [CountType], or `None` if the sum overflows.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use crate::rusted_dice::probability::ProbabilityDistribution;
+    /// assert_eq!(
+    ///     ProbabilityDistribution::new_multiple_dice(2, 6).checked_total_outcome_count(),
+    ///     Some(36)
+    /// );
+    ///
+    /// let mut probability_distribution = ProbabilityDistribution::new_dice(2);
+    /// probability_distribution.scale_counts_in_place(u64::MAX);
+    /// assert_eq!(probability_distribution.checked_total_outcome_count(), None);
+    /// ```
+    pub fn checked_total_outcome_count(&self) -> Option<CountType> {
+        self.outcome_counts
+            .values()
+            .try_fold(0, |total: CountType, count| total.checked_add(*count))
+    }
 }
 
 #[cfg(test)]
@@ -84,4 +113,44 @@ mod tests {
             123 + 567 + 111
         );
     }
+
+    #[test]
+    fn test_checked_empty() {
+        let probability_distribution = ProbabilityDistribution::new_empty_distribution();
+        assert_eq!(
+            probability_distribution.checked_total_outcome_count(),
+            Some(0)
+        );
+    }
+
+    #[test]
+    fn test_checked_matches_total_outcome_count() {
+        for probability_distribution in [
+            ProbabilityDistribution::new_dice(6),
+            ProbabilityDistribution::new_multiple_dice(2, 6),
+            ProbabilityDistribution::new_multiple_dice(5, 8).add_self_value_constraint(1),
+        ] {
+            assert_eq!(
+                probability_distribution.checked_total_outcome_count(),
+                Some(probability_distribution.total_outcome_count())
+            );
+        }
+    }
+
+    #[test]
+    fn test_checked_at_limit() {
+        let mut probability_distribution = ProbabilityDistribution::new_dice(1);
+        probability_distribution.scale_counts_in_place(CountType::MAX);
+        assert_eq!(
+            probability_distribution.checked_total_outcome_count(),
+            Some(CountType::MAX)
+        );
+    }
+
+    #[test]
+    fn test_checked_overflow() {
+        let mut probability_distribution = ProbabilityDistribution::new_dice(3);
+        probability_distribution.scale_counts_in_place(CountType::MAX / 2);
+        assert_eq!(probability_distribution.checked_total_outcome_count(), None);
+    }
 }
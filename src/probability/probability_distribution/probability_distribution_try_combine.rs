@@ -0,0 +1,207 @@
+use std::collections::{BTreeMap, BTreeSet};
+
+use crate::constraint_management::IsTheoreticallyPossible;
+use crate::probability::{ProbabilityDistribution, ProbabilityOutcome};
+use crate::{CountType, ValueType};
+
+use super::{add_outcome_to_map, CombineOverflow};
+
+impl ProbabilityDistribution {
+    /// Combine this instance with another instance using a fallible operation on [ProbabilityOutcome]s.
+    /// in the order: `checked_operation(self, other)`
+    ///
+    /// `checked_operation` returns `None` when combining two outcomes overflows,
+    /// for example [ProbabilityOutcome::checked_add].
+    /// every failing pair is collected instead of panicking part way through.
+    /// pairs whose combined constraint maps are not possible are skipped before `checked_operation` is called,
+    /// so they are never reported as overflowing.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The [ProbabilityDistribution] to combine with.
+    /// * `checked_operation` - The function used to combine the outcomes.
+    ///
+    /// # Returns
+    ///
+    /// The new [ProbabilityDistribution],
+    /// or a [CombineOverflow] error holding the values of every pair of outcomes that overflowed.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use crate::rusted_dice::probability::{CombineOverflow, ProbabilityDistribution, ProbabilityOutcome};
+    /// # use crate::rusted_dice::probability::ToTable;
+    /// let dice = ProbabilityDistribution::new_dice(2);
+    /// let large = ProbabilityDistribution::from(i32::MAX - 1);
+    ///
+    /// assert_eq!(
+    ///     dice.try_combine(&dice, ProbabilityOutcome::checked_add)
+    ///         .unwrap()
+    ///         .to_table(),
+    ///     (dice.clone() + dice.clone()).to_table()
+    /// );
+    /// assert_eq!(
+    ///     dice.try_combine(&large, ProbabilityOutcome::checked_add)
+    ///         .unwrap_err(),
+    ///     CombineOverflow {
+    ///         pairs: vec![(2, i32::MAX - 1)]
+    ///     }
+    /// );
+    /// ```
+    pub fn try_combine(
+        &self,
+        other: &Self,
+        checked_operation: fn(
+            &ProbabilityOutcome,
+            &ProbabilityOutcome,
+        ) -> Option<ProbabilityOutcome>,
+    ) -> Result<Self, CombineOverflow> {
+        let mut new_outcome_counts: BTreeMap<ProbabilityOutcome, CountType> = BTreeMap::new();
+        let mut overflowing_pairs: BTreeSet<(ValueType, ValueType)> = BTreeSet::new();
+
+        for (outcome_one, count_one) in self.outcome_counts.iter() {
+            for (outcome_two, count_two) in other.outcome_counts.iter() {
+                let constraint_map =
+                    outcome_one.constraint_map.clone() + outcome_two.constraint_map.clone();
+                if !constraint_map.is_theoretically_possible() {
+                    continue;
+                }
+                match checked_operation(outcome_one, outcome_two) {
+                    Some(new_outcome) => add_outcome_to_map(
+                        &mut new_outcome_counts,
+                        new_outcome,
+                        count_one * count_two,
+                    ),
+                    None => {
+                        overflowing_pairs.insert((outcome_one.value, outcome_two.value));
+                    }
+                }
+            }
+        }
+
+        if overflowing_pairs.is_empty() {
+            Ok(ProbabilityDistribution {
+                outcome_counts: new_outcome_counts,
+            })
+        } else {
+            Err(CombineOverflow {
+                pairs: overflowing_pairs.into_iter().collect(),
+            })
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::probability::{
+        CombineOverflow, ProbabilityDistribution, ProbabilityOutcome, ToTable,
+    };
+    use crate::ValueType;
+
+    #[test]
+    fn test_try_combine_empty() {
+        let dice = ProbabilityDistribution::new_dice(6);
+        let empty = ProbabilityDistribution::new_empty_distribution();
+        assert_eq!(
+            dice.try_combine(&empty, ProbabilityOutcome::checked_add)
+                .unwrap()
+                .outcome_counts
+                .len(),
+            0
+        );
+    }
+
+    #[test]
+    fn test_try_combine_matches_operators() {
+        let dice_one = ProbabilityDistribution::new_dice(4).add_self_value_constraint(1);
+        let dice_two = ProbabilityDistribution::new_dice(3).add_self_value_constraint(2);
+
+        assert_eq!(
+            dice_one
+                .try_combine(&dice_two, ProbabilityOutcome::checked_add)
+                .unwrap()
+                .to_table(),
+            (dice_one.clone() + dice_two.clone()).to_table()
+        );
+        assert_eq!(
+            dice_one
+                .try_combine(&dice_two, ProbabilityOutcome::checked_sub)
+                .unwrap()
+                .to_table(),
+            (dice_one.clone() - dice_two.clone()).to_table()
+        );
+        assert_eq!(
+            dice_one
+                .try_combine(&dice_two, ProbabilityOutcome::checked_mul)
+                .unwrap()
+                .to_table(),
+            (dice_one * dice_two).to_table()
+        );
+    }
+
+    #[test]
+    fn test_try_combine_drops_impossible_constraints() {
+        let dice = ProbabilityDistribution::new_dice(3).add_self_value_constraint(1);
+        let combined = dice
+            .try_combine(&dice, ProbabilityOutcome::checked_add)
+            .unwrap();
+        assert_eq!(combined.to_table(), (dice.clone() + dice).to_table());
+        assert_eq!(combined.total_outcome_count(), 3);
+    }
+
+    #[test]
+    fn test_try_combine_skips_impossible_overflow() {
+        let max = ProbabilityDistribution::from(ValueType::MAX).add_self_value_constraint(1);
+        let dice = ProbabilityDistribution::new_dice(2).add_self_value_constraint(1);
+
+        assert_eq!(
+            max.try_combine(&dice, ProbabilityOutcome::checked_add)
+                .unwrap()
+                .outcome_counts
+                .len(),
+            0
+        );
+    }
+
+    #[test]
+    fn test_try_combine_max_overflow() {
+        let dice = ProbabilityDistribution::new_dice(3);
+        let max = ProbabilityDistribution::from(ValueType::MAX);
+
+        assert_eq!(
+            max.try_combine(&dice, ProbabilityOutcome::checked_add)
+                .unwrap_err(),
+            CombineOverflow {
+                pairs: vec![
+                    (ValueType::MAX, 1),
+                    (ValueType::MAX, 2),
+                    (ValueType::MAX, 3)
+                ]
+            }
+        );
+        assert_eq!(
+            max.try_combine(&dice, ProbabilityOutcome::checked_mul)
+                .unwrap_err(),
+            CombineOverflow {
+                pairs: vec![(ValueType::MAX, 2), (ValueType::MAX, 3)]
+            }
+        );
+        assert!(max
+            .try_combine(&dice, ProbabilityOutcome::checked_sub)
+            .is_ok());
+    }
+
+    #[test]
+    fn test_try_combine_min_overflow() {
+        let dice = ProbabilityDistribution::new_dice(2);
+        let min = ProbabilityDistribution::from(ValueType::MIN);
+
+        assert_eq!(
+            min.try_combine(&dice, ProbabilityOutcome::checked_sub)
+                .unwrap_err(),
+            CombineOverflow {
+                pairs: vec![(ValueType::MIN, 1), (ValueType::MIN, 2)]
+            }
+        );
+    }
+}
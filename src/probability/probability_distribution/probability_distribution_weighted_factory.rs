@@ -0,0 +1,103 @@
+use std::collections::BTreeMap;
+
+use crate::probability::{ProbabilityDistribution, ProbabilityOutcome};
+use crate::{CountType, ValueType};
+
+use super::add_outcome_to_map;
+
+impl ProbabilityDistribution {
+    /// Creates a new [ProbabilityDistribution] of a weighted dice, for example a loaded dice or a deck of cards.
+    /// each face becomes a [ProbabilityOutcome] with an empty constraint map and its weight as the count.
+    /// repeated faces are merged by summing their weights, faces with a weight of 0 are skipped,
+    /// so if every weight is 0 the [ProbabilityDistribution] will have no [ProbabilityOutcome]s.
+    ///
+    /// # Arguments
+    ///
+    /// * `faces` - The (face, weight) pairs of the dice.
+    ///
+    /// # Returns
+    ///
+    /// The new [ProbabilityDistribution].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use crate::rusted_dice::probability::ProbabilityDistribution;
+    /// let loaded_d6 =
+    ///     ProbabilityDistribution::new_weighted_dice(&[(1, 1), (2, 1), (3, 1), (4, 1), (5, 1), (6, 5)]);
+    /// assert_eq!(loaded_d6.total_outcome_count(), 10);
+    /// assert_eq!(loaded_d6.probability_at_least(6), 0.5);
+    /// ```
+    pub fn new_weighted_dice(faces: &[(ValueType, CountType)]) -> ProbabilityDistribution {
+        let mut map = BTreeMap::new();
+        for (face, weight) in faces {
+            if *weight != 0 {
+                add_outcome_to_map(
+                    &mut map,
+                    ProbabilityOutcome::new_with_empty_constraint_map(*face),
+                    *weight,
+                );
+            }
+        }
+        ProbabilityDistribution {
+            outcome_counts: map,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::probability::{ProbabilityDistribution, ToTable};
+
+    #[test]
+    fn test_new_weighted_dice_empty() {
+        assert!(ProbabilityDistribution::new_weighted_dice(&[]).is_empty());
+    }
+
+    #[test]
+    fn test_new_weighted_dice_zero_weights() {
+        let probability_distribution =
+            ProbabilityDistribution::new_weighted_dice(&[(1, 0), (2, 0), (2, 0)]);
+        assert!(probability_distribution.is_empty());
+
+        let probability_distribution =
+            ProbabilityDistribution::new_weighted_dice(&[(1, 0), (2, 3)]);
+        assert!(!probability_distribution.contains_value(1));
+        assert_eq!(probability_distribution.len(), 1);
+    }
+
+    #[test]
+    fn test_new_weighted_dice_loaded_d6() {
+        let loaded_d6 = ProbabilityDistribution::new_weighted_dice(&[
+            (1, 1),
+            (2, 1),
+            (3, 1),
+            (4, 1),
+            (5, 1),
+            (6, 5),
+        ]);
+        assert_eq!(loaded_d6.total_outcome_count(), 10);
+        assert_eq!(loaded_d6.probability_at_least(6), 0.5);
+        assert_eq!(loaded_d6.count_of(6), 5);
+        assert_eq!(loaded_d6.count_of(1), 1);
+    }
+
+    #[test]
+    fn test_new_weighted_dice_merges_repeated_faces() {
+        let probability_distribution =
+            ProbabilityDistribution::new_weighted_dice(&[(3, 2), (-1, 1), (3, 4)]);
+        assert_eq!(
+            probability_distribution.coefficients(),
+            (-1, vec![1, 0, 0, 0, 6])
+        );
+    }
+
+    #[test]
+    fn test_new_weighted_dice_unit_weights_match_new_dice() {
+        let faces: Vec<_> = (1..=8).map(|face| (face, 1)).collect();
+        assert_eq!(
+            ProbabilityDistribution::new_weighted_dice(&faces).to_table(),
+            ProbabilityDistribution::new_dice(8).to_table()
+        );
+    }
+}
@@ -1,15 +1,19 @@
+pub mod probability_outcome_abs;
 pub mod probability_outcome_add;
 pub mod probability_outcome_add_constraint;
 pub mod probability_outcome_bitand;
 pub mod probability_outcome_bitor;
 pub mod probability_outcome_bitxor;
+pub mod probability_outcome_checked;
 pub mod probability_outcome_combine;
+pub mod probability_outcome_display;
 pub mod probability_outcome_div;
 pub mod probability_outcome_factory;
 pub mod probability_outcome_mul;
 pub mod probability_outcome_neg;
 pub mod probability_outcome_not;
 pub mod probability_outcome_rem;
+pub mod probability_outcome_saturating;
 pub mod probability_outcome_struct;
 pub mod probability_outcome_sub;
 
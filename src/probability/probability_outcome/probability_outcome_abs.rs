@@ -0,0 +1,79 @@
+use crate::probability::ProbabilityOutcome;
+
+impl ProbabilityOutcome {
+    /// Returns the [ProbabilityOutcome] with the absolute value of its value.
+    /// constraint map is unchanged.
+    ///
+    /// # Panics
+    ///
+    /// Panics in debug builds if the value is [ValueType::MIN](crate::ValueType), whose absolute value overflows.
+    ///
+    /// # Returns
+    ///
+    /// The resulting [ProbabilityOutcome].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use crate::rusted_dice::constraint_management::Constraint;
+    /// # use crate::rusted_dice::probability::ProbabilityOutcome;
+    /// let probability_outcome = ProbabilityOutcome::new_with_constraints(
+    ///     -123,
+    ///     vec![Constraint::new_many_item_constraint(1, vec![1, 2, 3])],
+    /// );
+    ///
+    /// assert_eq!(
+    ///     probability_outcome.abs(),
+    ///     ProbabilityOutcome::new_with_constraints(
+    ///         123,
+    ///         vec![Constraint::new_many_item_constraint(1, vec![1, 2, 3])],
+    ///     )
+    /// );
+    /// ```
+    pub fn abs(&self) -> Self {
+        ProbabilityOutcome {
+            value: self.value.abs(),
+            constraint_map: self.constraint_map.clone(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::constraint_management::{Constraint, ConstraintMap};
+    use crate::probability::ProbabilityOutcome;
+    use crate::ValueType;
+
+    use proptest::prelude::*;
+
+    proptest! {
+        #[test]
+        fn test_abs(value_one in (ValueType::MIN + 1)..=ValueType::MAX) {
+            let probability_outcome = ProbabilityOutcome::new_with_constraint_map(
+                value_one,
+                ConstraintMap::new_constraint_map(
+                    vec![Constraint::new_many_item_constraint(3, vec![1, 2, 3])]
+                )
+            );
+            let result = probability_outcome.abs();
+            assert_eq!(result.value, value_one.abs());
+            assert_eq!(
+                result.constraint_map,
+                ConstraintMap::new_constraint_map(
+                    vec![
+                        Constraint::new_many_item_constraint(
+                            3,
+                            vec![1, 2, 3]
+                        )
+                    ]
+                )
+            );
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "attempt to negate with overflow")]
+    fn test_abs_min_overflow() {
+        ProbabilityOutcome::new_with_empty_constraint_map(ValueType::MIN).abs();
+    }
+}
@@ -0,0 +1,196 @@
+use crate::probability::ProbabilityOutcome;
+use crate::ValueType;
+
+impl ProbabilityOutcome {
+    /// a helper function to combine two [ProbabilityOutcome]s with a fallible function of their values.
+    /// constraint maps are combined using the ConstraintMap::add function.
+    fn checked_combine(
+        &self,
+        other: &Self,
+        checked_operation: fn(ValueType, ValueType) -> Option<ValueType>,
+    ) -> Option<Self> {
+        Some(ProbabilityOutcome {
+            value: checked_operation(self.value, other.value)?,
+            constraint_map: self.constraint_map.clone() + other.constraint_map.clone(),
+        })
+    }
+
+    /// Adds two [ProbabilityOutcome]s, returning `None` instead of panicking if the values overflow.
+    /// constraint maps are combined using the ConstraintMap::add function.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The [ProbabilityOutcome] to add.
+    ///
+    /// # Returns
+    ///
+    /// The resulting [ProbabilityOutcome], or `None` if the addition overflows a [ValueType].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use crate::rusted_dice::probability::ProbabilityOutcome;
+    /// let one = ProbabilityOutcome::new_with_empty_constraint_map(1);
+    /// let max = ProbabilityOutcome::new_with_empty_constraint_map(i32::MAX);
+    ///
+    /// assert_eq!(one.checked_add(&one).map(|outcome| outcome.value), Some(2));
+    /// assert_eq!(one.checked_add(&max), None);
+    /// ```
+    pub fn checked_add(&self, other: &Self) -> Option<Self> {
+        self.checked_combine(other, ValueType::checked_add)
+    }
+
+    /// Subtracts two [ProbabilityOutcome]s, returning `None` instead of panicking if the values overflow.
+    /// constraint maps are combined using the ConstraintMap::add function.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The [ProbabilityOutcome] to subtract.
+    ///
+    /// # Returns
+    ///
+    /// The resulting [ProbabilityOutcome], or `None` if the subtraction overflows a [ValueType].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use crate::rusted_dice::probability::ProbabilityOutcome;
+    /// let one = ProbabilityOutcome::new_with_empty_constraint_map(1);
+    /// let min = ProbabilityOutcome::new_with_empty_constraint_map(i32::MIN);
+    ///
+    /// assert_eq!(one.checked_sub(&one).map(|outcome| outcome.value), Some(0));
+    /// assert_eq!(min.checked_sub(&one), None);
+    /// ```
+    pub fn checked_sub(&self, other: &Self) -> Option<Self> {
+        self.checked_combine(other, ValueType::checked_sub)
+    }
+
+    /// Multiplies two [ProbabilityOutcome]s, returning `None` instead of panicking if the values overflow.
+    /// constraint maps are combined using the ConstraintMap::add function.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The [ProbabilityOutcome] to multiply by.
+    ///
+    /// # Returns
+    ///
+    /// The resulting [ProbabilityOutcome], or `None` if the multiplication overflows a [ValueType].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use crate::rusted_dice::probability::ProbabilityOutcome;
+    /// let two = ProbabilityOutcome::new_with_empty_constraint_map(2);
+    /// let max = ProbabilityOutcome::new_with_empty_constraint_map(i32::MAX);
+    ///
+    /// assert_eq!(two.checked_mul(&two).map(|outcome| outcome.value), Some(4));
+    /// assert_eq!(two.checked_mul(&max), None);
+    /// ```
+    pub fn checked_mul(&self, other: &Self) -> Option<Self> {
+        self.checked_combine(other, ValueType::checked_mul)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::constraint_management::Constraint;
+    use crate::probability::ProbabilityOutcome;
+    use crate::ValueType;
+
+    #[test]
+    fn test_checked_add() {
+        let probability_outcome_one = ProbabilityOutcome::new_with_empty_constraint_map(9999);
+        let probability_outcome_two = ProbabilityOutcome::new_with_empty_constraint_map(8765);
+
+        assert_eq!(
+            probability_outcome_one.checked_add(&probability_outcome_two),
+            Some(probability_outcome_one + probability_outcome_two)
+        );
+    }
+
+    #[test]
+    fn test_checked_add_overflow() {
+        let max = ProbabilityOutcome::new_with_empty_constraint_map(ValueType::MAX);
+        let one = ProbabilityOutcome::new_with_empty_constraint_map(1);
+        let min = ProbabilityOutcome::new_with_empty_constraint_map(ValueType::MIN);
+        let minus_one = ProbabilityOutcome::new_with_empty_constraint_map(-1);
+
+        assert_eq!(max.checked_add(&one), None);
+        assert_eq!(min.checked_add(&minus_one), None);
+        assert_eq!(
+            max.checked_add(&minus_one).unwrap().value,
+            ValueType::MAX - 1
+        );
+    }
+
+    #[test]
+    fn test_checked_sub() {
+        let probability_outcome_one = ProbabilityOutcome::new_with_empty_constraint_map(-12);
+        let probability_outcome_two = ProbabilityOutcome::new_with_empty_constraint_map(30);
+
+        assert_eq!(
+            probability_outcome_one.checked_sub(&probability_outcome_two),
+            Some(probability_outcome_one - probability_outcome_two)
+        );
+    }
+
+    #[test]
+    fn test_checked_sub_overflow() {
+        let max = ProbabilityOutcome::new_with_empty_constraint_map(ValueType::MAX);
+        let min = ProbabilityOutcome::new_with_empty_constraint_map(ValueType::MIN);
+        let one = ProbabilityOutcome::new_with_empty_constraint_map(1);
+        let minus_one = ProbabilityOutcome::new_with_empty_constraint_map(-1);
+
+        assert_eq!(max.checked_sub(&minus_one), None);
+        assert_eq!(min.checked_sub(&one), None);
+    }
+
+    #[test]
+    fn test_checked_mul() {
+        let probability_outcome_one = ProbabilityOutcome::new_with_empty_constraint_map(-7);
+        let probability_outcome_two = ProbabilityOutcome::new_with_empty_constraint_map(6);
+
+        assert_eq!(
+            probability_outcome_one.checked_mul(&probability_outcome_two),
+            Some(probability_outcome_one * probability_outcome_two)
+        );
+    }
+
+    #[test]
+    fn test_checked_mul_overflow() {
+        let max = ProbabilityOutcome::new_with_empty_constraint_map(ValueType::MAX);
+        let min = ProbabilityOutcome::new_with_empty_constraint_map(ValueType::MIN);
+        let two = ProbabilityOutcome::new_with_empty_constraint_map(2);
+        let minus_one = ProbabilityOutcome::new_with_empty_constraint_map(-1);
+
+        assert_eq!(max.checked_mul(&two), None);
+        assert_eq!(min.checked_mul(&minus_one), None);
+    }
+
+    #[test]
+    fn test_checked_combines_constraints() {
+        let probability_outcome_one = ProbabilityOutcome::new_with_constraints(
+            1,
+            vec![Constraint::new_many_item_constraint(1, vec![1, 2])],
+        );
+        let probability_outcome_two = ProbabilityOutcome::new_with_constraints(
+            2,
+            vec![
+                Constraint::new_many_item_constraint(1, vec![2, 3]),
+                Constraint::new_single_valid_value_constraint(2, 5),
+            ],
+        );
+
+        for result in [
+            probability_outcome_one.checked_add(&probability_outcome_two),
+            probability_outcome_one.checked_sub(&probability_outcome_two),
+            probability_outcome_one.checked_mul(&probability_outcome_two),
+        ] {
+            assert_eq!(
+                result.unwrap().constraint_map,
+                probability_outcome_one.constraint_map.clone()
+                    + probability_outcome_two.constraint_map.clone()
+            );
+        }
+    }
+}
@@ -0,0 +1,94 @@
+use std::fmt;
+
+use crate::constraint_management::ConstraintIdType;
+use crate::probability::ProbabilityOutcome;
+
+impl fmt::Display for ProbabilityOutcome {
+    /// Formats a [ProbabilityOutcome] for people to read.
+    /// prints only the value, followed by the sorted ids of its constraints if it has any.
+    /// use [Debug](fmt::Debug) to see the valid values of each constraint.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use crate::rusted_dice::probability::ProbabilityOutcome;
+    /// # use crate::rusted_dice::constraint_management::Constraint;
+    /// let probability_outcome = ProbabilityOutcome::new_with_empty_constraint_map(5);
+    /// assert_eq!(format!("{}", probability_outcome), "5");
+    ///
+    /// let probability_outcome = ProbabilityOutcome::new_with_constraints(
+    ///     -3,
+    ///     vec![
+    ///         Constraint::new_many_item_constraint(2, vec![1, 2]),
+    ///         Constraint::new_single_valid_value_constraint(1, 4),
+    ///     ],
+    /// );
+    /// assert_eq!(format!("{}", probability_outcome), "-3 [constraints: 1,2]");
+    /// ```
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.constraint_map.map.is_empty() {
+            return write!(formatter, "{}", self.value);
+        }
+
+        let mut ids: Vec<ConstraintIdType> = self.constraint_map.map.keys().copied().collect();
+        ids.sort();
+        let ids: Vec<String> = ids.iter().map(|id| id.to_string()).collect();
+        write!(formatter, "{} [constraints: {}]", self.value, ids.join(","))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::constraint_management::Constraint;
+    use crate::probability::ProbabilityOutcome;
+    use crate::ValueType;
+
+    use proptest::prelude::*;
+
+    #[test]
+    fn test_display_single_constraint() {
+        let probability_outcome = ProbabilityOutcome::new_with_constraints(
+            12,
+            vec![Constraint::new_many_item_constraint(7, vec![1, 2, 3])],
+        );
+        assert_eq!(probability_outcome.to_string(), "12 [constraints: 7]");
+    }
+
+    #[test]
+    fn test_display_many_constraints_sorted() {
+        let probability_outcome = ProbabilityOutcome::new_with_constraints(
+            0,
+            (0..12)
+                .rev()
+                .map(|id| Constraint::new_single_valid_value_constraint(id, 1)),
+        );
+        assert_eq!(
+            probability_outcome.to_string(),
+            "0 [constraints: 0,1,2,3,4,5,6,7,8,9,10,11]"
+        );
+    }
+
+    #[test]
+    fn test_display_impossible_constraint() {
+        let probability_outcome =
+            ProbabilityOutcome::new_with_constraints(1, vec![Constraint::new_empty_constraint(3)]);
+        assert_eq!(probability_outcome.to_string(), "1 [constraints: 3]");
+    }
+
+    #[test]
+    fn test_debug_unchanged() {
+        let probability_outcome = ProbabilityOutcome::new_with_empty_constraint_map(5);
+        assert_eq!(
+            format!("{:?}", probability_outcome),
+            "ProbabilityOutcome { value: 5, constraint_map: ConstraintMap { map: {} } }"
+        );
+    }
+
+    proptest! {
+        #[test]
+        fn test_display_value(value: ValueType) {
+            let probability_outcome = ProbabilityOutcome::new_with_empty_constraint_map(value);
+            prop_assert_eq!(probability_outcome.to_string(), value.to_string());
+        }
+    }
+}
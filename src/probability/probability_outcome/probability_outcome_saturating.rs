@@ -0,0 +1,162 @@
+use crate::probability::ProbabilityOutcome;
+use crate::ValueType;
+
+impl ProbabilityOutcome {
+    /// a helper function to combine two [ProbabilityOutcome]s with a saturating function of their values.
+    /// constraint maps are combined using the ConstraintMap::add function.
+    fn saturating_combine(
+        &self,
+        other: &Self,
+        saturating_operation: fn(ValueType, ValueType) -> ValueType,
+    ) -> Self {
+        ProbabilityOutcome {
+            value: saturating_operation(self.value, other.value),
+            constraint_map: self.constraint_map.clone() + other.constraint_map.clone(),
+        }
+    }
+
+    /// Adds two [ProbabilityOutcome]s, clamping the value to the bounds of [ValueType] instead of overflowing.
+    /// constraint maps are combined using the ConstraintMap::add function.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The [ProbabilityOutcome] to add.
+    ///
+    /// # Returns
+    ///
+    /// The resulting [ProbabilityOutcome].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use crate::rusted_dice::probability::ProbabilityOutcome;
+    /// let one = ProbabilityOutcome::new_with_empty_constraint_map(1);
+    /// let max = ProbabilityOutcome::new_with_empty_constraint_map(i32::MAX);
+    ///
+    /// assert_eq!(one.saturating_add(&one).value, 2);
+    /// assert_eq!(one.saturating_add(&max).value, i32::MAX);
+    /// ```
+    pub fn saturating_add(&self, other: &Self) -> Self {
+        self.saturating_combine(other, ValueType::saturating_add)
+    }
+
+    /// Subtracts two [ProbabilityOutcome]s, clamping the value to the bounds of [ValueType] instead of overflowing.
+    /// constraint maps are combined using the ConstraintMap::add function.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The [ProbabilityOutcome] to subtract.
+    ///
+    /// # Returns
+    ///
+    /// The resulting [ProbabilityOutcome].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use crate::rusted_dice::probability::ProbabilityOutcome;
+    /// let one = ProbabilityOutcome::new_with_empty_constraint_map(1);
+    /// let min = ProbabilityOutcome::new_with_empty_constraint_map(i32::MIN);
+    ///
+    /// assert_eq!(one.saturating_sub(&one).value, 0);
+    /// assert_eq!(min.saturating_sub(&one).value, i32::MIN);
+    /// ```
+    pub fn saturating_sub(&self, other: &Self) -> Self {
+        self.saturating_combine(other, ValueType::saturating_sub)
+    }
+
+    /// Multiplies two [ProbabilityOutcome]s, clamping the value to the bounds of [ValueType] instead of overflowing.
+    /// constraint maps are combined using the ConstraintMap::add function.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The [ProbabilityOutcome] to multiply by.
+    ///
+    /// # Returns
+    ///
+    /// The resulting [ProbabilityOutcome].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use crate::rusted_dice::probability::ProbabilityOutcome;
+    /// let two = ProbabilityOutcome::new_with_empty_constraint_map(2);
+    /// let min = ProbabilityOutcome::new_with_empty_constraint_map(i32::MIN);
+    ///
+    /// assert_eq!(two.saturating_mul(&two).value, 4);
+    /// assert_eq!(two.saturating_mul(&min).value, i32::MIN);
+    /// ```
+    pub fn saturating_mul(&self, other: &Self) -> Self {
+        self.saturating_combine(other, ValueType::saturating_mul)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::constraint_management::Constraint;
+    use crate::probability::ProbabilityOutcome;
+    use crate::ValueType;
+
+    #[test]
+    fn test_saturating_matches_operators() {
+        let probability_outcome_one = ProbabilityOutcome::new_with_empty_constraint_map(-12);
+        let probability_outcome_two = ProbabilityOutcome::new_with_empty_constraint_map(30);
+
+        assert_eq!(
+            probability_outcome_one.saturating_add(&probability_outcome_two),
+            probability_outcome_one.clone() + probability_outcome_two.clone()
+        );
+        assert_eq!(
+            probability_outcome_one.saturating_sub(&probability_outcome_two),
+            probability_outcome_one.clone() - probability_outcome_two.clone()
+        );
+        assert_eq!(
+            probability_outcome_one.saturating_mul(&probability_outcome_two),
+            probability_outcome_one * probability_outcome_two
+        );
+    }
+
+    #[test]
+    fn test_saturating_max() {
+        let max = ProbabilityOutcome::new_with_empty_constraint_map(ValueType::MAX);
+        let two = ProbabilityOutcome::new_with_empty_constraint_map(2);
+        let minus_one = ProbabilityOutcome::new_with_empty_constraint_map(-1);
+
+        assert_eq!(max.saturating_add(&two).value, ValueType::MAX);
+        assert_eq!(max.saturating_sub(&minus_one).value, ValueType::MAX);
+        assert_eq!(max.saturating_mul(&two).value, ValueType::MAX);
+        assert_eq!(max.saturating_mul(&minus_one).value, -ValueType::MAX);
+    }
+
+    #[test]
+    fn test_saturating_min() {
+        let min = ProbabilityOutcome::new_with_empty_constraint_map(ValueType::MIN);
+        let two = ProbabilityOutcome::new_with_empty_constraint_map(2);
+        let minus_one = ProbabilityOutcome::new_with_empty_constraint_map(-1);
+
+        assert_eq!(min.saturating_add(&minus_one).value, ValueType::MIN);
+        assert_eq!(min.saturating_sub(&two).value, ValueType::MIN);
+        assert_eq!(min.saturating_mul(&two).value, ValueType::MIN);
+        assert_eq!(min.saturating_mul(&minus_one).value, ValueType::MAX);
+    }
+
+    #[test]
+    fn test_saturating_combines_constraints() {
+        let probability_outcome_one = ProbabilityOutcome::new_with_constraints(
+            ValueType::MAX,
+            vec![Constraint::new_many_item_constraint(1, vec![1, 2])],
+        );
+        let probability_outcome_two = ProbabilityOutcome::new_with_constraints(
+            2,
+            vec![Constraint::new_many_item_constraint(1, vec![2, 3])],
+        );
+
+        assert_eq!(
+            probability_outcome_one
+                .saturating_add(&probability_outcome_two)
+                .constraint_map,
+            probability_outcome_one.constraint_map.clone()
+                + probability_outcome_two.constraint_map.clone()
+        );
+    }
+}
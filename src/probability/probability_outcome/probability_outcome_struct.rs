@@ -36,7 +36,8 @@ use crate::{constraint_management::ConstraintMap, ValueType};
 ///     vec![constraint_1, constraint_2]
 /// );
 /// ```
-#[derive(Clone, Debug, Eq, Ord, PartialOrd, PartialEq)]
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialOrd, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ProbabilityOutcome {
     pub value: ValueType,
     pub constraint_map: ConstraintMap,
@@ -45,6 +46,9 @@ pub struct ProbabilityOutcome {
 #[cfg(test)]
 mod tests {
     use std::cmp::Ordering::{Equal, Greater, Less};
+    use std::collections::hash_map::DefaultHasher;
+    use std::collections::HashMap;
+    use std::hash::{Hash, Hasher};
 
     use crate::constraint_management::{Constraint, ConstraintIdType, ConstraintMap};
     use crate::probability::ProbabilityOutcome;
@@ -401,4 +405,102 @@ mod tests {
         probability_outcome_two.clone_from(&probability_outcome_one);
         assert_ne!(probability_outcome_two.value, 2);
     }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn test_serde_round_trip() {
+        let probability_outcome = ProbabilityOutcome::new_with_constraints(
+            7,
+            vec![
+                Constraint::new_many_item_constraint(1, vec![1, 2, 3]),
+                Constraint::new_single_valid_value_constraint(4, -2),
+            ],
+        );
+        let json = serde_json::to_string(&probability_outcome).unwrap();
+        assert_eq!(
+            serde_json::from_str::<ProbabilityOutcome>(&json).unwrap(),
+            probability_outcome
+        );
+    }
+
+    fn hash_of(probability_outcome: &ProbabilityOutcome) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        probability_outcome.hash(&mut hasher);
+        hasher.finish()
+    }
+
+    #[test]
+    fn test_hash_equal_outcomes_built_independently() {
+        let probability_outcome_one = ProbabilityOutcome::new_with_constraints(
+            7,
+            vec![
+                Constraint::new_many_item_constraint(1, vec![1, 2, 3, 4]),
+                Constraint::new_many_item_constraint(2, vec![5, 6, 7, 8]),
+                Constraint::new_many_item_constraint(1, vec![2, 3, 4, 5]),
+            ],
+        );
+        let probability_outcome_two = ProbabilityOutcome::new_with_constraints(
+            7,
+            vec![
+                Constraint::new_many_item_constraint(2, vec![8, 7, 6, 5]),
+                Constraint::new_many_item_constraint(1, vec![4, 3, 2]),
+            ],
+        );
+
+        assert_eq!(probability_outcome_one, probability_outcome_two);
+        assert_eq!(
+            hash_of(&probability_outcome_one),
+            hash_of(&probability_outcome_two)
+        );
+    }
+
+    #[test]
+    fn test_hash_map_key() {
+        let mut outcome_names = HashMap::new();
+        outcome_names.insert(
+            ProbabilityOutcome::new_with_constraints(
+                3,
+                vec![Constraint::new_many_item_constraint(1, vec![1, 2])],
+            ),
+            "three",
+        );
+        assert_eq!(
+            outcome_names.get(&ProbabilityOutcome::new_with_constraints(
+                3,
+                vec![Constraint::new_many_item_constraint(1, vec![2, 1])],
+            )),
+            Some(&"three")
+        );
+        assert_eq!(
+            outcome_names.get(&ProbabilityOutcome::new_with_empty_constraint_map(3)),
+            None
+        );
+    }
+
+    proptest! {
+        #[test]
+        fn test_hash_matches_eq(
+            value in -5..5,
+            id_one: ConstraintIdType,
+            id_two: ConstraintIdType,
+            valid_values in prop::collection::vec(-5..5, 0..6),
+        ) {
+            let probability_outcome_one = ProbabilityOutcome::new_with_constraints(
+                value,
+                vec![
+                    Constraint::new_many_item_constraint(id_one, valid_values.clone()),
+                    Constraint::new_many_item_constraint(id_two, valid_values.clone()),
+                ],
+            );
+            let probability_outcome_two = ProbabilityOutcome::new_with_constraints(
+                value,
+                vec![
+                    Constraint::new_many_item_constraint(id_two, valid_values.iter().rev().cloned()),
+                    Constraint::new_many_item_constraint(id_one, valid_values.into_iter().rev()),
+                ],
+            );
+            prop_assert_eq!(&probability_outcome_one, &probability_outcome_two);
+            prop_assert_eq!(hash_of(&probability_outcome_one), hash_of(&probability_outcome_two));
+        }
+    }
 }
@@ -41,3 +41,17 @@ pub trait Combine {
     /// Returns the self type result of the [BinaryOperation] function.
     fn value_type_combine(&self, other: ValueType, binary_operation: BinaryOperation) -> Self;
 }
+
+/// A trait for strategies that reduce the faces shown by a pool of dice into a single [ValueType].
+pub trait PoolAggregator {
+    /// Aggregate the faces shown by a pool of dice.
+    ///
+    /// # Arguments
+    ///
+    /// * `sorted_faces` - The [ValueType] faces shown by each dice, sorted ascending.
+    ///
+    /// # Returns
+    ///
+    /// Returns the [ValueType] result of the pool.
+    fn aggregate(&self, sorted_faces: &[ValueType]) -> ValueType;
+}
@@ -9,3 +9,6 @@ pub type BinaryOperation = fn(ValueType, ValueType) -> ValueType;
 
 /// A type representing a [BTreeMap] mapping [ProbabilityOutcome] to a count [CountType].
 pub type OutcomeToCountMap = BTreeMap<ProbabilityOutcome, CountType>;
+
+/// A type representing a [BTreeMap] mapping a [ValueType] to a count [CountType], ignoring constraints.
+pub type ValueToCountMap = BTreeMap<ValueType, CountType>;
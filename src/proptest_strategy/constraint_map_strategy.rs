@@ -0,0 +1,105 @@
+use std::ops::Range;
+
+use proptest::collection::vec;
+use proptest::prelude::*;
+
+use crate::constraint_management::{ConstraintIdType, ConstraintMap};
+use crate::ValueType;
+
+use super::constraint_strategy;
+
+/// Creates a proptest [Strategy] generating arbitrary [ConstraintMap]s.
+/// the [ConstraintMap] is built from up to `max_constraints` generated [Constraint](crate::constraint_management::Constraint)s,
+/// [Constraint](crate::constraint_management::Constraint)s sharing an id are intersected,
+/// so some generated [ConstraintMap]s are impossible.
+///
+/// # Arguments
+///
+/// * `max_constraints` - The largest number of [Constraint](crate::constraint_management::Constraint)s to generate.
+/// * `id_range` - The [Range] of [ConstraintIdType] to pick the ids from.
+/// * `value_range` - The [Range] of [ValueType] to pick the valid values from.
+///
+/// # Returns
+///
+/// The [Strategy] of [ConstraintMap].
+///
+/// # Panics
+///
+/// Panics when sampled if `id_range` or `value_range` is empty.
+///
+/// # Example
+///
+/// ```
+/// # use crate::rusted_dice::proptest_strategy::constraint_map_strategy;
+/// use proptest::strategy::{Strategy, ValueTree};
+/// use proptest::test_runner::TestRunner;
+///
+/// let mut runner = TestRunner::default();
+/// let constraint_map = constraint_map_strategy(3, 1..3, 0..6)
+///     .new_tree(&mut runner)
+///     .unwrap()
+///     .current();
+///
+/// assert!(constraint_map.map.len() <= 2);
+/// ```
+pub fn constraint_map_strategy(
+    max_constraints: usize,
+    id_range: Range<ConstraintIdType>,
+    value_range: Range<ValueType>,
+) -> impl Strategy<Value = ConstraintMap> {
+    vec(
+        constraint_strategy(id_range, value_range),
+        0..=max_constraints,
+    )
+    .prop_map(ConstraintMap::new_constraint_map)
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+
+    #[cfg(feature = "serde")]
+    use crate::constraint_management::ConstraintMap;
+    use crate::constraint_management::IsTheoreticallyPossible;
+
+    use super::constraint_map_strategy;
+
+    proptest! {
+        #[test]
+        fn test_constraint_map_strategy_invariants(
+            constraint_map in constraint_map_strategy(5, 1..4, 0..4)
+        ) {
+            prop_assert!(constraint_map.map.len() <= 3);
+            for (id, constraint) in constraint_map.map.iter() {
+                prop_assert_eq!(*id, constraint.id);
+                prop_assert!((1..4).contains(id));
+            }
+        }
+
+        #[test]
+        fn test_constraint_map_strategy_possibility(
+            constraint_map in constraint_map_strategy(5, 1..4, 0..4)
+        ) {
+            let has_empty_constraint = constraint_map
+                .map
+                .values()
+                .any(|constraint| constraint.valid_values.is_empty());
+            prop_assert_eq!(
+                constraint_map.is_theoretically_possible(),
+                !has_empty_constraint
+            );
+        }
+
+        #[cfg(feature = "serde")]
+        #[test]
+        fn test_constraint_map_strategy_serde_round_trip(
+            constraint_map in constraint_map_strategy(5, 1..4, -3..4)
+        ) {
+            let json = serde_json::to_string(&constraint_map).unwrap();
+            prop_assert_eq!(
+                serde_json::from_str::<ConstraintMap>(&json).unwrap(),
+                constraint_map
+            );
+        }
+    }
+}
@@ -0,0 +1,73 @@
+use std::ops::Range;
+
+use proptest::collection::btree_set;
+use proptest::prelude::*;
+
+use crate::constraint_management::{Constraint, ConstraintIdType};
+use crate::ValueType;
+
+/// the largest number of valid values given to a generated [Constraint].
+pub const MAX_GENERATED_VALID_VALUES: usize = 4;
+
+/// Creates a proptest [Strategy] generating arbitrary [Constraint]s.
+/// each [Constraint] has up to [MAX_GENERATED_VALID_VALUES] valid values,
+/// occasionally none at all, making the [Constraint] impossible.
+///
+/// # Arguments
+///
+/// * `id_range` - The [Range] of [ConstraintIdType] to pick the id from.
+/// * `value_range` - The [Range] of [ValueType] to pick the valid values from.
+///
+/// # Returns
+///
+/// The [Strategy] of [Constraint].
+///
+/// # Panics
+///
+/// Panics when sampled if `id_range` or `value_range` is empty.
+///
+/// # Example
+///
+/// ```
+/// # use crate::rusted_dice::proptest_strategy::constraint_strategy;
+/// use proptest::strategy::{Strategy, ValueTree};
+/// use proptest::test_runner::TestRunner;
+///
+/// let mut runner = TestRunner::default();
+/// let constraint = constraint_strategy(1..3, 0..6)
+///     .new_tree(&mut runner)
+///     .unwrap()
+///     .current();
+///
+/// assert!((1..3).contains(&constraint.id));
+/// assert!(constraint.valid_values.iter().all(|value| (0..6).contains(value)));
+/// ```
+pub fn constraint_strategy(
+    id_range: Range<ConstraintIdType>,
+    value_range: Range<ValueType>,
+) -> impl Strategy<Value = Constraint> {
+    (
+        id_range,
+        btree_set(value_range, 0..=MAX_GENERATED_VALID_VALUES),
+    )
+        .prop_map(|(id, valid_values)| Constraint::new_many_item_constraint(id, valid_values))
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+
+    use super::{constraint_strategy, MAX_GENERATED_VALID_VALUES};
+
+    proptest! {
+        #[test]
+        fn test_constraint_strategy_invariants(constraint in constraint_strategy(2..5, -3..3)) {
+            prop_assert!((2..5).contains(&constraint.id));
+            prop_assert!(constraint.valid_values.len() <= MAX_GENERATED_VALID_VALUES);
+            prop_assert!(constraint
+                .valid_values
+                .iter()
+                .all(|value| (-3..3).contains(value)));
+        }
+    }
+}
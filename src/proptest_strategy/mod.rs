@@ -0,0 +1,7 @@
+pub mod constraint_map_strategy;
+pub mod constraint_strategy;
+pub mod probability_distribution_strategy;
+
+pub use self::constraint_map_strategy::constraint_map_strategy;
+pub use self::constraint_strategy::constraint_strategy;
+pub use self::probability_distribution_strategy::probability_distribution_strategy;
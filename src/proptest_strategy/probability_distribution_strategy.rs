@@ -0,0 +1,134 @@
+use std::collections::{BTreeMap, BTreeSet};
+use std::ops::Range;
+
+use proptest::collection::{btree_map, btree_set, vec};
+use proptest::prelude::*;
+
+use crate::constraint_management::{Constraint, ConstraintIdType};
+use crate::probability::{add_outcome_to_map, ProbabilityDistribution, ProbabilityOutcome};
+use crate::{CountType, ValueType};
+
+/// the largest count given to a single generated outcome, small enough that totals can not overflow.
+pub const MAX_GENERATED_COUNT: CountType = 1_000_000;
+
+/// the largest number of constraints given to a single generated outcome.
+pub const MAX_GENERATED_CONSTRAINTS: usize = 3;
+
+/// Creates a proptest [Strategy] generating arbitrary valid [ProbabilityDistribution]s.
+/// every outcome has a count between 1 and [MAX_GENERATED_COUNT],
+/// and up to [MAX_GENERATED_CONSTRAINTS] possible constraints, each with at least one valid value.
+///
+/// # Arguments
+///
+/// * `max_outcomes` - The largest number of outcomes to generate, outcomes which are the same are merged.
+/// * `value_range` - The [Range] of [ValueType] used for outcome values and valid constraint values.
+///
+/// # Returns
+///
+/// The [Strategy] of [ProbabilityDistribution].
+///
+/// # Panics
+///
+/// Panics when sampled if `value_range` is empty.
+///
+/// # Example
+///
+/// ```
+/// # use crate::rusted_dice::proptest_strategy::probability_distribution_strategy;
+/// use proptest::strategy::{Strategy, ValueTree};
+/// use proptest::test_runner::TestRunner;
+///
+/// let mut runner = TestRunner::default();
+/// let probability_distribution = probability_distribution_strategy(5, -3..4)
+///     .new_tree(&mut runner)
+///     .unwrap()
+///     .current();
+///
+/// assert!(probability_distribution.outcome_counts.len() <= 5);
+/// assert!(probability_distribution
+///     .outcome_counts
+///     .keys()
+///     .all(|outcome| (-3..4).contains(&outcome.value)));
+/// ```
+pub fn probability_distribution_strategy(
+    max_outcomes: usize,
+    value_range: Range<ValueType>,
+) -> impl Strategy<Value = ProbabilityDistribution> {
+    let constraints = btree_map(
+        0..(MAX_GENERATED_CONSTRAINTS as ConstraintIdType * 2),
+        btree_set(value_range.clone(), 1..4),
+        0..=MAX_GENERATED_CONSTRAINTS,
+    );
+    let outcome = (value_range, 1..=MAX_GENERATED_COUNT, constraints);
+
+    vec(outcome, 0..=max_outcomes).prop_map(|outcomes| {
+        let mut outcome_counts = BTreeMap::new();
+        for (value, count, constraints) in outcomes {
+            add_outcome_to_map(
+                &mut outcome_counts,
+                ProbabilityOutcome::new_with_constraints(
+                    value,
+                    constraints.into_iter().map(
+                        |(id, valid_values): (ConstraintIdType, BTreeSet<ValueType>)| {
+                            Constraint::new_many_item_constraint(id, valid_values)
+                        },
+                    ),
+                ),
+                count,
+            );
+        }
+        ProbabilityDistribution { outcome_counts }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+
+    use crate::constraint_management::IsTheoreticallyPossible;
+
+    use super::{probability_distribution_strategy, MAX_GENERATED_CONSTRAINTS};
+
+    proptest! {
+        #[test]
+        fn test_probability_distribution_strategy_invariants(
+            probability_distribution in probability_distribution_strategy(8, -5..5)
+        ) {
+            prop_assert!(probability_distribution.outcome_counts.len() <= 8);
+            for (outcome, count) in probability_distribution.outcome_counts.iter() {
+                prop_assert!(*count > 0);
+                prop_assert!((-5..5).contains(&outcome.value));
+                prop_assert!(outcome.constraint_map.is_theoretically_possible());
+                prop_assert!(outcome.constraint_map.map.len() <= MAX_GENERATED_CONSTRAINTS);
+                for constraint in outcome.constraint_map.map.values() {
+                    prop_assert!(constraint
+                        .valid_values
+                        .iter()
+                        .all(|value| (-5..5).contains(value)));
+                }
+            }
+        }
+
+        #[test]
+        fn test_probability_distribution_strategy_combines(
+            probability_distribution_one in probability_distribution_strategy(4, 0..6),
+            probability_distribution_two in probability_distribution_strategy(4, 0..6),
+        ) {
+            let combined = probability_distribution_one + probability_distribution_two;
+            prop_assert!(combined.outcome_counts.values().all(|count| *count > 0));
+        }
+    }
+
+    #[test]
+    fn test_probability_distribution_strategy_no_outcomes() {
+        use proptest::strategy::ValueTree;
+        use proptest::test_runner::TestRunner;
+
+        let mut runner = TestRunner::default();
+        let probability_distribution = probability_distribution_strategy(0, 0..1)
+            .new_tree(&mut runner)
+            .unwrap()
+            .current();
+        assert_eq!(probability_distribution.outcome_counts.len(), 0);
+    }
+}